@@ -0,0 +1,501 @@
+//! System V/GNU `ar` archives, the static libraries linkers take.
+
+use crate::input::Data;
+use std::error;
+use std::fmt::Display;
+use std::str;
+
+pub const MAGIC: &[u8; 8] = b"!<arch>\n";
+/// Archives only keeping the paths of their members, relative to the archive.
+pub const THIN_MAGIC: &[u8; 8] = b"!<thin>\n";
+
+/// Size of the header before each member.
+pub const HEADER_SIZE: usize = 60;
+
+#[derive(Debug)]
+pub enum Error {
+    Magic,
+    Header { offset: usize, err: String },
+    Index(String),
+    Truncated { offset: usize },
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Magic => write!(f, "not an archive"),
+            Self::Header { offset, err } => {
+                write!(f, "malformed member header at {:#x}: {}", offset, err)
+            }
+            Self::Index(err) => write!(f, "malformed symbol index: {}", err),
+            Self::Truncated { offset } => {
+                write!(f, "truncated member at {:#x}", offset)
+            }
+        }
+    }
+}
+
+/// A member of an archive.
+#[derive(Debug, Clone, Copy)]
+pub struct Member<'a> {
+    name: &'a str,
+    offset: usize,
+    date: u64,
+    uid: u32,
+    gid: u32,
+    mode: u32,
+    size: u64,
+    archive: Data<'a>,
+    contents: Option<(usize, usize)>,
+}
+
+impl<'a> Member<'a> {
+    /// Name of the member, the path of the file relative to the archive for thin ones.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Offset of its header in the archive, as the symbol index refers to it.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn date(&self) -> u64 {
+        self.date
+    }
+
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    pub fn gid(&self) -> u32 {
+        self.gid
+    }
+
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Contents of the member, read from the archive the first time they are asked for. `None`
+    /// for the members of thin archives which are read from [`Member::name`] instead, or if
+    /// they can not be read.
+    pub fn data(&self) -> Option<&'a [u8]> {
+        let (start, end) = self.contents?;
+        self.archive.get(start..end)
+    }
+}
+
+/// An entry of the symbol index, the `/` or `/SYM64/` member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexEntry<'a> {
+    pub name: &'a str,
+    /// Offset of the header of the member defining it.
+    pub offset: u64,
+}
+
+/// A parsed archive. Every member header is validated up front, their contents are only read
+/// as they are accessed through [`Member::data`].
+#[derive(Debug, Clone)]
+pub struct Archive<'a> {
+    thin: bool,
+    index: Vec<IndexEntry<'a>>,
+    members: Vec<Member<'a>>,
+}
+
+/// Raw fields of a member header.
+struct Header<'a> {
+    name: &'a [u8],
+    date: u64,
+    uid: u32,
+    gid: u32,
+    mode: u32,
+    size: u64,
+}
+
+fn field<T: TryFrom<u64>>(bytes: &[u8], radix: u32, what: &str) -> Result<T, String> {
+    let text = str::from_utf8(bytes)
+        .map_err(|_| format!("{what} is not ASCII"))?
+        .trim_end_matches(' ');
+
+    if text.is_empty() {
+        return T::try_from(0).map_err(|_| format!("{what} is out of range"));
+    }
+
+    u64::from_str_radix(text, radix)
+        .ok()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| format!("invalid {what}: {text}"))
+}
+
+impl<'a> Header<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, String> {
+        if &bytes[58..60] != b"`\n" {
+            return Err("missing terminator".into());
+        }
+
+        Ok(Self {
+            name: &bytes[0..16],
+            date: field(&bytes[16..28], 10, "date")?,
+            uid: field(&bytes[28..34], 10, "uid")?,
+            gid: field(&bytes[34..40], 10, "gid")?,
+            mode: field(&bytes[40..48], 8, "mode")?,
+            size: field(&bytes[48..58], 10, "size")?,
+        })
+    }
+}
+
+impl<'a> Archive<'a> {
+    /// Whether `data` starts with the magic of a regular or thin archive.
+    pub fn is_archive(data: impl Into<Data<'a>>) -> bool {
+        data.into()
+            .get(0..MAGIC.len())
+            .is_some_and(|magic| magic == MAGIC || magic == THIN_MAGIC)
+    }
+
+    pub fn parse(data: impl Into<Data<'a>>) -> Result<Self, Error> {
+        let data = data.into();
+        let thin = match data.get(0..MAGIC.len()) {
+            Some(magic) if magic == MAGIC => false,
+            Some(magic) if magic == THIN_MAGIC => true,
+            _ => return Err(Error::Magic),
+        };
+
+        let mut index = None;
+        let mut names: &[u8] = &[];
+        let mut members = Vec::new();
+        let mut offset = MAGIC.len();
+
+        while offset < data.len() {
+            let bytes = data
+                .get(offset..offset + HEADER_SIZE)
+                .ok_or(Error::Truncated { offset })?;
+            let header = Header::parse(bytes).map_err(|err| Error::Header { offset, err })?;
+            let name = str::from_utf8(header.name)
+                .map_err(|_| Error::Header {
+                    offset,
+                    err: "name is not UTF-8".into(),
+                })?
+                .trim_end_matches(' ');
+
+            let size = usize::try_from(header.size).map_err(|_| Error::Truncated { offset })?;
+            let start = offset + HEADER_SIZE;
+
+            // Thin archives only store the symbol index and the name table
+            let stored = !thin || matches!(name, "/" | "/SYM64/" | "//");
+            let contents = match stored {
+                true => Some(
+                    start
+                        .checked_add(size)
+                        .filter(|&end| end <= data.len())
+                        .map(|end| (start, end))
+                        .ok_or(Error::Truncated { offset })?,
+                ),
+                false => None,
+            };
+            let read = |(start, end)| data.get(start..end).ok_or(Error::Truncated { offset });
+
+            let (name, contents) = match name {
+                // Some archives have both a `/` and a `/SYM64/` index, the first one is kept
+                "/" | "/SYM64/" => {
+                    if index.is_none() {
+                        let bytes = contents.map(read).transpose()?.unwrap_or_default();
+                        index = Some(Self::symbol_index(bytes, name == "/SYM64/")?);
+                    }
+                    (None, None)
+                }
+                "//" => {
+                    names = contents.map(read).transpose()?.unwrap_or_default();
+                    (None, None)
+                }
+                // GNU names are terminated by a slash, longer ones are in the `//` table
+                _ => match name.strip_prefix('/') {
+                    Some(position) => {
+                        let name = position
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|position| Self::long_name(names, position))
+                            .ok_or_else(|| Error::Header {
+                                offset,
+                                err: format!("invalid long name /{position}"),
+                            })?;
+
+                        (Some(name), contents)
+                    }
+                    // BSD names follow the header and are counted in its size
+                    None => match name.strip_prefix("#1/") {
+                        Some(length) => {
+                            let (start, end) = contents.unwrap_or((start, start));
+                            let length = length
+                                .parse::<usize>()
+                                .ok()
+                                .filter(|&length| length <= end - start)
+                                .ok_or_else(|| Error::Header {
+                                    offset,
+                                    err: format!("invalid BSD name #1/{length}"),
+                                })?;
+                            let name = str::from_utf8(read((start, start + length))?)
+                                .map_err(|_| Error::Header {
+                                    offset,
+                                    err: "name is not UTF-8".into(),
+                                })?
+                                .trim_end_matches('\0');
+
+                            (Some(name), Some((start + length, end)))
+                        }
+                        None => (Some(name.strip_suffix('/').unwrap_or(name)), contents),
+                    },
+                },
+            };
+
+            if let Some(name) = name {
+                members.push(Member {
+                    name,
+                    offset,
+                    date: header.date,
+                    uid: header.uid,
+                    gid: header.gid,
+                    mode: header.mode,
+                    size: header.size,
+                    archive: data,
+                    contents,
+                });
+            }
+
+            // Members are aligned to two bytes
+            offset = match stored {
+                true => start + size + size % 2,
+                false => start,
+            };
+        }
+
+        Ok(Self {
+            thin,
+            index: index.unwrap_or_default(),
+            members,
+        })
+    }
+
+    /// The entries of a symbol index: a big-endian count, the offsets of the members defining
+    /// each symbol and their NUL terminated names, with 64-bit words for `/SYM64/`.
+    fn symbol_index(bytes: &'a [u8], wide: bool) -> Result<Vec<IndexEntry<'a>>, Error> {
+        let word = if wide { 8 } else { 4 };
+        let read = |at: usize| -> Option<u64> {
+            let bytes = bytes.get(at..at + word)?;
+            Some(
+                bytes
+                    .iter()
+                    .fold(0, |value, &byte| value << 8 | byte as u64),
+            )
+        };
+
+        let count = read(0)
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(|| Error::Index("missing symbol count".into()))?;
+        let names = count
+            .checked_mul(word)
+            .and_then(|size| size.checked_add(word))
+            .and_then(|start| bytes.get(start..))
+            .ok_or_else(|| Error::Index(format!("{count} offsets do not fit")))?;
+
+        let mut names = names.split(|&byte| byte == 0);
+
+        (0..count)
+            .map(|index| {
+                let offset = read(word + index * word).unwrap_or_default();
+                let name = names
+                    .next()
+                    .and_then(|name| str::from_utf8(name).ok())
+                    .ok_or_else(|| Error::Index(format!("missing name of symbol {index}")))?;
+
+                Ok(IndexEntry { name, offset })
+            })
+            .collect()
+    }
+
+    /// Name at `position` of the `//` table, where they end with `/\n`.
+    fn long_name(names: &'a [u8], position: usize) -> Option<&'a str> {
+        let names = names.get(position..)?;
+        let end = names.iter().position(|&byte| byte == b'\n')?;
+        let name = str::from_utf8(&names[..end]).ok()?;
+
+        Some(name.strip_suffix('/').unwrap_or(name))
+    }
+
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Entries of the symbol index, empty for archives without one.
+    pub fn index(&self) -> &[IndexEntry<'a>] {
+        &self.index
+    }
+
+    pub fn members(&self) -> &[Member<'a>] {
+        &self.members
+    }
+
+    /// Member whose header is at `offset`, what the symbol index refers to.
+    pub fn member_at(&self, offset: u64) -> Option<&Member<'a>> {
+        self.members
+            .iter()
+            .find(|member| member.offset as u64 == offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Archive, Error, IndexEntry};
+    use crate::elf::Elf;
+    use crate::input::Lazy;
+    use std::io::Cursor;
+
+    #[test]
+    fn archive_of_lib() {
+        let archive = Archive::parse(include_bytes!("../test/lib.a")).unwrap();
+
+        assert!(!archive.is_thin());
+        assert_eq!(archive.members().len(), 1);
+
+        let member = archive.members()[0];
+        assert_eq!(member.name(), "lib.o");
+        assert_eq!(member.mode(), 0o644);
+        assert_eq!(member.data(), Some(&include_bytes!("../test/lib.o")[..]));
+        assert!(Elf::parse(member.data().unwrap()).is_ok());
+
+        assert_eq!(
+            archive.index(),
+            [IndexEntry {
+                name: "foo",
+                offset: member.offset() as u64
+            }]
+        );
+    }
+
+    #[test]
+    fn archive_with_long_names() {
+        let archive = Archive::parse(include_bytes!("../test/long.a")).unwrap();
+        let names: Vec<&str> = archive
+            .members()
+            .iter()
+            .map(|member| member.name())
+            .collect();
+
+        assert_eq!(names, ["a_member_with_a_long_name.o", "i386.o"]);
+        assert_eq!(
+            archive.member_at(archive.index()[1].offset).unwrap().name(),
+            "i386.o"
+        );
+    }
+
+    #[test]
+    fn thin_archive() {
+        let archive = Archive::parse(include_bytes!("../test/thin.a")).unwrap();
+
+        assert!(archive.is_thin());
+        assert!(archive
+            .members()
+            .iter()
+            .all(|member| member.data().is_none()));
+        assert_eq!(archive.members()[1].name(), "i386.o");
+        assert_eq!(archive.members()[1].size(), 804);
+        assert_eq!(archive.index().len(), 2);
+    }
+
+    #[test]
+    fn archive_of_bsd_names_and_sym64() {
+        let mut file = b"!<arch>\n".to_vec();
+        let header = |name: &str, size: usize| {
+            format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644)
+        };
+
+        // One symbol defined in the member at 0x58
+        let mut index = 1u64.to_be_bytes().to_vec();
+        index.extend(0x58u64.to_be_bytes());
+        index.extend(b"sym\0");
+        file.extend(header("/SYM64/", index.len()).bytes());
+        file.extend(index);
+        file.extend(header("#1/5", 8).bytes());
+        file.extend(b"a.o\0\0abc");
+
+        let archive = Archive::parse(&file).unwrap();
+        assert_eq!(archive.members()[0].name(), "a.o");
+        assert_eq!(archive.members()[0].data(), Some(&b"abc"[..]));
+        assert_eq!(
+            archive.member_at(archive.index()[0].offset).unwrap().name(),
+            "a.o"
+        );
+
+        assert!(matches!(
+            Archive::parse(&file[..file.len() - 1]),
+            Err(Error::Truncated { .. })
+        ));
+        assert!(matches!(Archive::parse(&b"\x7fELF"[..]), Err(Error::Magic)));
+    }
+
+    #[test]
+    fn archive_with_both_indexes() {
+        let mut file = b"!<arch>\n".to_vec();
+        let header = |name: &str, size: usize| {
+            format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644)
+        };
+
+        // Both point at the member at 0xA0, the second one and the one after it are skipped
+        let mut index = 1u32.to_be_bytes().to_vec();
+        index.extend(0xA0u32.to_be_bytes());
+        index.extend(b"sym\0");
+        file.extend(header("/", index.len()).bytes());
+        file.extend(&index);
+
+        let mut index64 = 1u64.to_be_bytes().to_vec();
+        index64.extend(0xA0u64.to_be_bytes());
+        index64.extend(b"bad\0");
+        file.extend(header("/SYM64/", index64.len()).bytes());
+        file.extend(index64);
+
+        file.extend(header("a.o/", 4).bytes());
+        file.extend(b"abcd");
+        file.extend(header("/", index.len()).bytes());
+        file.extend(&index);
+
+        let archive = Archive::parse(&file).unwrap();
+        assert_eq!(archive.members().len(), 1);
+        assert_eq!(
+            archive.index(),
+            [IndexEntry {
+                name: "sym",
+                offset: 0xA0
+            }]
+        );
+        assert_eq!(
+            archive.member_at(archive.index()[0].offset).unwrap().name(),
+            "a.o"
+        );
+    }
+
+    #[test]
+    fn members_are_read_as_accessed() {
+        let mut file = b"!<arch>\n".to_vec();
+        file.extend(
+            format!(
+                "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+                "big/", 0, 0, 0, 644, 0x10000
+            )
+            .bytes(),
+        );
+        file.extend([0xAA; 0x10000]);
+
+        let lazy = Lazy::new(Cursor::new(&file)).unwrap();
+        let archive = Archive::parse(&lazy).unwrap();
+        assert!(lazy.loaded() <= 0x1000);
+
+        let member = archive.members()[0];
+        assert_eq!(member.data(), Some(&file[0x44..]));
+        assert!(lazy.loaded() > 0x10000);
+    }
+}
@@ -0,0 +1,92 @@
+//! Raw byte output of the `hexdump` and `strings` subcommands, which work on any file.
+
+use std::io::{self, Write};
+
+/// `hexdump -C` of `bytes`, read from the file at `offset`.
+pub fn hexdump(out: &mut impl Write, bytes: &[u8], offset: u64) -> io::Result<()> {
+    for (index, line) in bytes.chunks(16).enumerate() {
+        write!(out, "{:08x} ", offset + index as u64 * 16)?;
+
+        for column in 0..16 {
+            if column == 8 {
+                write!(out, " ")?;
+            }
+
+            match line.get(column) {
+                Some(byte) => write!(out, " {byte:02x}")?,
+                None => write!(out, "   ")?,
+            }
+        }
+
+        let ascii: String = line
+            .iter()
+            .map(|&byte| match byte {
+                0x20..=0x7E => byte as char,
+                _ => '.',
+            })
+            .collect();
+
+        writeln!(out, "  |{ascii}|")?;
+    }
+
+    writeln!(out, "{:08x}", offset + bytes.len() as u64)
+}
+
+/// Runs of at least `min` printable ASCII characters in `bytes`, with their file offset as
+/// `strings -t x`.
+pub fn strings(out: &mut impl Write, bytes: &[u8], offset: u64, min: usize) -> io::Result<()> {
+    let printable = |byte: &u8| matches!(byte, 0x20..=0x7E | b'\t');
+    let mut start = 0;
+
+    for run in bytes.split(|byte| !printable(byte)) {
+        if run.len() >= min.max(1) {
+            writeln!(
+                out,
+                "{:7x} {}",
+                offset + start as u64,
+                String::from_utf8_lossy(run)
+            )?;
+        }
+
+        start += run.len() + 1;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hexdump, strings};
+
+    #[test]
+    fn hexdump_partial_line() {
+        let mut out = Vec::new();
+        hexdump(
+            &mut out,
+            b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0\x03\0",
+            0x40,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "00000040  7f 45 4c 46 02 01 01 00  00 00 00 00 00 00 00 00  |.ELF............|\n\
+             00000050  03 00                                             |..|\n\
+             00000052\n"
+        );
+    }
+
+    #[test]
+    fn strings_of_exe() {
+        let file = include_bytes!("../../test/exe");
+        let mut out = Vec::new();
+        strings(&mut out, file, 0, 4).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out
+            .lines()
+            .any(|line| line.ends_with(" /lib64/ld-linux-x86-64.so.2")));
+        assert!(out.lines().any(|line| line.ends_with(" libc.so.6")));
+        assert!(out.lines().all(|line| line[8..].len() >= 4));
+    }
+}
@@ -0,0 +1,544 @@
+//! The `--format json` document, its schema is described in `doc/json.md`.
+//!
+//! Fields are only ever added to the schema, anything else bumps [`SCHEMA`].
+
+use badelf::elf::common::{self, p, sh};
+use badelf::elf::note::Notes;
+use badelf::elf::reader::{Class, Endian};
+use badelf::elf::reloc::{Relocations, Relr};
+use badelf::elf::symbol::Symbols;
+use badelf::elf::version::Versions;
+use badelf::elf::{Elf, Error};
+use serde_json::{json, Map, Value};
+
+/// Version of the schema, the `schema` field of every document.
+pub const SCHEMA: u64 = 1;
+
+/// Parts of the document, each one a top level field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Ident,
+    Header,
+    Segments,
+    Sections,
+    Symbols,
+    Dynamic,
+    Relocations,
+    Versions,
+    Notes,
+    Core,
+}
+
+impl Part {
+    pub const ALL: [Part; 10] = [
+        Part::Ident,
+        Part::Header,
+        Part::Segments,
+        Part::Sections,
+        Part::Symbols,
+        Part::Dynamic,
+        Part::Relocations,
+        Part::Versions,
+        Part::Notes,
+        Part::Core,
+    ];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Part::Ident => "ident",
+            Part::Header => "header",
+            Part::Segments => "segments",
+            Part::Sections => "sections",
+            Part::Symbols => "symbols",
+            Part::Dynamic => "dynamic",
+            Part::Relocations => "relocations",
+            Part::Versions => "versions",
+            Part::Notes => "notes",
+            Part::Core => "core",
+        }
+    }
+}
+
+/// The document of `elf` with only the `parts` asked for.
+pub fn document(elf: &Elf, parts: &[Part]) -> Result<Value, Error> {
+    let mut document = Map::new();
+    document.insert("schema".into(), SCHEMA.into());
+
+    for part in parts {
+        let value = match part {
+            Part::Ident => ident(elf),
+            Part::Header => header(elf),
+            Part::Segments => segments(elf),
+            Part::Sections => sections(elf),
+            Part::Symbols => symbols(elf)?,
+            Part::Dynamic => dynamic(elf)?,
+            Part::Relocations => relocations(elf)?,
+            Part::Versions => versions(elf)?,
+            Part::Notes => notes(elf)?,
+            Part::Core => core(elf)?,
+        };
+
+        document.insert(part.key().into(), value);
+    }
+
+    Ok(Value::Object(document))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn ident(elf: &Elf) -> Value {
+    let ident = elf.ident();
+    let reader = elf.header().reader();
+
+    json!({
+        "class": ident.class(),
+        "class_name": match reader.class() {
+            Class::Elf32 => "ELF32",
+            Class::Elf64 => "ELF64",
+        },
+        "data": ident.endianness(),
+        "data_name": match reader.endian() {
+            Endian::Little => "little-endian",
+            Endian::Big => "big-endian",
+        },
+        "version": ident.version(),
+        "abi": ident.abi(),
+        "abi_name": common::ei_abi_name(ident.abi()),
+        "abi_version": ident.abi_version(),
+    })
+}
+
+fn header(elf: &Elf) -> Value {
+    let header = elf.header();
+
+    json!({
+        "type": header.r#type(),
+        "type_name": common::type_name(header.r#type()),
+        "machine": header.machine(),
+        "machine_name": common::machine_name(header.machine()),
+        "version": header.version(),
+        "entry": header.entry(),
+        "phoff": header.phoff(),
+        "shoff": header.shoff(),
+        "flags": header.flags(),
+        "ehsize": header.ehsize(),
+        "phentsize": header.phentzise(),
+        "phnum": header.phnum(),
+        "shentsize": header.shentsize(),
+        "shnum": header.shnum(),
+        "shstrndx": header.shstrndx(),
+    })
+}
+
+fn segments(elf: &Elf) -> Value {
+    elf.segments()
+        .map(|segment| {
+            json!({
+                "type": segment.r#type(),
+                "type_name": common::p_type_name(segment.r#type()),
+                "flags": segment.flags(),
+                "offset": segment.offset(),
+                "vaddr": segment.vaddr(),
+                "paddr": segment.paddr(),
+                "filesz": segment.filesz(),
+                "memsz": segment.memsz(),
+                "align": segment.align(),
+            })
+        })
+        .collect()
+}
+
+fn sections(elf: &Elf) -> Value {
+    elf.sections()
+        .map(|section| {
+            json!({
+                "name": section.name(),
+                "type": section.r#type(),
+                "type_name": common::sh_type_name(section.r#type()),
+                "flags": section.flags(),
+                "flags_name": common::sh_flags_name(section.flags()),
+                "addr": section.addr(),
+                "offset": section.offset(),
+                "size": section.size(),
+                "link": section.link(),
+                "info": section.info(),
+                "addralign": section.addralign(),
+                "entsize": section.entsize(),
+                "compression": section.compression(elf.data()).ok().flatten().map(|header| {
+                    json!({
+                        "type": header.r#type(),
+                        "type_name": common::ch_type_name(header.r#type()),
+                        "size": header.size(),
+                        "addralign": header.addralign(),
+                    })
+                }),
+            })
+        })
+        .collect()
+}
+
+fn symbols(elf: &Elf) -> Result<Value, Error> {
+    let sections = elf.sections();
+    let mut tables = Vec::new();
+
+    for (index, section) in sections.clone().enumerate() {
+        if !matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM) {
+            continue;
+        }
+
+        let symbols = Symbols::new(elf.data(), &sections, index)?;
+        let entries: Vec<Value> = symbols
+            .clone()
+            .map(|symbol| {
+                json!({
+                    "name": symbol.name(),
+                    "value": symbol.value(),
+                    "size": symbol.size(),
+                    "type": symbol.r#type(),
+                    "type_name": common::st_type_name(symbol.r#type()),
+                    "bind": symbol.bind(),
+                    "bind_name": common::st_bind_name(symbol.bind()),
+                    "visibility": symbol.visibility(),
+                    "visibility_name": common::st_visibility_name(symbol.visibility()),
+                    "shndx": symbol.shndx(),
+                    "section": symbol.section_index(),
+                    "version": symbol.version().and_then(|version| version.name()),
+                })
+            })
+            .collect();
+
+        tables.push(json!({
+            "section": index,
+            "name": symbols.name(),
+            "entries": entries,
+        }));
+    }
+
+    Ok(tables.into())
+}
+
+fn dynamic(elf: &Elf) -> Result<Value, Error> {
+    let Some(dynamic) = elf.dynamic()? else {
+        return Ok(Value::Null);
+    };
+
+    let entries: Vec<Value> = dynamic
+        .clone()
+        .map(|entry| {
+            json!({
+                "tag": entry.tag(),
+                "tag_name": common::d_tag_name(entry.tag()),
+                "value": entry.value(),
+                "string": entry.string(),
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "offset": dynamic.offset(),
+        "entries": entries,
+    }))
+}
+
+fn relocations(elf: &Elf) -> Result<Value, Error> {
+    let file = elf.data();
+    let header = elf.header();
+    let sections = elf.sections();
+    let mut tables = Vec::new();
+
+    for (index, section) in sections.clone().enumerate() {
+        let entries: Vec<Value> = match section.r#type() {
+            sh::r#type::REL | sh::r#type::RELA => {
+                Relocations::new(file, &header, &sections, index)?
+                    .map(|relocation| {
+                        json!({
+                            "offset": relocation.offset(),
+                            "info": relocation.info(),
+                            "type": relocation.r#type(),
+                            "type_name": relocation.type_name(),
+                            "sym": relocation.sym(),
+                            "symbol": relocation.symbol().and_then(|symbol| symbol.name()),
+                            "addend": relocation.addend(),
+                        })
+                    })
+                    .collect()
+            }
+            sh::r#type::RELR => Relr::new(file, &header, &sections, index)?
+                .map(|offset| json!({ "offset": offset }))
+                .collect(),
+            _ => continue,
+        };
+
+        tables.push(json!({
+            "section": index,
+            "name": section.name(),
+            "type_name": common::sh_type_name(section.r#type()),
+            "offset": section.offset(),
+            "entries": entries,
+        }));
+    }
+
+    Ok(tables.into())
+}
+
+fn versions(elf: &Elf) -> Result<Value, Error> {
+    let sections = elf.sections();
+
+    let Some(dynsym) = sections
+        .clone()
+        .position(|section| section.r#type() == sh::r#type::DYNSYM)
+    else {
+        return Ok(Value::Null);
+    };
+
+    let Some(versions) = Versions::new(elf.data(), &sections, dynsym)? else {
+        return Ok(Value::Null);
+    };
+
+    let definitions: Vec<Value> = versions
+        .definitions()
+        .map(|definition| {
+            json!({
+                "index": definition.index(),
+                "name": definition.name(),
+                "base": definition.is_base(),
+                "parent": definition.parent(),
+            })
+        })
+        .collect();
+
+    let needs: Vec<Value> = versions
+        .needs()
+        .map(|need| {
+            let versions: Vec<Value> = need
+                .versions()
+                .map(|version| {
+                    json!({
+                        "index": version.index(),
+                        "name": version.name(),
+                        "weak": version.is_weak(),
+                    })
+                })
+                .collect();
+
+            json!({
+                "file": need.file(),
+                "versions": versions,
+            })
+        })
+        .collect();
+
+    let max_required: Map<String, Value> = versions
+        .max_required()
+        .into_iter()
+        .map(|(library, version)| (library.into(), version.into()))
+        .collect();
+
+    Ok(json!({
+        "definitions": definitions,
+        "needs": needs,
+        "max_required": max_required,
+    }))
+}
+
+fn notes(elf: &Elf) -> Result<Value, Error> {
+    let file = elf.data();
+    let header = elf.header();
+    let sections = elf.sections();
+    let mut entries = Vec::new();
+
+    let mut push = |section: Option<&str>, notes: Notes| {
+        for note in notes {
+            entries.push(json!({
+                "section": section,
+                "owner": note.name(),
+                "type": note.r#type(),
+                "type_name": note
+                    .name()
+                    .and_then(|owner| common::n_type_name(owner, note.r#type())),
+                "desc": hex(note.desc()),
+                "build_id": note.build_id().map(hex),
+            }));
+        }
+    };
+
+    // Same as the text output, segments are only read for files stripped of their sections
+    if sections
+        .clone()
+        .any(|section| section.r#type() == sh::r#type::NOTE)
+    {
+        for section in sections.filter(|section| section.r#type() == sh::r#type::NOTE) {
+            push(
+                section.name(),
+                Notes::from_section(file, &header, &section)?,
+            );
+        }
+    } else {
+        for segment in elf
+            .segments()
+            .filter(|segment| segment.r#type() == p::r#type::NOTE)
+        {
+            push(None, Notes::from_segment(file, &header, &segment)?);
+        }
+    }
+
+    Ok(entries.into())
+}
+
+fn core(elf: &Elf) -> Result<Value, Error> {
+    let Some(core) = elf.core()? else {
+        return Ok(Value::Null);
+    };
+
+    let process = core.process().map(|process| {
+        json!({
+            "pid": process.pid(),
+            "ppid": process.ppid(),
+            "pgrp": process.pgrp(),
+            "sid": process.sid(),
+            "uid": process.uid(),
+            "gid": process.gid(),
+            "state": process.state().to_string(),
+            "nice": process.nice(),
+            "flags": process.flags(),
+            "fname": process.fname(),
+            "psargs": process.psargs(),
+        })
+    });
+
+    let signal = core.signal().map(|signal| {
+        json!({
+            "signo": signal.signo(),
+            "signo_name": common::signal_name(signal.signo()),
+            "errno": signal.errno(),
+            "code": signal.code(),
+            "code_name": signal.code_name(),
+            "address": signal.address(),
+        })
+    });
+
+    let threads: Vec<Value> = core
+        .threads()
+        .iter()
+        .map(|thread| {
+            let registers = thread.registers().map(|registers| {
+                registers
+                    .into_iter()
+                    .map(|(name, value)| json!({ "name": name, "value": value }))
+                    .collect::<Vec<_>>()
+            });
+
+            json!({
+                "pid": thread.pid(),
+                "signal": thread.signal(),
+                "signal_name": common::signal_name(thread.signal()),
+                "registers": registers,
+            })
+        })
+        .collect();
+
+    let files = core.files().map(|files| {
+        let page_size = files.page_size();
+        let entries: Vec<Value> = files
+            .map(|file| {
+                json!({
+                    "start": file.start,
+                    "end": file.end,
+                    "offset": file.offset,
+                    "path": file.path,
+                })
+            })
+            .collect();
+
+        json!({
+            "page_size": page_size,
+            "entries": entries,
+        })
+    });
+
+    let auxv = core.auxv().map(|auxv| {
+        auxv.map(|(r#type, value)| {
+            json!({
+                "type": r#type,
+                "type_name": common::at_name(r#type),
+                "value": value,
+            })
+        })
+        .collect::<Vec<_>>()
+    });
+
+    Ok(json!({
+        "process": process,
+        "signal": signal,
+        "threads": threads,
+        "files": files,
+        "auxv": auxv,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{document, Part, SCHEMA};
+    use badelf::elf::Elf;
+
+    #[test]
+    fn document_of_exe() {
+        let file = include_bytes!("../../test/exe");
+        let elf = Elf::parse(file).unwrap();
+        let document = document(&elf, &Part::ALL).unwrap();
+
+        assert_eq!(document["schema"], SCHEMA);
+        assert_eq!(document["ident"]["class_name"], "ELF64");
+        assert_eq!(document["header"]["entry"], 0x1040);
+        assert_eq!(document["segments"].as_array().unwrap().len(), 13);
+        assert_eq!(document["segments"][2]["type_name"], "LOAD");
+        assert_eq!(document["sections"][27]["name"], ".symtab");
+
+        let main = document["symbols"][1]["entries"][21].clone();
+        assert_eq!(document["symbols"][1]["name"], ".symtab");
+        assert_eq!(main["name"], "main");
+        assert_eq!(main["value"], 0x1160);
+        assert_eq!(main["type_name"], "FUNC");
+
+        let printf = &document["symbols"][0]["entries"][3];
+        assert_eq!(printf["version"], "GLIBC_2.2.5");
+
+        assert!(document["dynamic"]["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|entry| entry["tag_name"] == "NEEDED" && entry["string"] == "libc.so.6"));
+        assert!(document["notes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|note| note["section"] == ".note.gnu.build-id" && note["build_id"].is_string()));
+    }
+
+    #[test]
+    fn document_of_object() {
+        let file = include_bytes!("../../test/lib.o");
+        let elf = Elf::parse(file).unwrap();
+        let document = document(&elf, &[Part::Header, Part::Dynamic]).unwrap();
+
+        assert_eq!(document["header"]["type_name"], "Relocatable file");
+        assert!(document["dynamic"].is_null());
+        assert!(document.get("segments").is_none());
+    }
+
+    #[test]
+    fn document_of_core() {
+        let file = include_bytes!("../../test/core");
+        let elf = Elf::parse(file).unwrap();
+        let document = document(&elf, &[Part::Core]).unwrap();
+        let core = &document["core"];
+
+        assert_eq!(core["process"]["fname"], "crash");
+        assert_eq!(core["signal"]["signo_name"], "SIGSEGV");
+        assert_eq!(core["signal"]["address"], 0x10);
+        assert_eq!(core["threads"].as_array().unwrap().len(), 2);
+        assert_eq!(core["threads"][0]["registers"][16]["name"], "rip");
+    }
+}
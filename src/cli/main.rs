@@ -0,0 +1,1214 @@
+mod dump;
+mod json;
+mod readelf;
+
+use badelf::ar::Archive;
+use badelf::dwarf::common::{at_name, form_name, tag_name, ut_name};
+use badelf::dwarf::layout::Layout;
+use badelf::dwarf::line::LineIndex;
+use badelf::dwarf::{Dwarf, Value};
+use badelf::elf::common::{self, p, sh};
+use badelf::elf::note::Notes;
+use badelf::elf::reloc::{Relocations, Relr};
+use badelf::elf::symbol::Symbols;
+use badelf::elf::version::Versions;
+use badelf::elf::Elf;
+use badelf::input::{Data, Input};
+use clap::{Parser, Subcommand, ValueEnum};
+use json::Part;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::error;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Human readable text
+    Text,
+    /// A JSON document, see doc/json.md for its schema
+    Json,
+    /// The layout of binutils' readelf, for scripts parsing it
+    Readelf,
+}
+
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(long, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Same as `--format readelf`
+    #[arg(long, global = true)]
+    readelf: bool,
+
+    /// Do not truncate names in the readelf format, as `readelf -W`
+    #[arg(short = 'W', long, global = true)]
+    wide: bool,
+
+    #[command(flatten)]
+    files: Files,
+}
+
+#[derive(clap::Args, Debug)]
+struct Files {
+    /// Files to read, each one under a `File:` line when there are several
+    #[arg(default_value = "a.out")]
+    files: Vec<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print the ELF header
+    Header {
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the program headers
+    Segments {
+        /// Only the segments of this type, e.g. LOAD
+        #[arg(long = "type")]
+        r#type: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the section headers
+    Sections {
+        /// Only the sections whose name contains this
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Only the sections of this type, e.g. PROGBITS
+        #[arg(long = "type")]
+        r#type: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the entries of the .symtab and .dynsym sections
+    Symbols {
+        #[command(flatten)]
+        filter: SymbolFilter,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the entries of the dynamic section
+    Dynamic {
+        /// Only the entries with this tag, e.g. NEEDED
+        #[arg(long)]
+        tag: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the entries of the REL, RELA and RELR sections
+    Relocs {
+        /// Only the relocation sections whose name contains this
+        #[arg(long)]
+        section: Option<String>,
+
+        /// Only the relocations against symbols whose name contains this
+        #[arg(long)]
+        symbol: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the defined and required symbol versions, with the highest one needed per library
+    Versions {
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the notes of the SHT_NOTE sections, or of the PT_NOTE segments without them
+    Notes {
+        /// Only the notes of this owner, e.g. GNU
+        #[arg(long)]
+        owner: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// Show the process, crashing signal, threads with their registers and memory map of a core
+    /// dump
+    Core {
+        #[command(flatten)]
+        files: Files,
+    },
+    /// Find the segment, section and symbol of addresses, read from stdin when none are given
+    Addr2sym {
+        /// Addresses to look up, decimal or hex with 0x
+        addresses: Vec<String>,
+
+        /// Look up file offsets instead of virtual addresses
+        #[arg(long)]
+        offsets: bool,
+
+        /// File the addresses are in
+        #[arg(short = 'e', long, default_value = "a.out")]
+        file: String,
+    },
+    /// Find the source line and symbol of addresses, or the addresses of lines given as
+    /// FILE:LINE, from the DWARF line tables. Read from stdin when none are given
+    Addr2line {
+        /// Addresses, decimal or hex with 0x, or FILE:LINE with FILE any end of the path
+        queries: Vec<String>,
+
+        /// File the addresses are in
+        #[arg(short = 'e', long, default_value = "a.out")]
+        file: String,
+    },
+    /// Dump the units and debugging information entries of .debug_info
+    Info {
+        #[command(flatten)]
+        files: Files,
+    },
+    /// Show where the members of structures, unions and classes lie and the holes between
+    /// them, from the DWARF debugging information, as pahole
+    Layout {
+        /// Only the types with this name
+        #[arg(long)]
+        name: Option<String>,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// Dump bytes in hex and ASCII, of the whole file unless narrowed down
+    Hexdump {
+        #[command(flatten)]
+        range: Range,
+
+        #[command(flatten)]
+        files: Files,
+    },
+    /// List the runs of printable ASCII characters, of the whole file unless narrowed down
+    Strings {
+        /// Shortest run listed
+        #[arg(short = 'n', long, default_value_t = 4)]
+        min_len: usize,
+
+        #[command(flatten)]
+        range: Range,
+
+        #[command(flatten)]
+        files: Files,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct SymbolFilter {
+    /// Only the symbols whose name contains this
+    #[arg(long)]
+    name: Option<String>,
+
+    /// Only the symbols of this type, e.g. FUNC
+    #[arg(long = "type")]
+    r#type: Option<String>,
+
+    /// Only the symbols of this binding, e.g. GLOBAL
+    #[arg(long)]
+    bind: Option<String>,
+
+    /// Only the symbols defined in the file
+    #[arg(long, conflicts_with = "undefined")]
+    defined: bool,
+
+    /// Only the symbols the file needs from others
+    #[arg(long)]
+    undefined: bool,
+
+    /// Only the .dynsym table
+    #[arg(long)]
+    dynamic: bool,
+}
+
+impl SymbolFilter {
+    fn is_set(&self) -> bool {
+        self.name.is_some()
+            || self.r#type.is_some()
+            || self.bind.is_some()
+            || self.defined
+            || self.undefined
+            || self.dynamic
+    }
+}
+
+/// Bytes `hexdump` and `strings` read, `offset` is from the start of the file or the section.
+#[derive(clap::Args, Debug)]
+struct Range {
+    /// Only the contents of the section with this name
+    #[arg(long)]
+    section: Option<String>,
+
+    /// First byte read, decimal or hex with 0x
+    #[arg(long, value_parser = number, default_value_t = 0)]
+    offset: u64,
+
+    /// Number of bytes read, decimal or hex with 0x
+    #[arg(long, value_parser = number)]
+    length: Option<u64>,
+}
+
+impl Range {
+    fn is_set(&self) -> bool {
+        self.section.is_some() || self.offset != 0 || self.length.is_some()
+    }
+}
+
+impl Command {
+    fn files(&self) -> &[String] {
+        match self {
+            Command::Header { files }
+            | Command::Segments { files, .. }
+            | Command::Sections { files, .. }
+            | Command::Symbols { files, .. }
+            | Command::Dynamic { files, .. }
+            | Command::Relocs { files, .. }
+            | Command::Versions { files }
+            | Command::Notes { files, .. }
+            | Command::Core { files }
+            | Command::Info { files }
+            | Command::Layout { files, .. }
+            | Command::Hexdump { files, .. }
+            | Command::Strings { files, .. } => &files.files,
+            Command::Addr2sym { file, .. } | Command::Addr2line { file, .. } => {
+                std::slice::from_ref(file)
+            }
+        }
+    }
+
+    /// Whether it narrows down what is listed, which only the text output does.
+    fn is_filtered(&self) -> bool {
+        match self {
+            Command::Header { .. }
+            | Command::Versions { .. }
+            | Command::Core { .. }
+            | Command::Addr2sym { .. }
+            | Command::Addr2line { .. }
+            | Command::Info { .. } => false,
+            Command::Segments { r#type, .. } => r#type.is_some(),
+            Command::Sections { name, r#type, .. } => name.is_some() || r#type.is_some(),
+            Command::Symbols { filter, .. } => filter.is_set(),
+            Command::Dynamic { tag, .. } => tag.is_some(),
+            Command::Relocs {
+                section, symbol, ..
+            } => section.is_some() || symbol.is_some(),
+            Command::Notes { owner, .. } => owner.is_some(),
+            Command::Layout { name, .. } => name.is_some(),
+            Command::Hexdump { range, .. } | Command::Strings { range, .. } => range.is_set(),
+        }
+    }
+
+    /// Whether it has no JSON or readelf output.
+    fn is_text_only(&self) -> bool {
+        matches!(
+            self,
+            Command::Hexdump { .. }
+                | Command::Strings { .. }
+                | Command::Addr2sym { .. }
+                | Command::Addr2line { .. }
+                | Command::Info { .. }
+                | Command::Layout { .. }
+        )
+    }
+
+    /// Whether it has a JSON output but no readelf one.
+    fn is_without_readelf(&self) -> bool {
+        matches!(self, Command::Versions { .. } | Command::Core { .. })
+    }
+}
+
+/// A decimal number, or a hex one with `0x`.
+fn number(text: &str) -> Result<u64, String> {
+    let number = match text.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => text.parse(),
+    };
+
+    number.map_err(|err| err.to_string())
+}
+
+/// Whether `name` is the one a filter asks for, ignoring case as the names of enumerated values
+/// are uppercase.
+fn is_named(name: &str, filter: &Option<String>) -> bool {
+    filter
+        .as_ref()
+        .is_none_or(|filter| name.eq_ignore_ascii_case(filter))
+}
+
+/// Whether `name` contains the text a filter asks for.
+fn contains(name: Option<&str>, filter: &Option<String>) -> bool {
+    filter
+        .as_ref()
+        .is_none_or(|filter| name.is_some_and(|name| name.contains(filter.as_str())))
+}
+
+fn load(elf: &Elf) -> Result<(), Box<dyn error::Error>> {
+    header(elf)?;
+    segments(elf, &None)?;
+    sections(elf, &None, &None)
+}
+
+fn header(elf: &Elf) -> Result<(), Box<dyn error::Error>> {
+    let header = elf.header();
+
+    println!("{}", header.ident());
+    println!("{header}");
+    println!("{:?}", header.as_ref());
+
+    Ok(())
+}
+
+fn segments(elf: &Elf, r#type: &Option<String>) -> Result<(), Box<dyn error::Error>> {
+    for (index, segment) in elf.segments().enumerate() {
+        if is_named(common::p_type_name(segment.r#type()), r#type) {
+            println!("[{index:>2}] {segment}");
+        }
+    }
+
+    Ok(())
+}
+
+fn sections(
+    elf: &Elf,
+    name: &Option<String>,
+    r#type: &Option<String>,
+) -> Result<(), Box<dyn error::Error>> {
+    for (index, section) in elf.sections().enumerate() {
+        if !contains(section.name(), name)
+            || !is_named(common::sh_type_name(section.r#type()), r#type)
+        {
+            continue;
+        }
+
+        match section.compression(elf.data()) {
+            Ok(Some(header)) => println!(
+                "[{index:>2}] {section} compression={} uncompressed_size={:#08X}",
+                common::ch_type_name(header.r#type()),
+                header.size()
+            ),
+            _ => println!("[{index:>2}] {section}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn symbols(elf: &Elf, filter: &SymbolFilter) -> Result<(), Box<dyn error::Error>> {
+    let file = elf.data();
+    let sections = elf.sections();
+    let tables: &[u32] = match filter.dynamic {
+        true => &[sh::r#type::DYNSYM],
+        false => &[sh::r#type::SYMTAB, sh::r#type::DYNSYM],
+    };
+
+    for (index, section) in sections.clone().enumerate() {
+        if !tables.contains(&section.r#type()) {
+            continue;
+        }
+
+        let symbols = Symbols::new(file, &sections, index)?;
+
+        println!(
+            "Symbol table '{}' contains {} entries:",
+            symbols.name().unwrap_or("<corrupt>"),
+            symbols.num()
+        );
+
+        for (index, symbol) in symbols.enumerate() {
+            if contains(symbol.name(), &filter.name)
+                && is_named(common::st_type_name(symbol.r#type()), &filter.r#type)
+                && is_named(common::st_bind_name(symbol.bind()), &filter.bind)
+                && (!filter.defined || !symbol.is_undefined())
+                && (!filter.undefined || symbol.is_undefined())
+            {
+                println!("{index:>6}: {symbol}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn relocations(
+    elf: &Elf,
+    name: &Option<String>,
+    symbol: &Option<String>,
+) -> Result<(), Box<dyn error::Error>> {
+    let file = elf.data();
+    let header = elf.header();
+    let sections = elf.sections();
+
+    for (index, section) in sections.clone().enumerate() {
+        if !contains(section.name(), name) {
+            continue;
+        }
+
+        match section.r#type() {
+            sh::r#type::REL | sh::r#type::RELA => {
+                let relocations = Relocations::new(file, &header, &sections, index)?;
+
+                println!(
+                    "Relocation section '{}' at offset {:#X} contains {} entries:",
+                    relocations.name().unwrap_or("<corrupt>"),
+                    section.offset(),
+                    relocations.len()
+                );
+
+                for relocation in relocations {
+                    let target = relocation.symbol().and_then(|symbol| symbol.name());
+
+                    if contains(target, symbol) {
+                        println!("{relocation}");
+                    }
+                }
+            }
+            // Relative relocations have no symbol to filter on
+            sh::r#type::RELR if symbol.is_none() => {
+                let relr = Relr::new(file, &header, &sections, index)?;
+
+                println!(
+                    "Relocation section '{}' at offset {:#X} contains {} entries:",
+                    relr.name().unwrap_or("<corrupt>"),
+                    section.offset(),
+                    relr.clone().count()
+                );
+
+                for address in relr {
+                    println!("offset={address:#018X}");
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+fn dynamic(elf: &Elf, tag: &Option<String>) -> Result<(), Box<dyn error::Error>> {
+    match elf.dynamic()? {
+        Some(dynamic) => {
+            println!(
+                "Dynamic section at offset {:#x} contains {} entries:",
+                dynamic.offset(),
+                dynamic.clone().count()
+            );
+            println!("  Tag        Type                         Name/Value");
+
+            for entry in dynamic {
+                if is_named(common::d_tag_name(entry.tag()), tag) {
+                    println!(" {entry}");
+                }
+            }
+        }
+        None => println!("There is no dynamic section in this file."),
+    }
+
+    Ok(())
+}
+
+fn notes(elf: &Elf, owner: &Option<String>) -> Result<(), Box<dyn error::Error>> {
+    let file = elf.data();
+    let header = elf.header();
+    let sections = elf.sections();
+
+    let print = |notes: Notes| {
+        println!("  Owner                Data size \tDescription");
+
+        for note in notes {
+            if is_named(note.name().unwrap_or_default(), owner) {
+                println!("  {note}");
+            }
+        }
+    };
+
+    // Sections name each note, segments are only used for files that were stripped of them
+    if sections
+        .clone()
+        .any(|section| section.r#type() == sh::r#type::NOTE)
+    {
+        for section in sections {
+            if section.r#type() != sh::r#type::NOTE {
+                continue;
+            }
+
+            println!(
+                "\nDisplaying notes found in: {}",
+                section.name().unwrap_or("<corrupt>")
+            );
+            print(Notes::from_section(file, &header, &section)?);
+        }
+    } else {
+        for segment in elf.segments() {
+            if segment.r#type() != p::r#type::NOTE {
+                continue;
+            }
+
+            println!(
+                "\nDisplaying notes found at file offset {:#010x} with length {:#010x}:",
+                segment.offset(),
+                segment.filesz()
+            );
+            print(Notes::from_segment(file, &header, &segment)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn versions(elf: &Elf) -> Result<(), Box<dyn error::Error>> {
+    let file = elf.data();
+    let sections = elf.sections();
+
+    let Some(dynsym) = sections
+        .clone()
+        .position(|section| section.r#type() == sh::r#type::DYNSYM)
+    else {
+        println!("No version information found in this file.");
+        return Ok(());
+    };
+
+    let Some(versions) = Versions::new(file, &sections, dynsym)? else {
+        println!("No version information found in this file.");
+        return Ok(());
+    };
+
+    println!("Version definitions:");
+    for definition in versions.definitions() {
+        print!(
+            "  {:>3}: {}",
+            definition.index(),
+            definition.name().unwrap_or("<corrupt>")
+        );
+
+        if definition.is_base() {
+            print!(" (BASE)");
+        }
+
+        match definition.parent() {
+            Some(parent) => println!(" parent={}", parent),
+            None => println!(),
+        }
+    }
+
+    println!("Version needs:");
+    for need in versions.needs() {
+        println!("  {}:", need.file().unwrap_or("<corrupt>"));
+
+        for version in need.versions() {
+            print!(
+                "    {:>3}: {}",
+                version.index(),
+                version.name().unwrap_or("<corrupt>")
+            );
+
+            if version.is_weak() {
+                print!(" (WEAK)");
+            }
+
+            println!();
+        }
+    }
+
+    println!("Maximum required versions:");
+    for (library, version) in versions.max_required() {
+        println!("  {}: {}", library, version);
+    }
+
+    Ok(())
+}
+
+fn core(elf: &Elf) -> Result<(), Box<dyn error::Error>> {
+    let Some(core) = elf.core()? else {
+        println!("This file is not a core dump.");
+        return Ok(());
+    };
+
+    let word = elf.header().reader().by_class(8, 16);
+
+    if let Some(process) = core.process() {
+        println!(
+            "Process {} ({}), parent {}, state {}, uid {}, gid {}",
+            process.pid(),
+            process.fname().unwrap_or("<corrupt>"),
+            process.ppid(),
+            process.state(),
+            process.uid(),
+            process.gid()
+        );
+        println!(
+            "  Command line: {}",
+            process.psargs().unwrap_or("<corrupt>")
+        );
+    }
+
+    if let Some(signal) = core.signal() {
+        print!(
+            "Killed by {} ({})",
+            common::signal_name(signal.signo()),
+            signal.signo()
+        );
+
+        match signal.code_name() {
+            Some(code) => print!(", {code}"),
+            None => print!(", code {}", signal.code()),
+        }
+
+        match signal.address() {
+            Some(address) => println!(" at {address:#x}"),
+            None => println!(),
+        }
+    }
+
+    for thread in core.threads() {
+        print!("\nThread {}", thread.pid());
+        match thread.signal() {
+            0 => println!(":"),
+            signal => println!(" received {}:", common::signal_name(signal)),
+        }
+
+        let Some(registers) = thread.registers() else {
+            println!(
+                "  Registers of {} are not known",
+                common::machine_name(elf.header().machine())
+            );
+            continue;
+        };
+
+        for line in registers.chunks(4) {
+            let line: Vec<String> = line
+                .iter()
+                .map(|(name, value)| format!("{name:>8} {value:#0width$x}", width = word + 2))
+                .collect();
+
+            println!("{}", line.join(" "));
+        }
+    }
+
+    if let Some(files) = core.files() {
+        println!("\nMapped files (page size {:#x}):", files.page_size());
+        println!(
+            "  {:<w$} {:<w$} {:<w$} Path",
+            "Start",
+            "End",
+            "Offset",
+            w = word + 2
+        );
+
+        for file in files {
+            println!(
+                "  {:#0w$x} {:#0w$x} {:#0w$x} {}",
+                file.start,
+                file.end,
+                file.offset,
+                file.path.unwrap_or("<corrupt>"),
+                w = word + 2
+            );
+        }
+    }
+
+    if let Some(auxv) = core.auxv() {
+        println!("\nAuxiliary vector:");
+
+        for (r#type, value) in auxv {
+            println!("  {:<20} {value:#x}", common::at_name(r#type));
+        }
+    }
+
+    Ok(())
+}
+
+/// Answers every query, or every word of stdin when there are none. Answers are flushed as
+/// they are written so that other programs can wait on them, queries `answer` fails are reported
+/// and skipped.
+fn answer(
+    queries: &[String],
+    mut answer: impl FnMut(&str) -> Result<String, String>,
+) -> Result<(), Box<dyn error::Error>> {
+    let words: Box<dyn Iterator<Item = io::Result<String>>> = match queries.is_empty() {
+        false => Box::new(queries.iter().cloned().map(Ok)),
+        true => Box::new(io::stdin().lines().flat_map(|line| {
+            match line {
+                Ok(line) => line
+                    .split_whitespace()
+                    .map(|word| Ok(word.into()))
+                    .collect(),
+                Err(err) => vec![Err(err)],
+            }
+        })),
+    };
+
+    let mut out = io::stdout().lock();
+    let (mut total, mut failed) = (0, 0);
+
+    for word in words {
+        let word = word?;
+        total += 1;
+
+        match answer(&word) {
+            Ok(answer) => {
+                out.write_all(answer.as_bytes())?;
+                out.flush()?;
+            }
+            Err(err) => {
+                eprintln!("cli: {word}: {err}");
+                failed += 1;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} of {total} queries failed").into()),
+    }
+}
+
+fn addr2sym(elf: &Elf, addresses: &[String], offsets: bool) -> Result<(), Box<dyn error::Error>> {
+    let index = elf.address_index()?;
+
+    answer(addresses, |address| {
+        let address = number(address)?;
+        let location = match offsets {
+            true => index.lookup_offset(address),
+            false => Some(index.lookup(address)),
+        };
+
+        let Some(location) = location else {
+            return Ok(format!("{address:#x} not loaded\n"));
+        };
+
+        let or_none = |value: Option<String>| value.unwrap_or_else(|| "-".into());
+
+        Ok(format!(
+            "{address:#x} {location} address={:#x} offset={} section={} segment={}\n",
+            location.address(),
+            or_none(location.offset().map(|offset| format!("{offset:#x}"))),
+            or_none(
+                location
+                    .section()
+                    .map(|(_, section)| section.name().unwrap_or("<corrupt>").into())
+            ),
+            or_none(location.segment().map(|(index, _)| index.to_string())),
+        ))
+    })
+}
+
+/// Source line and symbol of addresses, or addresses and symbols of `FILE:LINE` queries.
+fn addr2line(elf: &Elf, queries: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let dwarf = Dwarf::new(elf)?;
+    let lines = LineIndex::new(&dwarf)?;
+    let symbols = elf.address_index()?;
+
+    answer(queries, |query| {
+        // Both as `addr2line`, unknown lines are `??:0`
+        if let Some((path, line)) = query.rsplit_once(':') {
+            let line = line.parse().map_err(|err| format!("line {line}: {err}"))?;
+            let addresses = lines.addresses(path, line);
+
+            if addresses.is_empty() {
+                return Err("no code for this line".into());
+            }
+
+            return Ok(addresses
+                .iter()
+                .map(|line| {
+                    let address = line.row().address;
+                    format!("{address:#x} {} {line}\n", symbols.lookup(address))
+                })
+                .collect());
+        }
+
+        let address = number(query)?;
+        let line = match lines.lookup(address) {
+            Some(line) => line.to_string(),
+            None => "??:0".into(),
+        };
+
+        Ok(format!("{address:#x} {} {line}\n", symbols.lookup(address)))
+    })
+}
+
+/// Name of a `DW_*` value, the value itself when it is unknown.
+fn dw_name(name: &str, value: u64) -> String {
+    match name {
+        "UNKNOWN" => format!("{value:#x}"),
+        name => name.into(),
+    }
+}
+
+/// Units of `.debug_info` and their entries, indented by depth.
+fn info(elf: &Elf) -> Result<(), Box<dyn error::Error>> {
+    let dwarf = Dwarf::new(elf)?;
+    let mut out = io::stdout().lock();
+
+    for unit in dwarf.units() {
+        let unit = unit?;
+        let encoding = unit.encoding();
+
+        writeln!(
+            out,
+            "Unit at {:#x}: version {}, {}, abbrev offset {:#x}, address size {}, offset size {}",
+            unit.offset(),
+            encoding.version,
+            ut_name(unit.unit_type()),
+            unit.abbrev_offset(),
+            encoding.address_size,
+            encoding.offset_size
+        )?;
+
+        for entry in unit.entries() {
+            let (depth, die) = entry?;
+            let indent = "  ".repeat(depth + 1);
+
+            writeln!(
+                out,
+                "{indent}<{:#x}> {}",
+                die.offset(),
+                dw_name(tag_name(die.tag()), die.tag())
+            )?;
+
+            for attribute in die.attributes() {
+                let value = match attribute.value {
+                    Value::Address(address) => format!("{address:#x}"),
+                    Value::AddressIndex(index) => format!("(address index {index})"),
+                    Value::Unsigned(value) => value.to_string(),
+                    Value::Signed(value) => value.to_string(),
+                    Value::Flag(flag) => flag.to_string(),
+                    Value::Block(bytes) => {
+                        let bytes: Vec<_> =
+                            bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                        format!("[{}]", bytes.join(" "))
+                    }
+                    Value::String(string) => string.unwrap_or("<corrupt>").into(),
+                    Value::StringIndex(index) => format!("(string index {index})"),
+                    Value::Reference(offset) => format!("<{:#x}>", unit.offset() as u64 + offset),
+                    Value::InfoReference(offset) => format!("<{offset:#x}>"),
+                    Value::Signature(signature) => format!("signature {signature:#018x}"),
+                    Value::SectionOffset(offset) => format!("{offset:#x}"),
+                    Value::ListIndex(index) => format!("(list index {index})"),
+                    Value::Supplementary(offset) => format!("(supplementary {offset:#x})"),
+                };
+
+                writeln!(
+                    out,
+                    "{indent}    {:<24} {:<18} {value}",
+                    dw_name(at_name(attribute.name), attribute.name),
+                    dw_name(form_name(attribute.form), attribute.form)
+                )?;
+            }
+        }
+    }
+
+    if dwarf.section(".debug_info").is_empty() {
+        writeln!(out, "This file has no debugging information.")?;
+    }
+
+    Ok(out.flush()?)
+}
+
+/// Layouts of the structures of every unit, the ones defined in several units only once.
+fn layouts(elf: &Elf, name: &Option<String>) -> Result<(), Box<dyn error::Error>> {
+    let dwarf = Dwarf::new(elf)?;
+    let mut out = io::stdout().lock();
+    let mut shown = HashSet::new();
+
+    for unit in dwarf.units() {
+        let tree = unit?.tree()?;
+
+        for layout in Layout::of_unit(&tree) {
+            if name
+                .as_deref()
+                .is_some_and(|name| layout.name() != Some(name))
+            {
+                continue;
+            }
+
+            let text = layout.to_string();
+            if shown.insert(text.clone()) {
+                writeln!(out, "{text}\n")?;
+            }
+        }
+    }
+
+    if dwarf.section(".debug_info").is_empty() {
+        writeln!(out, "This file has no debugging information.")?;
+    }
+
+    Ok(out.flush()?)
+}
+
+/// Bytes to dump and the offset of the first one.
+type Dump<'a> = (Cow<'a, [u8]>, u64);
+
+/// Bytes of `data` in `range` and the offset they start at. The file is only parsed when a
+/// section is asked for, so that any file can be dumped.
+fn bytes<'a>(data: Data<'a>, range: &Range) -> Result<Dump<'a>, Box<dyn error::Error>> {
+    let (contents, start) = match &range.section {
+        Some(name) => {
+            let elf = Elf::parse(data)?;
+            let section = elf
+                .sections()
+                .by_name(name)
+                .ok_or_else(|| format!("no section named {name}"))?;
+
+            // Compressed sections are dumped decompressed, from offset 0 as they are not in
+            // the file that way
+            match section.contents(data)? {
+                Cow::Borrowed(bytes) => (Cow::Borrowed(bytes), section.offset()),
+                contents => (contents, 0),
+            }
+        }
+        None => (
+            Cow::Borrowed(data.get(0..data.len()).ok_or("failed to read the file")?),
+            0,
+        ),
+    };
+
+    let offset = usize::try_from(range.offset)
+        .ok()
+        .filter(|&offset| offset <= contents.len())
+        .ok_or_else(|| format!("offset {:#x} is past the end", range.offset))?;
+    let end = range
+        .length
+        .and_then(|length| usize::try_from(length).ok())
+        .map_or(contents.len(), |length| {
+            offset.saturating_add(length).min(contents.len())
+        });
+
+    let bytes = match contents {
+        Cow::Borrowed(bytes) => Cow::Borrowed(&bytes[offset..end]),
+        Cow::Owned(bytes) => Cow::Owned(bytes[offset..end].to_vec()),
+    };
+
+    Ok((bytes, start + range.offset))
+}
+
+/// Runs the command on every member of the archive at `path`, each under a `File:` line naming
+/// it as `archive(member)`.
+fn archive(
+    args: &Args,
+    format: Format,
+    path: &str,
+    input: &Input,
+) -> Result<(), Box<dyn error::Error>> {
+    let archive = Archive::parse(input)?;
+    let mut failed = 0;
+
+    if format == Format::Text
+        && matches!(args.command, Some(Command::Symbols { .. }))
+        && !archive.index().is_empty()
+    {
+        println!("Archive index:");
+        for entry in archive.index() {
+            let member = archive.member_at(entry.offset);
+            println!(
+                "{} in {}",
+                entry.name,
+                member.map_or("<corrupt>", |member| member.name())
+            );
+        }
+    }
+
+    for member in archive.members() {
+        let name = format!("{path}({})", member.name());
+
+        if format != Format::Json {
+            println!("\nFile: {name}");
+        }
+
+        let result = match member.data() {
+            Some(data) => run(args, format, &name, data.into()),
+            // Members of thin archives are relative to it
+            None if archive.is_thin() => {
+                let file = Path::new(path).with_file_name(member.name());
+                Input::open(&file)
+                    .map_err(|err| format!("{}: {err}", file.display()).into())
+                    .and_then(|input| run(args, format, &name, input.data()))
+            }
+            None => Err("cannot read member".into()),
+        };
+
+        if let Err(err) = result {
+            eprintln!("cli: {name}: {err}");
+            failed += 1;
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} of {} members failed", archive.members().len()).into()),
+    }
+}
+
+/// Runs the command on the file `data` was read from, named `file`.
+fn run(args: &Args, format: Format, file: &str, data: Data) -> Result<(), Box<dyn error::Error>> {
+    // Neither needs the file to be an ELF
+    match &args.command {
+        Some(Command::Hexdump { range, .. }) => {
+            let (bytes, offset) = bytes(data, range)?;
+            return Ok(dump::hexdump(&mut io::stdout().lock(), &bytes, offset)?);
+        }
+        Some(Command::Strings { range, min_len, .. }) => {
+            let (bytes, offset) = bytes(data, range)?;
+            return Ok(dump::strings(
+                &mut io::stdout().lock(),
+                &bytes,
+                offset,
+                *min_len,
+            )?);
+        }
+        _ => (),
+    }
+
+    let elf = Elf::parse(data)?;
+
+    if format == Format::Readelf {
+        let mut out = io::stdout().lock();
+        let wide = args.wide;
+
+        match &args.command {
+            Some(Command::Header { .. }) => readelf::header(&mut out, &elf)?,
+            Some(Command::Segments { .. }) => readelf::segments(&mut out, &elf, wide, false)?,
+            Some(Command::Sections { .. }) => readelf::sections(&mut out, &elf, wide, false)?,
+            Some(Command::Symbols { .. }) => readelf::symbols(&mut out, &elf, wide)?,
+            Some(Command::Dynamic { .. }) => readelf::dynamic(&mut out, &elf)?,
+            Some(Command::Relocs { .. }) => readelf::relocations(&mut out, &elf, wide)?,
+            Some(Command::Notes { .. }) => readelf::notes(&mut out, &elf, wide)?,
+            Some(
+                Command::Versions { .. }
+                | Command::Core { .. }
+                | Command::Hexdump { .. }
+                | Command::Strings { .. }
+                | Command::Addr2sym { .. }
+                | Command::Addr2line { .. }
+                | Command::Info { .. }
+                | Command::Layout { .. },
+            ) => {
+                unreachable!()
+            }
+            None => {
+                readelf::header(&mut out, &elf)?;
+                readelf::sections(&mut out, &elf, wide, true)?;
+                readelf::segments(&mut out, &elf, wide, true)?;
+            }
+        }
+
+        out.flush()?;
+        return Ok(());
+    }
+
+    if format == Format::Json {
+        let parts: &[Part] = match &args.command {
+            Some(Command::Header { .. }) => &[Part::Ident, Part::Header],
+            Some(Command::Segments { .. }) => &[Part::Segments],
+            Some(Command::Sections { .. }) => &[Part::Sections],
+            Some(Command::Symbols { .. }) => &[Part::Symbols],
+            Some(Command::Dynamic { .. }) => &[Part::Dynamic],
+            Some(Command::Relocs { .. }) => &[Part::Relocations],
+            Some(Command::Notes { .. }) => &[Part::Notes],
+            Some(Command::Versions { .. }) => &[Part::Versions],
+            Some(Command::Core { .. }) => &[Part::Core],
+            Some(
+                Command::Hexdump { .. }
+                | Command::Strings { .. }
+                | Command::Addr2sym { .. }
+                | Command::Addr2line { .. }
+                | Command::Info { .. }
+                | Command::Layout { .. },
+            ) => {
+                unreachable!()
+            }
+            None => &Part::ALL,
+        };
+
+        let mut document = json::document(&elf, parts)?;
+        document["file"] = file.into();
+
+        println!("{document:#}");
+        return Ok(());
+    }
+
+    match &args.command {
+        Some(Command::Header { .. }) => header(&elf),
+        Some(Command::Segments { r#type, .. }) => segments(&elf, r#type),
+        Some(Command::Sections { name, r#type, .. }) => sections(&elf, name, r#type),
+        Some(Command::Symbols { filter, .. }) => symbols(&elf, filter),
+        Some(Command::Dynamic { tag, .. }) => dynamic(&elf, tag),
+        Some(Command::Relocs {
+            section, symbol, ..
+        }) => relocations(&elf, section, symbol),
+        Some(Command::Notes { owner, .. }) => notes(&elf, owner),
+        Some(Command::Versions { .. }) => versions(&elf),
+        Some(Command::Core { .. }) => core(&elf),
+        Some(Command::Addr2sym {
+            addresses, offsets, ..
+        }) => addr2sym(&elf, addresses, *offsets),
+        Some(Command::Addr2line { queries, .. }) => addr2line(&elf, queries),
+        Some(Command::Info { .. }) => info(&elf),
+        Some(Command::Layout { name, .. }) => layouts(&elf, name),
+        Some(Command::Hexdump { .. } | Command::Strings { .. }) => unreachable!(),
+        None => load(&elf),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let format = match args.readelf {
+        true => Format::Readelf,
+        false => args.format,
+    };
+
+    let files = match &args.command {
+        Some(command) => command.files(),
+        None => &args.files.files,
+    };
+
+    if let Some(command) = &args.command {
+        if command.is_text_only() && format != Format::Text {
+            eprintln!(
+                "cli: hexdump, strings, addr2sym, addr2line, info and layout only have a text output"
+            );
+            return ExitCode::FAILURE;
+        }
+
+        if command.is_without_readelf() && format == Format::Readelf {
+            eprintln!("cli: versions and core have no readelf output");
+            return ExitCode::FAILURE;
+        }
+
+        if command.is_filtered() && format != Format::Text {
+            eprintln!("cli: filters are only supported with --format text");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut status = ExitCode::SUCCESS;
+
+    for file in files {
+        let result = Input::open(file).map_err(Into::into).and_then(|input| {
+            if Archive::is_archive(&input) {
+                return archive(&args, format, file, &input);
+            }
+
+            // As readelf, files are only named when there is more than one, JSON documents have it
+            if files.len() > 1 && format != Format::Json {
+                println!("\nFile: {file}");
+            }
+
+            run(&args, format, file, input.data())
+        });
+
+        if let Err(err) = result {
+            eprintln!("cli: {file}: {err}");
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    status
+}
@@ -0,0 +1,1109 @@
+//! Output of `--format readelf`, laid out as `readelf` from binutils 2.40 prints it so that
+//! scripts parsing it keep working. `wide` is `readelf -W`.
+
+use badelf::elf::common::{self, dt, e, p, sh, shn, stt};
+use badelf::elf::note::Notes;
+use badelf::elf::reader::{Class, Reader};
+use badelf::elf::reloc::{Relocations, Relr};
+use badelf::elf::section::{SectionHeader, SectionHeaders};
+use badelf::elf::segment::ProgramHeader;
+use badelf::elf::symbol::{Symbol, Symbols};
+use badelf::elf::Elf;
+use std::error;
+use std::io::Write;
+
+type Result = std::result::Result<(), Box<dyn error::Error>>;
+
+/// `print_vma (value, LONG_HEX)`, zero padded to the size of an address.
+fn vma(reader: &Reader, value: u64) -> String {
+    format!("{value:0width$x}", width = reader.by_class(8, 16))
+}
+
+/// `print_symbol`, names longer than `width` are cut short with `[...]` unless `wide`.
+fn symbol_name(name: &str, width: usize, wide: bool) -> String {
+    if wide || name.chars().count() <= width {
+        return name.into();
+    }
+
+    let kept: String = name.chars().take(width.saturating_sub(5)).collect();
+    format!("{kept}[...]")
+}
+
+fn plural<'a>(count: usize, one: &'a str, many: &'a str) -> &'a str {
+    match count {
+        1 => one,
+        _ => many,
+    }
+}
+
+/// `get_osabi_name`
+fn osabi_name(abi: u8) -> String {
+    match abi {
+        0x00 => "UNIX - System V".into(),
+        0x01 => "UNIX - HP-UX".into(),
+        0x02 => "UNIX - NetBSD".into(),
+        0x03 => "UNIX - GNU".into(),
+        0x06 => "UNIX - Solaris".into(),
+        0x07 => "UNIX - AIX".into(),
+        0x08 => "UNIX - IRIX".into(),
+        0x09 => "UNIX - FreeBSD".into(),
+        0x0A => "UNIX - TRU64".into(),
+        0x0B => "Novell - Modesto".into(),
+        0x0C => "UNIX - OpenBSD".into(),
+        0x0D => "VMS - OpenVMS".into(),
+        0x0E => "HP - Non-Stop Kernel".into(),
+        0x0F => "AROS".into(),
+        0x10 => "FenixOS".into(),
+        0x11 => "Nuxi CloudABI".into(),
+        0x12 => "Stratus Technologies OpenVOS".into(),
+        abi => format!("<unknown: {abi:x}>"),
+    }
+}
+
+/// `get_machine_name`, which names some machines differently than [`common::machine_name`].
+fn machine_name(machine: u16) -> &'static str {
+    match machine {
+        0x0002 => "Sparc",
+        0x0003 => "Intel 80386",
+        0x0004 => "MC68000",
+        0x0008 => "MIPS R3000",
+        0x0014 => "PowerPC",
+        0x0015 => "PowerPC64",
+        0x0016 => "IBM S/390",
+        0x0028 => "ARM",
+        0x002B => "Sparc v9",
+        0x003E => "Advanced Micro Devices X86-64",
+        0x00B7 => "AArch64",
+        0x00F3 => "RISC-V",
+        0x0102 => "LoongArch",
+        machine => common::machine_name(machine),
+    }
+}
+
+/// `get_file_type`, telling position independent executables from shared objects by their
+/// `DF_1_PIE` flag.
+fn file_type(elf: &Elf) -> String {
+    let r#type = elf.header().r#type();
+
+    match r#type {
+        e::r#type::NONE => "NONE (None)".into(),
+        e::r#type::REL => "REL (Relocatable file)".into(),
+        e::r#type::EXEC => "EXEC (Executable file)".into(),
+        e::r#type::DYN if is_pie(elf) => "DYN (Position-Independent Executable file)".into(),
+        e::r#type::DYN => "DYN (Shared object file)".into(),
+        e::r#type::CORE => "CORE (Core file)".into(),
+        r#type if e::r#type::PROC.contains(&r#type) => {
+            format!("Processor Specific: ({type:x})")
+        }
+        r#type if e::r#type::OS.contains(&r#type) => format!("OS Specific: ({type:x})"),
+        r#type => format!("<unknown>: {type:x}"),
+    }
+}
+
+fn is_pie(elf: &Elf) -> bool {
+    let Ok(Some(mut dynamic)) = elf.dynamic() else {
+        return false;
+    };
+
+    dynamic.any(|entry| entry.tag() == dt::FLAGS_1 && entry.value() & dt::flags_1::PIE != 0)
+}
+
+/// `decode_*_machine_flags` for the machines of the test fixtures, other machines only get the
+/// raw value.
+fn machine_flags(machine: u16, flags: u32) -> String {
+    let mut names = Vec::new();
+
+    match machine {
+        e::machine::ARM => {
+            const BE8: u32 = 0x00800000;
+            const LE8: u32 = 0x00400000;
+            const FLOAT_SOFT: u32 = 0x00000200;
+            const FLOAT_HARD: u32 = 0x00000400;
+
+            let known = match flags & 0xFF000000 {
+                0x04000000 => {
+                    names.push("Version4 EABI");
+                    BE8 | LE8
+                }
+                0x05000000 => {
+                    names.push("Version5 EABI");
+                    BE8 | LE8 | FLOAT_SOFT | FLOAT_HARD
+                }
+                _ => {
+                    names.push("<unrecognized EABI>");
+                    0
+                }
+            };
+
+            for (flag, name) in [
+                (BE8, "BE8"),
+                (LE8, "LE8"),
+                (FLOAT_SOFT, "soft-float ABI"),
+                (FLOAT_HARD, "hard-float ABI"),
+            ] {
+                if flags & known & flag != 0 {
+                    names.push(name);
+                }
+            }
+
+            if flags & !0xFF000000 & !known != 0 {
+                names.push("<unknown>");
+            }
+        }
+        e::machine::PPC => {
+            for (flag, name) in [
+                (0x80000000, "emb"),
+                (0x00010000, "relocatable"),
+                (0x00008000, "relocatable-lib"),
+            ] {
+                if flags & flag != 0 {
+                    names.push(name);
+                }
+            }
+        }
+        e::machine::PPC64 if flags & 0x03 != 0 => {
+            return format!(", abiv{}", flags & 0x03);
+        }
+        _ => (),
+    }
+
+    names.iter().map(|name| format!(", {name}")).collect()
+}
+
+/// `readelf -h`
+pub fn header(out: &mut impl Write, elf: &Elf) -> Result {
+    let header = elf.header();
+    let ident = header.ident();
+    let magic: String = ident.iter().map(|byte| format!("{byte:02x} ")).collect();
+
+    writeln!(out, "ELF Header:")?;
+    writeln!(out, "  Magic:   {magic}")?;
+    writeln!(
+        out,
+        "  Class:                             {}",
+        match header.reader().class() {
+            Class::Elf32 => "ELF32",
+            Class::Elf64 => "ELF64",
+        }
+    )?;
+    writeln!(
+        out,
+        "  Data:                              2's complement, {}",
+        match ident.endianness() {
+            0x01 => "little endian",
+            _ => "big endian",
+        }
+    )?;
+    writeln!(
+        out,
+        "  Version:                           {}{}",
+        ident.version(),
+        match ident.version() {
+            0x01 => " (current)",
+            _ => " <unknown>",
+        }
+    )?;
+    writeln!(
+        out,
+        "  OS/ABI:                            {}",
+        osabi_name(ident.abi())
+    )?;
+    writeln!(
+        out,
+        "  ABI Version:                       {}",
+        ident.abi_version()
+    )?;
+    writeln!(
+        out,
+        "  Type:                              {}",
+        file_type(elf)
+    )?;
+    writeln!(
+        out,
+        "  Machine:                           {}",
+        machine_name(header.machine())
+    )?;
+    writeln!(
+        out,
+        "  Version:                           {:#x}",
+        header.version()
+    )?;
+    writeln!(
+        out,
+        "  Entry point address:               {:#x}",
+        header.entry()
+    )?;
+    writeln!(
+        out,
+        "  Start of program headers:          {} (bytes into file)",
+        header.phoff()
+    )?;
+    writeln!(
+        out,
+        "  Start of section headers:          {} (bytes into file)",
+        header.shoff()
+    )?;
+    writeln!(
+        out,
+        "  Flags:                             {:#x}{}",
+        header.flags(),
+        machine_flags(header.machine(), header.flags())
+    )?;
+    writeln!(
+        out,
+        "  Size of this header:               {} (bytes)",
+        header.ehsize()
+    )?;
+    writeln!(
+        out,
+        "  Size of program headers:           {} (bytes)",
+        header.phentzise()
+    )?;
+    writeln!(
+        out,
+        "  Number of program headers:         {}",
+        header.phnum()
+    )?;
+    writeln!(
+        out,
+        "  Size of section headers:           {} (bytes)",
+        header.shentsize()
+    )?;
+    writeln!(
+        out,
+        "  Number of section headers:         {}",
+        header.shnum()
+    )?;
+    writeln!(
+        out,
+        "  Section header string table index: {}",
+        header.shstrndx()
+    )?;
+
+    Ok(())
+}
+
+/// `get_section_type_name`, with the processor specific types of the fixtures' machines.
+fn section_type_name(machine: u16, r#type: u32) -> String {
+    match (machine, r#type) {
+        (e::machine::ARM, 0x70000001) => "ARM_EXIDX".into(),
+        (e::machine::ARM, 0x70000002) => "ARM_PREEMPTMAP".into(),
+        (e::machine::ARM, 0x70000003) => "ARM_ATTRIBUTES".into(),
+        (e::machine::ARM, 0x70000004) => "ARM_DEBUGOVERLAY".into(),
+        (e::machine::ARM, 0x70000005) => "ARM_OVERLAYSECTION".into(),
+        (e::machine::X86_64, 0x70000001) => "X86_64_UNWIND".into(),
+        (_, 0x60000000..=0x6FFFFFFF) if common::sh_type_name(r#type) == "LOOS+" => {
+            format!("LOOS+{:#x}", r#type - 0x60000000)
+        }
+        (_, 0x70000000..=0x7FFFFFFF) => format!("LOPROC+{:#x}", r#type - 0x70000000),
+        (_, 0x80000000..=0xFFFFFFFF) => format!("LOUSER+{:#x}", r#type - 0x80000000),
+        _ => match common::sh_type_name(r#type) {
+            "UNKNOWN" => format!("{type:08x}: <unknown>"),
+            name => name.into(),
+        },
+    }
+}
+
+/// `get_elf_section_flags`, a key per set bit from the lowest one.
+fn section_flags(elf: &Elf, flags: u64) -> String {
+    let machine = elf.header().machine();
+    let (retain, mbind) = gnu_flags(elf);
+
+    (0..u64::BITS)
+        .map(|bit| 1u64 << bit)
+        .filter(|flag| flags & flag != 0)
+        .map(|flag| match flag {
+            sh::flags::WRITE => 'W',
+            sh::flags::ALLOC => 'A',
+            sh::flags::EXECINSTR => 'X',
+            sh::flags::MERGE => 'M',
+            sh::flags::STRINGS => 'S',
+            sh::flags::INFO_LINK => 'I',
+            sh::flags::LINK_ORDER => 'L',
+            sh::flags::OS_NONCONFORMING => 'O',
+            sh::flags::GROUP => 'G',
+            sh::flags::TLS => 'T',
+            sh::flags::EXCLUDE => 'E',
+            sh::flags::COMPRESSED => 'C',
+            0x10000000 if machine == e::machine::X86_64 => 'l',
+            0x20000000 if machine == e::machine::ARM => 'y',
+            0x10000000 if machine == e::machine::PPC => 'v',
+            sh::flags::GNU_RETAIN if retain => 'R',
+            0x01000000 if mbind => 'D',
+            flag if flag & sh::flags::MASKOS != 0 => 'o',
+            flag if flag & sh::flags::MASKPROC != 0 => 'p',
+            _ => 'x',
+        })
+        .collect()
+}
+
+/// Whether `SHF_GNU_RETAIN` and `SHF_GNU_MBIND` are known for the OS ABI of `elf`, binutils
+/// also takes `SHF_GNU_MBIND` for ELFOSABI_NONE but not `SHF_GNU_RETAIN`.
+fn gnu_flags(elf: &Elf) -> (bool, bool) {
+    let abi = elf.ident().abi();
+    (
+        matches!(abi, 0x03 | 0x09),
+        matches!(abi, 0x00 | 0x03 | 0x09),
+    )
+}
+
+/// `readelf -S`, `header` is whether `readelf -h` was printed before.
+pub fn sections(out: &mut impl Write, elf: &Elf, wide: bool, header: bool) -> Result {
+    let reader = elf.header().reader();
+    let sections = elf.sections();
+    let elf32 = reader.class() == Class::Elf32;
+
+    if sections.is_empty() {
+        if !header {
+            writeln!(out)?;
+        }
+        writeln!(out, "There are no sections in this file.")?;
+        return Ok(());
+    }
+
+    if !header {
+        writeln!(
+            out,
+            "There {} {} section {}, starting at offset {:#x}:",
+            plural(sections.num(), "is", "are"),
+            sections.num(),
+            plural(sections.num(), "header", "headers"),
+            elf.header().shoff()
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{}",
+        plural(sections.num(), "Section Header:", "Section Headers:")
+    )?;
+
+    match (elf32, wide) {
+        (true, _) => writeln!(
+            out,
+            "  [Nr] Name              Type            Addr     Off    Size   ES Flg Lk Inf Al"
+        )?,
+        (false, true) => writeln!(
+            out,
+            "  [Nr] Name              Type            Address          Off    Size   ES Flg Lk Inf Al"
+        )?,
+        (false, false) => {
+            writeln!(
+                out,
+                "  [Nr] Name              Type             Address           Offset"
+            )?;
+            writeln!(
+                out,
+                "       Size              EntSize          Flags  Link  Info  Align"
+            )?;
+        }
+    }
+
+    let machine = elf.header().machine();
+
+    for (index, section) in sections.enumerate() {
+        let name = symbol_name(section.name().unwrap_or("<corrupt>"), 17, wide);
+        let r#type = section_type_name(machine, section.r#type());
+        let r#type = match wide {
+            true => r#type,
+            false => r#type.chars().take(15).collect(),
+        };
+        let flags = section_flags(elf, section.flags());
+
+        write!(out, "  [{index:2}] {name:<17} {type:<15} ")?;
+
+        match (elf32, wide) {
+            (true, _) => writeln!(
+                out,
+                "{} {:06x} {:06x} {:02x} {flags:>3} {:2} {:3} {:2}",
+                vma(&reader, section.addr()),
+                section.offset(),
+                section.size(),
+                section.entsize(),
+                section.link(),
+                section.info(),
+                section.addralign()
+            )?,
+            (false, true) => {
+                let size = match section.size() {
+                    size @ ..=0xFFFFFF => format!("{size:06x}"),
+                    size => vma(&reader, size),
+                };
+                let entsize = match section.entsize() {
+                    entsize @ ..=0xFF => format!("{entsize:02x}"),
+                    entsize => vma(&reader, entsize),
+                };
+
+                writeln!(
+                    out,
+                    "{} {:06x} {size} {entsize} {flags:>3} {:2} {:3} {:2}",
+                    vma(&reader, section.addr()),
+                    section.offset(),
+                    section.link(),
+                    section.info(),
+                    section.addralign()
+                )?;
+            }
+            (false, false) => {
+                writeln!(
+                    out,
+                    " {}  {:08x}",
+                    vma(&reader, section.addr()),
+                    section.offset()
+                )?;
+                writeln!(
+                    out,
+                    "       {}  {} {flags:>3}      {:2}   {:3}     {}",
+                    vma(&reader, section.size()),
+                    vma(&reader, section.entsize()),
+                    section.link(),
+                    section.info(),
+                    section.addralign()
+                )?;
+            }
+        }
+    }
+
+    writeln!(out, "Key to Flags:")?;
+    writeln!(
+        out,
+        "  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),"
+    )?;
+    writeln!(
+        out,
+        "  L (link order), O (extra OS processing required), G (group), T (TLS),"
+    )?;
+    writeln!(
+        out,
+        "  C (compressed), x (unknown), o (OS specific), E (exclude),"
+    )?;
+
+    let (retain, mbind) = gnu_flags(elf);
+    writeln!(
+        out,
+        "  {}{}{}p (processor specific)",
+        if retain { "R (retain), " } else { "" },
+        if mbind { "D (mbind), " } else { "" },
+        match machine {
+            e::machine::X86_64 => "l (large), ",
+            e::machine::ARM => "y (purecode), ",
+            e::machine::PPC => "v (VLE), ",
+            _ => "",
+        }
+    )?;
+
+    Ok(())
+}
+
+/// `get_segment_type`
+fn segment_type_name(machine: u16, r#type: u32) -> String {
+    match (machine, r#type) {
+        (e::machine::ARM, 0x70000001) => "EXIDX".into(),
+        (_, 0x60000000..=0x6FFFFFFF) if common::p_type_name(r#type) == "LOOS+" => {
+            format!("LOOS+{:#x}", r#type - 0x60000000)
+        }
+        (_, 0x70000000..=0x7FFFFFFF) => format!("LOPROC+{:#x}", r#type - 0x70000000),
+        _ => match common::p_type_name(r#type) {
+            "UNKNOWN" => format!("<unknown>: {type:x}"),
+            name => name.into(),
+        },
+    }
+}
+
+/// `ELF_SECTION_IN_SEGMENT_STRICT` of `include/elf/internal.h`, what the section to segment
+/// mapping lists.
+fn section_in_segment(section: &SectionHeader, segment: &ProgramHeader) -> bool {
+    let tls = section.flags() & sh::flags::TLS != 0;
+    let alloc = section.flags() & sh::flags::ALLOC != 0;
+    let nobits = section.r#type() == sh::r#type::NOBITS;
+    let r#type = segment.r#type();
+
+    // .tbss only takes room in the PT_TLS segment
+    let size = match tls && nobits && r#type != p::r#type::TLS {
+        true => 0,
+        false => section.size(),
+    };
+
+    let kind = match tls {
+        true => matches!(
+            r#type,
+            p::r#type::TLS | p::r#type::GNU_RELRO | p::r#type::LOAD
+        ),
+        false => r#type != p::r#type::TLS && r#type != p::r#type::PHDR,
+    };
+
+    let allocated = alloc
+        || !matches!(
+            r#type,
+            p::r#type::LOAD
+                | p::r#type::DYNAMIC
+                | p::r#type::GNU_EH_FRAME
+                | p::r#type::GNU_STACK
+                | p::r#type::GNU_RELRO
+                | p::r#type::GNU_SFRAME
+                | 0x6474E555..=0x6474F554
+        );
+
+    let offset = section.offset().wrapping_sub(segment.offset());
+    let in_file = nobits
+        || (section.offset() >= segment.offset()
+            && offset <= segment.filesz().wrapping_sub(1)
+            && offset.wrapping_add(size) <= segment.filesz());
+
+    let addr = section.addr().wrapping_sub(segment.vaddr());
+    let in_memory = !alloc
+        || (section.addr() >= segment.vaddr()
+            && addr <= segment.memsz().wrapping_sub(1)
+            && addr.wrapping_add(size) <= segment.memsz());
+
+    let not_empty_edge = !matches!(r#type, p::r#type::DYNAMIC | p::r#type::NOTE)
+        || section.size() != 0
+        || segment.memsz() == 0
+        || ((nobits || (section.offset() > segment.offset() && offset < segment.filesz()))
+            && (!alloc || (section.addr() > segment.vaddr() && addr < segment.memsz())));
+
+    !(tls && nobits && r#type != p::r#type::TLS)
+        && kind
+        && allocated
+        && in_file
+        && in_memory
+        && not_empty_edge
+}
+
+/// `readelf -l`, `header` is whether `readelf -h` was printed before.
+pub fn segments(out: &mut impl Write, elf: &Elf, wide: bool, header: bool) -> Result {
+    let file = elf.data();
+    let reader = elf.header().reader();
+    let segments = elf.segments();
+    let elf32 = reader.class() == Class::Elf32;
+
+    if segments.len() == 0 {
+        writeln!(out)?;
+        writeln!(out, "There are no program headers in this file.")?;
+        return Ok(());
+    }
+
+    if !header {
+        writeln!(out)?;
+        writeln!(out, "Elf file type is {}", file_type(elf))?;
+        writeln!(out, "Entry point {:#x}", elf.header().entry())?;
+        writeln!(
+            out,
+            "There {} {} program {}, starting at offset {}",
+            plural(segments.len(), "is", "are"),
+            segments.len(),
+            plural(segments.len(), "header", "headers"),
+            elf.header().phoff()
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{}",
+        plural(segments.len(), "Program Header:", "Program Headers:")
+    )?;
+
+    match (elf32, wide) {
+        (true, _) => writeln!(
+            out,
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align"
+        )?,
+        (false, true) => writeln!(
+            out,
+            "  Type           Offset   VirtAddr           PhysAddr           FileSiz  MemSiz   Flg Align"
+        )?,
+        (false, false) => {
+            writeln!(
+                out,
+                "  Type           Offset             VirtAddr           PhysAddr"
+            )?;
+            writeln!(
+                out,
+                "                 FileSiz            MemSiz              Flags  Align"
+            )?;
+        }
+    }
+
+    let machine = elf.header().machine();
+
+    for segment in segments.clone() {
+        let flag = |flag, key| match segment.flags() & flag {
+            0 => ' ',
+            _ => key,
+        };
+        let flags = format!(
+            "{}{}{}",
+            flag(p::flags::R, 'R'),
+            flag(p::flags::W, 'W'),
+            flag(p::flags::X, 'E')
+        );
+        let r#type: String = segment_type_name(machine, segment.r#type())
+            .chars()
+            .take(14)
+            .collect();
+        let hex = |value: u64, digits: usize| match value < 1 << (digits * 4) {
+            true => format!("0x{value:0digits$x}"),
+            false => format!("0x{}", vma(&reader, value)),
+        };
+
+        write!(out, "  {type:<14} ")?;
+
+        match (elf32, wide) {
+            (true, _) => write!(
+                out,
+                "0x{:06x} 0x{:08x} 0x{:08x} 0x{:05x} 0x{:05x} {flags} ",
+                segment.offset(),
+                segment.vaddr(),
+                segment.paddr(),
+                segment.filesz(),
+                segment.memsz()
+            )?,
+            (false, true) => write!(
+                out,
+                "{} 0x{} 0x{} {} {} {flags} ",
+                hex(segment.offset(), 6),
+                vma(&reader, segment.vaddr()),
+                vma(&reader, segment.paddr()),
+                hex(segment.filesz(), 6),
+                hex(segment.memsz(), 6)
+            )?,
+            (false, false) => write!(
+                out,
+                "0x{} 0x{} 0x{}\n                 0x{} 0x{}  {flags}    ",
+                vma(&reader, segment.offset()),
+                vma(&reader, segment.vaddr()),
+                vma(&reader, segment.paddr()),
+                vma(&reader, segment.filesz()),
+                vma(&reader, segment.memsz())
+            )?,
+        }
+
+        match segment.align() {
+            0 => writeln!(out, "0")?,
+            align => writeln!(out, "{align:#x}")?,
+        }
+
+        if segment.r#type() == p::r#type::INTERP {
+            let interpreter = segment.data(file)?;
+            let interpreter = interpreter.split(|&byte| byte == 0x00).next();
+
+            writeln!(
+                out,
+                "      [Requesting program interpreter: {}]",
+                String::from_utf8_lossy(interpreter.unwrap_or_default())
+            )?;
+        }
+    }
+
+    let sections = elf.sections();
+
+    if sections.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out)?;
+    writeln!(out, " Section to Segment mapping:")?;
+    writeln!(out, "  Segment Sections...")?;
+
+    for (index, segment) in segments.enumerate() {
+        write!(out, "   {index:02}     ")?;
+
+        for section in sections.clone().skip(1) {
+            if section_in_segment(&section, &segment) {
+                write!(out, "{} ", section.name().unwrap_or("<corrupt>"))?;
+            }
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// The `@VERSION` of a dynamic symbol, with the version index for the ones it is required from
+/// a library when `index`.
+fn symbol_version(symbol: &Symbol, index: bool) -> String {
+    match symbol.versioned() {
+        // Needed versions also get their index in the symbol table
+        Some((separator, version)) if index && version.file().is_some() => {
+            format!("{separator}{version} ({})", version.index())
+        }
+        Some((separator, version)) => format!("{separator}{version}"),
+        None => String::new(),
+    }
+}
+
+/// Name of the section an unnamed `STT_SECTION` symbol stands for, which readelf shows in
+/// place of its empty name.
+fn section_symbol<'a>(symbol: &Symbol, sections: &SectionHeaders<'a>) -> Option<&'a str> {
+    match (symbol.name_offset(), symbol.r#type()) {
+        (0, stt::SECTION) => symbol
+            .section_index()
+            .and_then(|index| sections.get(index as usize))
+            .and_then(|section| section.name()),
+        _ => None,
+    }
+}
+
+/// `get_symbol_index_type`
+fn symbol_index(shndx: u16) -> String {
+    match shndx {
+        shn::UNDEF => "UND".into(),
+        shn::ABS => "ABS".into(),
+        shn::COMMON => "COM".into(),
+        shn::LOPROC..=shn::HIPROC => format!("PRC[{shndx:#06x}]"),
+        shn::LOOS..=shn::HIOS => format!("OS [{shndx:#06x}]"),
+        shndx if shndx >= shn::LORESERVE => format!("RSV[{shndx:#06x}]"),
+        shndx => format!("{shndx:3}"),
+    }
+}
+
+/// `readelf -s`
+pub fn symbols(out: &mut impl Write, elf: &Elf, wide: bool) -> Result {
+    let reader = elf.header().reader();
+    let sections = elf.sections();
+
+    for (index, section) in sections.clone().enumerate() {
+        if !matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM) {
+            continue;
+        }
+
+        let symbols = Symbols::new(elf.data(), &sections, index)?;
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Symbol table '{}' contains {} {}:",
+            symbols.name().unwrap_or("<corrupt>"),
+            symbols.num(),
+            plural(symbols.num(), "entry", "entries")
+        )?;
+
+        match reader.class() {
+            Class::Elf32 => writeln!(
+                out,
+                "   Num:    Value  Size Type    Bind   Vis      Ndx Name"
+            )?,
+            Class::Elf64 => writeln!(
+                out,
+                "   Num:    Value          Size Type    Bind   Vis      Ndx Name"
+            )?,
+        }
+
+        for (index, symbol) in symbols.enumerate() {
+            let size = match symbol.size() {
+                size @ ..=99999 => format!("{size:5}"),
+                size => format!("{size:#x}"),
+            };
+            let name = section_symbol(&symbol, &sections)
+                .or(symbol.name())
+                .unwrap_or("<corrupt>");
+            let version = symbol_version(&symbol, true);
+            let width = 21usize.saturating_sub(version.len());
+            let shndx = match (symbol.shndx(), symbol.section_index()) {
+                (shn::XINDEX, Some(index)) => format!("{index:3}"),
+                (shndx, _) => symbol_index(shndx),
+            };
+
+            writeln!(
+                out,
+                "{index:6}: {} {size} {:<7} {:<6} {:<7} {shndx:>4} {}{version}",
+                vma(&reader, symbol.value()),
+                common::st_type_name(symbol.r#type()),
+                common::st_bind_name(symbol.bind()),
+                common::st_visibility_name(symbol.visibility()),
+                symbol_name(name, width, wide)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// `readelf -d`
+pub fn dynamic(out: &mut impl Write, elf: &Elf) -> Result {
+    writeln!(out)?;
+
+    let Some(dynamic) = elf.dynamic()? else {
+        writeln!(out, "There is no dynamic section in this file.")?;
+        return Ok(());
+    };
+
+    let count = dynamic.clone().count();
+
+    writeln!(
+        out,
+        "Dynamic section at offset {:#x} contains {count} {}:",
+        dynamic.offset(),
+        plural(count, "entry", "entries")
+    )?;
+    writeln!(out, "  Tag        Type                         Name/Value")?;
+
+    for entry in dynamic {
+        writeln!(out, " {entry}")?;
+    }
+
+    Ok(())
+}
+
+/// `readelf -r`
+pub fn relocations(out: &mut impl Write, elf: &Elf, wide: bool) -> Result {
+    let file = elf.data();
+    let header = elf.header();
+    let reader = header.reader();
+    let sections = elf.sections();
+    let elf32 = reader.class() == Class::Elf32;
+    let mut found = false;
+
+    for (index, section) in sections.clone().enumerate() {
+        let r#type = section.r#type();
+
+        if !matches!(
+            r#type,
+            sh::r#type::REL | sh::r#type::RELA | sh::r#type::RELR
+        ) {
+            continue;
+        }
+
+        let entsize = match section.entsize() {
+            0 => reader.wordsize() as u64,
+            entsize => entsize,
+        };
+        let count = (section.size() / entsize) as usize;
+
+        found = true;
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Relocation section '{}' at offset {:#x} contains {count} {}:",
+            section.name().unwrap_or("<corrupt>"),
+            section.offset(),
+            plural(count, "entry", "entries")
+        )?;
+
+        if r#type == sh::r#type::RELR {
+            let relr = Relr::new(file, &header, &sections, index)?;
+            let count = relr.clone().count();
+
+            writeln!(out, "  {count} {}", plural(count, "offset", "offsets"))?;
+            for offset in relr {
+                writeln!(out, "{}", vma(&reader, offset))?;
+            }
+
+            continue;
+        }
+
+        let rela = r#type == sh::r#type::RELA;
+        let addend = if rela { " + Addend" } else { "" };
+
+        match (elf32, wide) {
+            (true, false) => writeln!(
+                out,
+                " Offset     Info    Type            Sym.Value  Sym. Name{addend}"
+            )?,
+            (true, true) => writeln!(
+                out,
+                " Offset     Info    Type                Sym. Value  Symbol's Name{addend}"
+            )?,
+            (false, false) => writeln!(
+                out,
+                "  Offset          Info           Type           Sym. Value    Sym. Name{addend}"
+            )?,
+            (false, true) => writeln!(
+                out,
+                "    Offset             Info             Type               Symbol's Value  Symbol's Name{addend}"
+            )?,
+        }
+
+        for relocation in Relocations::new(file, &header, &sections, index)? {
+            match (elf32, wide) {
+                (false, false) => write!(
+                    out,
+                    "{:012x}  {:012x} ",
+                    relocation.offset(),
+                    relocation.info()
+                )?,
+                _ => write!(
+                    out,
+                    "{}  {} ",
+                    vma(&reader, relocation.offset()),
+                    vma(&reader, relocation.info())
+                )?,
+            }
+
+            match relocation.type_name() {
+                Some(name) if wide => write!(out, "{name:<22}")?,
+                Some(name) => write!(out, "{:<17}", name.chars().take(17).collect::<String>())?,
+                None => write!(out, "unrecognized: {:<7x}", relocation.r#type())?,
+            }
+
+            let addend = relocation.addend().unwrap_or_default();
+            let sign = match addend < 0 {
+                true => '-',
+                false => '+',
+            };
+
+            match relocation.symbol() {
+                Some(symbol) if relocation.sym() != 0 => {
+                    let name = match section_symbol(&symbol, &sections) {
+                        Some(name) => name.into(),
+                        None if symbol.name_offset() == 0 => "<null>".into(),
+                        None => format!(
+                            "{}{}",
+                            symbol_name(symbol.name().unwrap_or("<corrupt>"), 22, wide),
+                            symbol_version(&symbol, false)
+                        ),
+                    };
+
+                    write!(
+                        out,
+                        " {}{}{name}",
+                        vma(&reader, symbol.value()),
+                        if elf32 { "   " } else { " " }
+                    )?;
+
+                    if rela {
+                        write!(out, " {sign} {:x}", addend.unsigned_abs())?;
+                    }
+                }
+                _ if rela => {
+                    let pad = match elf32 {
+                        true => 12,
+                        false => 20,
+                    };
+                    let sign = if addend < 0 { "-" } else { "" };
+
+                    write!(out, "{:pad$}{sign}{:x}", "", addend.unsigned_abs())?;
+                }
+                _ => (),
+            }
+
+            writeln!(out)?;
+        }
+    }
+
+    if !found {
+        writeln!(out)?;
+        writeln!(out, "There are no relocations in this file.")?;
+    }
+
+    Ok(())
+}
+
+/// `readelf -n`, with the description on the same line as the type in `wide`.
+pub fn notes(out: &mut impl Write, elf: &Elf, wide: bool) -> Result {
+    let file = elf.data();
+    let header = elf.header();
+    let sections = elf.sections();
+
+    let print = |out: &mut dyn Write, notes: Notes| -> Result {
+        writeln!(out, "  Owner                Data size \tDescription")?;
+
+        for note in notes {
+            let note = note.to_string();
+            let note = match wide {
+                true => note.replacen('\n', "\t", 1).replace("\n\t", ", "),
+                false => note,
+            };
+
+            writeln!(out, "  {note}")?;
+        }
+
+        Ok(())
+    };
+
+    if sections
+        .clone()
+        .any(|section| section.r#type() == sh::r#type::NOTE)
+    {
+        for section in sections.filter(|section| section.r#type() == sh::r#type::NOTE) {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "Displaying notes found in: {}",
+                section.name().unwrap_or("<corrupt>")
+            )?;
+            print(out, Notes::from_section(file, &header, &section)?)?;
+        }
+    } else {
+        for segment in elf
+            .segments()
+            .filter(|segment| segment.r#type() == p::r#type::NOTE)
+        {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "Displaying notes found at file offset {:#010x} with length {:#010x}:",
+                segment.offset(),
+                segment.filesz()
+            )?;
+            print(out, Notes::from_segment(file, &header, &segment)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dynamic, header, notes, relocations, sections, segments, symbols};
+    use badelf::elf::Elf;
+    use std::fs;
+
+    const FIXTURES: [&str; 11] = [
+        "exe",
+        "copy",
+        "lib.so",
+        "versioned.so",
+        "lib.o",
+        "retain.o",
+        "i386.o",
+        "i386.so",
+        "arm.o",
+        "ppc.o",
+        "ppc64.o",
+    ];
+
+    /// Compares with the output of binutils' readelf kept in `test/readelf` by `make golden`.
+    #[test]
+    fn golden() {
+        let mut failed = Vec::new();
+
+        for fixture in FIXTURES {
+            let file = fs::read(format!("test/{fixture}")).unwrap();
+            let elf = Elf::parse(&file).unwrap();
+
+            for wide in [false, true] {
+                for options in ["hlS", "s", "d", "r", "n"] {
+                    let mut out = Vec::new();
+
+                    match options {
+                        "hlS" => {
+                            header(&mut out, &elf).unwrap();
+                            sections(&mut out, &elf, wide, true).unwrap();
+                            segments(&mut out, &elf, wide, true).unwrap();
+                        }
+                        "s" => symbols(&mut out, &elf, wide).unwrap(),
+                        "d" => dynamic(&mut out, &elf).unwrap(),
+                        "r" => relocations(&mut out, &elf, wide).unwrap(),
+                        _ => notes(&mut out, &elf, wide).unwrap(),
+                    }
+
+                    let golden = match wide {
+                        true => format!("test/readelf/{fixture}.W{options}"),
+                        false => format!("test/readelf/{fixture}.{options}"),
+                    };
+
+                    if fs::read(&golden).unwrap() != out {
+                        failed.push(golden);
+                    }
+                }
+            }
+        }
+
+        assert!(failed.is_empty(), "differs from {failed:?}");
+    }
+}
@@ -0,0 +1,640 @@
+// Values from the DWARF 5 standard, section 7, and `include/dwarf2.def` of binutils
+
+/// `DW_LNS_*`, the standard opcodes of line number programs
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod lns {
+    pub const              COPY: u8 = 0x01;
+    pub const        ADVANCE_PC: u8 = 0x02;
+    pub const      ADVANCE_LINE: u8 = 0x03;
+    pub const          SET_FILE: u8 = 0x04;
+    pub const        SET_COLUMN: u8 = 0x05;
+    pub const       NEGATE_STMT: u8 = 0x06;
+    pub const   SET_BASIC_BLOCK: u8 = 0x07;
+    pub const      CONST_ADD_PC: u8 = 0x08;
+    pub const  FIXED_ADVANCE_PC: u8 = 0x09;
+    pub const  SET_PROLOGUE_END: u8 = 0x0A;
+    pub const SET_EPILOGUE_BEGIN: u8 = 0x0B;
+    pub const           SET_ISA: u8 = 0x0C;
+}
+
+/// `DW_LNE_*`, the extended opcodes of line number programs
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod lne {
+    pub const      END_SEQUENCE: u8 = 0x01;
+    pub const       SET_ADDRESS: u8 = 0x02;
+    pub const       DEFINE_FILE: u8 = 0x03;
+    pub const SET_DISCRIMINATOR: u8 = 0x04;
+}
+
+/// `DW_LNCT_*`, the content of the fields of DWARF 5 directory and file name entries
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod lnct {
+    pub const            PATH: u64 = 0x01;
+    pub const DIRECTORY_INDEX: u64 = 0x02;
+    pub const       TIMESTAMP: u64 = 0x03;
+    pub const            SIZE: u64 = 0x04;
+    pub const             MD5: u64 = 0x05;
+}
+
+/// `DW_FORM_*`, how attribute values are encoded
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod form {
+    pub const           ADDR: u64 = 0x01;
+    pub const         BLOCK2: u64 = 0x03;
+    pub const         BLOCK4: u64 = 0x04;
+    pub const          DATA2: u64 = 0x05;
+    pub const          DATA4: u64 = 0x06;
+    pub const          DATA8: u64 = 0x07;
+    pub const         STRING: u64 = 0x08;
+    pub const          BLOCK: u64 = 0x09;
+    pub const         BLOCK1: u64 = 0x0A;
+    pub const          DATA1: u64 = 0x0B;
+    pub const           FLAG: u64 = 0x0C;
+    pub const          SDATA: u64 = 0x0D;
+    pub const           STRP: u64 = 0x0E;
+    pub const          UDATA: u64 = 0x0F;
+    pub const       REF_ADDR: u64 = 0x10;
+    pub const           REF1: u64 = 0x11;
+    pub const           REF2: u64 = 0x12;
+    pub const           REF4: u64 = 0x13;
+    pub const           REF8: u64 = 0x14;
+    pub const      REF_UDATA: u64 = 0x15;
+    pub const       INDIRECT: u64 = 0x16;
+    pub const     SEC_OFFSET: u64 = 0x17;
+    pub const        EXPRLOC: u64 = 0x18;
+    pub const   FLAG_PRESENT: u64 = 0x19;
+    pub const           STRX: u64 = 0x1A;
+    pub const          ADDRX: u64 = 0x1B;
+    pub const       REF_SUP4: u64 = 0x1C;
+    pub const       STRP_SUP: u64 = 0x1D;
+    pub const         DATA16: u64 = 0x1E;
+    pub const      LINE_STRP: u64 = 0x1F;
+    pub const       REF_SIG8: u64 = 0x20;
+    pub const IMPLICIT_CONST: u64 = 0x21;
+    pub const       LOCLISTX: u64 = 0x22;
+    pub const       RNGLISTX: u64 = 0x23;
+    pub const       REF_SUP8: u64 = 0x24;
+    pub const          STRX1: u64 = 0x25;
+    pub const          STRX2: u64 = 0x26;
+    pub const          STRX3: u64 = 0x27;
+    pub const          STRX4: u64 = 0x28;
+    pub const         ADDRX1: u64 = 0x29;
+    pub const         ADDRX2: u64 = 0x2A;
+    pub const         ADDRX3: u64 = 0x2B;
+    pub const         ADDRX4: u64 = 0x2C;
+    pub const GNU_ADDR_INDEX: u64 = 0x1F01;
+    pub const  GNU_STR_INDEX: u64 = 0x1F02;
+    pub const    GNU_REF_ALT: u64 = 0x1F20;
+    pub const   GNU_STRP_ALT: u64 = 0x1F21;
+}
+
+/// `DW_TAG_*`, what a debugging information entry describes
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod tag {
+    pub const                  ARRAY_TYPE: u64 = 0x0001;
+    pub const                  CLASS_TYPE: u64 = 0x0002;
+    pub const                 ENTRY_POINT: u64 = 0x0003;
+    pub const            ENUMERATION_TYPE: u64 = 0x0004;
+    pub const            FORMAL_PARAMETER: u64 = 0x0005;
+    pub const        IMPORTED_DECLARATION: u64 = 0x0008;
+    pub const                       LABEL: u64 = 0x000A;
+    pub const               LEXICAL_BLOCK: u64 = 0x000B;
+    pub const                      MEMBER: u64 = 0x000D;
+    pub const                POINTER_TYPE: u64 = 0x000F;
+    pub const              REFERENCE_TYPE: u64 = 0x0010;
+    pub const                COMPILE_UNIT: u64 = 0x0011;
+    pub const                 STRING_TYPE: u64 = 0x0012;
+    pub const              STRUCTURE_TYPE: u64 = 0x0013;
+    pub const             SUBROUTINE_TYPE: u64 = 0x0015;
+    pub const                     TYPEDEF: u64 = 0x0016;
+    pub const                  UNION_TYPE: u64 = 0x0017;
+    pub const      UNSPECIFIED_PARAMETERS: u64 = 0x0018;
+    pub const                     VARIANT: u64 = 0x0019;
+    pub const                COMMON_BLOCK: u64 = 0x001A;
+    pub const            COMMON_INCLUSION: u64 = 0x001B;
+    pub const                 INHERITANCE: u64 = 0x001C;
+    pub const          INLINED_SUBROUTINE: u64 = 0x001D;
+    pub const                      MODULE: u64 = 0x001E;
+    pub const          PTR_TO_MEMBER_TYPE: u64 = 0x001F;
+    pub const                    SET_TYPE: u64 = 0x0020;
+    pub const               SUBRANGE_TYPE: u64 = 0x0021;
+    pub const                   WITH_STMT: u64 = 0x0022;
+    pub const          ACCESS_DECLARATION: u64 = 0x0023;
+    pub const                   BASE_TYPE: u64 = 0x0024;
+    pub const                 CATCH_BLOCK: u64 = 0x0025;
+    pub const                  CONST_TYPE: u64 = 0x0026;
+    pub const                    CONSTANT: u64 = 0x0027;
+    pub const                  ENUMERATOR: u64 = 0x0028;
+    pub const                   FILE_TYPE: u64 = 0x0029;
+    pub const                      FRIEND: u64 = 0x002A;
+    pub const                    NAMELIST: u64 = 0x002B;
+    pub const               NAMELIST_ITEM: u64 = 0x002C;
+    pub const                 PACKED_TYPE: u64 = 0x002D;
+    pub const                  SUBPROGRAM: u64 = 0x002E;
+    pub const     TEMPLATE_TYPE_PARAMETER: u64 = 0x002F;
+    pub const    TEMPLATE_VALUE_PARAMETER: u64 = 0x0030;
+    pub const                 THROWN_TYPE: u64 = 0x0031;
+    pub const                   TRY_BLOCK: u64 = 0x0032;
+    pub const                VARIANT_PART: u64 = 0x0033;
+    pub const                    VARIABLE: u64 = 0x0034;
+    pub const               VOLATILE_TYPE: u64 = 0x0035;
+    pub const             DWARF_PROCEDURE: u64 = 0x0036;
+    pub const               RESTRICT_TYPE: u64 = 0x0037;
+    pub const              INTERFACE_TYPE: u64 = 0x0038;
+    pub const                   NAMESPACE: u64 = 0x0039;
+    pub const             IMPORTED_MODULE: u64 = 0x003A;
+    pub const            UNSPECIFIED_TYPE: u64 = 0x003B;
+    pub const                PARTIAL_UNIT: u64 = 0x003C;
+    pub const               IMPORTED_UNIT: u64 = 0x003D;
+    pub const                   CONDITION: u64 = 0x003F;
+    pub const                 SHARED_TYPE: u64 = 0x0040;
+    pub const                   TYPE_UNIT: u64 = 0x0041;
+    pub const       RVALUE_REFERENCE_TYPE: u64 = 0x0042;
+    pub const                COARRAY_TYPE: u64 = 0x0043;
+    pub const            GENERIC_SUBRANGE: u64 = 0x0044;
+    pub const                DYNAMIC_TYPE: u64 = 0x0045;
+    pub const                 ATOMIC_TYPE: u64 = 0x0046;
+    pub const                   CALL_SITE: u64 = 0x0047;
+    pub const         CALL_SITE_PARAMETER: u64 = 0x0048;
+    pub const               SKELETON_UNIT: u64 = 0x0049;
+    pub const              IMMUTABLE_TYPE: u64 = 0x004A;
+    pub const GNU_TEMPLATE_TEMPLATE_PARAM: u64 = 0x4106;
+    pub const GNU_TEMPLATE_PARAMETER_PACK: u64 = 0x4107;
+    pub const   GNU_FORMAL_PARAMETER_PACK: u64 = 0x4108;
+    pub const               GNU_CALL_SITE: u64 = 0x4109;
+    pub const     GNU_CALL_SITE_PARAMETER: u64 = 0x410A;
+}
+
+/// `DW_AT_*`, the attributes of entries
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod at {
+    pub const                 SIBLING: u64 = 0x0001;
+    pub const                LOCATION: u64 = 0x0002;
+    pub const                    NAME: u64 = 0x0003;
+    pub const                ORDERING: u64 = 0x0009;
+    pub const               BYTE_SIZE: u64 = 0x000B;
+    pub const              BIT_OFFSET: u64 = 0x000C;
+    pub const                BIT_SIZE: u64 = 0x000D;
+    pub const               STMT_LIST: u64 = 0x0010;
+    pub const                  LOW_PC: u64 = 0x0011;
+    pub const                 HIGH_PC: u64 = 0x0012;
+    pub const                LANGUAGE: u64 = 0x0013;
+    pub const                   DISCR: u64 = 0x0015;
+    pub const             DISCR_VALUE: u64 = 0x0016;
+    pub const              VISIBILITY: u64 = 0x0017;
+    pub const                  IMPORT: u64 = 0x0018;
+    pub const           STRING_LENGTH: u64 = 0x0019;
+    pub const        COMMON_REFERENCE: u64 = 0x001A;
+    pub const                COMP_DIR: u64 = 0x001B;
+    pub const             CONST_VALUE: u64 = 0x001C;
+    pub const         CONTAINING_TYPE: u64 = 0x001D;
+    pub const           DEFAULT_VALUE: u64 = 0x001E;
+    pub const                  INLINE: u64 = 0x0020;
+    pub const             IS_OPTIONAL: u64 = 0x0021;
+    pub const             LOWER_BOUND: u64 = 0x0022;
+    pub const                PRODUCER: u64 = 0x0025;
+    pub const              PROTOTYPED: u64 = 0x0027;
+    pub const             RETURN_ADDR: u64 = 0x002A;
+    pub const             START_SCOPE: u64 = 0x002C;
+    pub const              BIT_STRIDE: u64 = 0x002E;
+    pub const             UPPER_BOUND: u64 = 0x002F;
+    pub const         ABSTRACT_ORIGIN: u64 = 0x0031;
+    pub const           ACCESSIBILITY: u64 = 0x0032;
+    pub const           ADDRESS_CLASS: u64 = 0x0033;
+    pub const              ARTIFICIAL: u64 = 0x0034;
+    pub const              BASE_TYPES: u64 = 0x0035;
+    pub const      CALLING_CONVENTION: u64 = 0x0036;
+    pub const                   COUNT: u64 = 0x0037;
+    pub const    DATA_MEMBER_LOCATION: u64 = 0x0038;
+    pub const             DECL_COLUMN: u64 = 0x0039;
+    pub const               DECL_FILE: u64 = 0x003A;
+    pub const               DECL_LINE: u64 = 0x003B;
+    pub const             DECLARATION: u64 = 0x003C;
+    pub const              DISCR_LIST: u64 = 0x003D;
+    pub const                ENCODING: u64 = 0x003E;
+    pub const                EXTERNAL: u64 = 0x003F;
+    pub const              FRAME_BASE: u64 = 0x0040;
+    pub const                  FRIEND: u64 = 0x0041;
+    pub const         IDENTIFIER_CASE: u64 = 0x0042;
+    pub const              MACRO_INFO: u64 = 0x0043;
+    pub const           NAMELIST_ITEM: u64 = 0x0044;
+    pub const                PRIORITY: u64 = 0x0045;
+    pub const                 SEGMENT: u64 = 0x0046;
+    pub const           SPECIFICATION: u64 = 0x0047;
+    pub const             STATIC_LINK: u64 = 0x0048;
+    pub const                    TYPE: u64 = 0x0049;
+    pub const            USE_LOCATION: u64 = 0x004A;
+    pub const      VARIABLE_PARAMETER: u64 = 0x004B;
+    pub const              VIRTUALITY: u64 = 0x004C;
+    pub const    VTABLE_ELEM_LOCATION: u64 = 0x004D;
+    pub const               ALLOCATED: u64 = 0x004E;
+    pub const              ASSOCIATED: u64 = 0x004F;
+    pub const           DATA_LOCATION: u64 = 0x0050;
+    pub const             BYTE_STRIDE: u64 = 0x0051;
+    pub const                ENTRY_PC: u64 = 0x0052;
+    pub const                USE_UTF8: u64 = 0x0053;
+    pub const               EXTENSION: u64 = 0x0054;
+    pub const                  RANGES: u64 = 0x0055;
+    pub const              TRAMPOLINE: u64 = 0x0056;
+    pub const             CALL_COLUMN: u64 = 0x0057;
+    pub const               CALL_FILE: u64 = 0x0058;
+    pub const               CALL_LINE: u64 = 0x0059;
+    pub const             DESCRIPTION: u64 = 0x005A;
+    pub const            BINARY_SCALE: u64 = 0x005B;
+    pub const           DECIMAL_SCALE: u64 = 0x005C;
+    pub const                   SMALL: u64 = 0x005D;
+    pub const            DECIMAL_SIGN: u64 = 0x005E;
+    pub const             DIGIT_COUNT: u64 = 0x005F;
+    pub const          PICTURE_STRING: u64 = 0x0060;
+    pub const                 MUTABLE: u64 = 0x0061;
+    pub const          THREADS_SCALED: u64 = 0x0062;
+    pub const                EXPLICIT: u64 = 0x0063;
+    pub const          OBJECT_POINTER: u64 = 0x0064;
+    pub const               ENDIANITY: u64 = 0x0065;
+    pub const               ELEMENTAL: u64 = 0x0066;
+    pub const                    PURE: u64 = 0x0067;
+    pub const               RECURSIVE: u64 = 0x0068;
+    pub const               SIGNATURE: u64 = 0x0069;
+    pub const         MAIN_SUBPROGRAM: u64 = 0x006A;
+    pub const         DATA_BIT_OFFSET: u64 = 0x006B;
+    pub const              CONST_EXPR: u64 = 0x006C;
+    pub const              ENUM_CLASS: u64 = 0x006D;
+    pub const            LINKAGE_NAME: u64 = 0x006E;
+    pub const  STRING_LENGTH_BIT_SIZE: u64 = 0x006F;
+    pub const STRING_LENGTH_BYTE_SIZE: u64 = 0x0070;
+    pub const                    RANK: u64 = 0x0071;
+    pub const        STR_OFFSETS_BASE: u64 = 0x0072;
+    pub const               ADDR_BASE: u64 = 0x0073;
+    pub const           RNGLISTS_BASE: u64 = 0x0074;
+    pub const                DWO_NAME: u64 = 0x0076;
+    pub const               REFERENCE: u64 = 0x0077;
+    pub const        RVALUE_REFERENCE: u64 = 0x0078;
+    pub const                  MACROS: u64 = 0x0079;
+    pub const          CALL_ALL_CALLS: u64 = 0x007A;
+    pub const   CALL_ALL_SOURCE_CALLS: u64 = 0x007B;
+    pub const     CALL_ALL_TAIL_CALLS: u64 = 0x007C;
+    pub const          CALL_RETURN_PC: u64 = 0x007D;
+    pub const              CALL_VALUE: u64 = 0x007E;
+    pub const             CALL_ORIGIN: u64 = 0x007F;
+    pub const          CALL_PARAMETER: u64 = 0x0080;
+    pub const                 CALL_PC: u64 = 0x0081;
+    pub const          CALL_TAIL_CALL: u64 = 0x0082;
+    pub const             CALL_TARGET: u64 = 0x0083;
+    pub const   CALL_TARGET_CLOBBERED: u64 = 0x0084;
+    pub const      CALL_DATA_LOCATION: u64 = 0x0085;
+    pub const         CALL_DATA_VALUE: u64 = 0x0086;
+    pub const                NORETURN: u64 = 0x0087;
+    pub const               ALIGNMENT: u64 = 0x0088;
+    pub const          EXPORT_SYMBOLS: u64 = 0x0089;
+    pub const                 DELETED: u64 = 0x008A;
+    pub const               DEFAULTED: u64 = 0x008B;
+    pub const           LOCLISTS_BASE: u64 = 0x008C;
+    pub const       MIPS_LINKAGE_NAME: u64 = 0x2007;
+    pub const                SF_NAMES: u64 = 0x2101;
+    pub const                SRC_INFO: u64 = 0x2102;
+    pub const                MAC_INFO: u64 = 0x2103;
+    pub const              SRC_COORDS: u64 = 0x2104;
+    pub const              BODY_BEGIN: u64 = 0x2105;
+    pub const                BODY_END: u64 = 0x2106;
+    pub const              GNU_VECTOR: u64 = 0x2107;
+    pub const       GNU_TEMPLATE_NAME: u64 = 0x2110;
+    pub const     GNU_CALL_SITE_VALUE: u64 = 0x2111;
+    pub const    GNU_CALL_SITE_TARGET: u64 = 0x2113;
+    pub const           GNU_TAIL_CALL: u64 = 0x2115;
+    pub const GNU_ALL_TAIL_CALL_SITES: u64 = 0x2116;
+    pub const      GNU_ALL_CALL_SITES: u64 = 0x2117;
+    pub const              GNU_MACROS: u64 = 0x2119;
+    pub const             GNU_DELETED: u64 = 0x211A;
+    pub const            GNU_DWO_NAME: u64 = 0x2130;
+    pub const              GNU_DWO_ID: u64 = 0x2131;
+    pub const         GNU_RANGES_BASE: u64 = 0x2132;
+    pub const           GNU_ADDR_BASE: u64 = 0x2133;
+    pub const            GNU_PUBNAMES: u64 = 0x2134;
+    pub const       GNU_DISCRIMINATOR: u64 = 0x2136;
+    pub const            GNU_LOCVIEWS: u64 = 0x2137;
+    pub const          GNU_ENTRY_VIEW: u64 = 0x2138;
+}
+
+/// `DW_UT_*`, the unit types of DWARF 5 unit headers
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod ut {
+    pub const       COMPILE: u8 = 0x0001;
+    pub const          TYPE: u8 = 0x0002;
+    pub const       PARTIAL: u8 = 0x0003;
+    pub const      SKELETON: u8 = 0x0004;
+    pub const SPLIT_COMPILE: u8 = 0x0005;
+    pub const    SPLIT_TYPE: u8 = 0x0006;
+}
+
+/// `DW_OP_*`, the operations of location expressions used for member offsets
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod op {
+    pub const      CONSTU: u8 = 0x10;
+    pub const PLUS_UCONST: u8 = 0x23;
+}
+
+/// Name of a `DW_TAG_*` value, `UNKNOWN` for the ones not in [`tag`]
+pub fn tag_name(value: u64) -> &'static str {
+    match value {
+        0x0001 => "DW_TAG_array_type",
+        0x0002 => "DW_TAG_class_type",
+        0x0003 => "DW_TAG_entry_point",
+        0x0004 => "DW_TAG_enumeration_type",
+        0x0005 => "DW_TAG_formal_parameter",
+        0x0008 => "DW_TAG_imported_declaration",
+        0x000A => "DW_TAG_label",
+        0x000B => "DW_TAG_lexical_block",
+        0x000D => "DW_TAG_member",
+        0x000F => "DW_TAG_pointer_type",
+        0x0010 => "DW_TAG_reference_type",
+        0x0011 => "DW_TAG_compile_unit",
+        0x0012 => "DW_TAG_string_type",
+        0x0013 => "DW_TAG_structure_type",
+        0x0015 => "DW_TAG_subroutine_type",
+        0x0016 => "DW_TAG_typedef",
+        0x0017 => "DW_TAG_union_type",
+        0x0018 => "DW_TAG_unspecified_parameters",
+        0x0019 => "DW_TAG_variant",
+        0x001A => "DW_TAG_common_block",
+        0x001B => "DW_TAG_common_inclusion",
+        0x001C => "DW_TAG_inheritance",
+        0x001D => "DW_TAG_inlined_subroutine",
+        0x001E => "DW_TAG_module",
+        0x001F => "DW_TAG_ptr_to_member_type",
+        0x0020 => "DW_TAG_set_type",
+        0x0021 => "DW_TAG_subrange_type",
+        0x0022 => "DW_TAG_with_stmt",
+        0x0023 => "DW_TAG_access_declaration",
+        0x0024 => "DW_TAG_base_type",
+        0x0025 => "DW_TAG_catch_block",
+        0x0026 => "DW_TAG_const_type",
+        0x0027 => "DW_TAG_constant",
+        0x0028 => "DW_TAG_enumerator",
+        0x0029 => "DW_TAG_file_type",
+        0x002A => "DW_TAG_friend",
+        0x002B => "DW_TAG_namelist",
+        0x002C => "DW_TAG_namelist_item",
+        0x002D => "DW_TAG_packed_type",
+        0x002E => "DW_TAG_subprogram",
+        0x002F => "DW_TAG_template_type_parameter",
+        0x0030 => "DW_TAG_template_value_parameter",
+        0x0031 => "DW_TAG_thrown_type",
+        0x0032 => "DW_TAG_try_block",
+        0x0033 => "DW_TAG_variant_part",
+        0x0034 => "DW_TAG_variable",
+        0x0035 => "DW_TAG_volatile_type",
+        0x0036 => "DW_TAG_dwarf_procedure",
+        0x0037 => "DW_TAG_restrict_type",
+        0x0038 => "DW_TAG_interface_type",
+        0x0039 => "DW_TAG_namespace",
+        0x003A => "DW_TAG_imported_module",
+        0x003B => "DW_TAG_unspecified_type",
+        0x003C => "DW_TAG_partial_unit",
+        0x003D => "DW_TAG_imported_unit",
+        0x003F => "DW_TAG_condition",
+        0x0040 => "DW_TAG_shared_type",
+        0x0041 => "DW_TAG_type_unit",
+        0x0042 => "DW_TAG_rvalue_reference_type",
+        0x0043 => "DW_TAG_coarray_type",
+        0x0044 => "DW_TAG_generic_subrange",
+        0x0045 => "DW_TAG_dynamic_type",
+        0x0046 => "DW_TAG_atomic_type",
+        0x0047 => "DW_TAG_call_site",
+        0x0048 => "DW_TAG_call_site_parameter",
+        0x0049 => "DW_TAG_skeleton_unit",
+        0x004A => "DW_TAG_immutable_type",
+        0x4106 => "DW_TAG_GNU_template_template_param",
+        0x4107 => "DW_TAG_GNU_template_parameter_pack",
+        0x4108 => "DW_TAG_GNU_formal_parameter_pack",
+        0x4109 => "DW_TAG_GNU_call_site",
+        0x410A => "DW_TAG_GNU_call_site_parameter",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Name of a `DW_AT_*` value
+pub fn at_name(value: u64) -> &'static str {
+    match value {
+        0x0001 => "DW_AT_sibling",
+        0x0002 => "DW_AT_location",
+        0x0003 => "DW_AT_name",
+        0x0009 => "DW_AT_ordering",
+        0x000B => "DW_AT_byte_size",
+        0x000C => "DW_AT_bit_offset",
+        0x000D => "DW_AT_bit_size",
+        0x0010 => "DW_AT_stmt_list",
+        0x0011 => "DW_AT_low_pc",
+        0x0012 => "DW_AT_high_pc",
+        0x0013 => "DW_AT_language",
+        0x0015 => "DW_AT_discr",
+        0x0016 => "DW_AT_discr_value",
+        0x0017 => "DW_AT_visibility",
+        0x0018 => "DW_AT_import",
+        0x0019 => "DW_AT_string_length",
+        0x001A => "DW_AT_common_reference",
+        0x001B => "DW_AT_comp_dir",
+        0x001C => "DW_AT_const_value",
+        0x001D => "DW_AT_containing_type",
+        0x001E => "DW_AT_default_value",
+        0x0020 => "DW_AT_inline",
+        0x0021 => "DW_AT_is_optional",
+        0x0022 => "DW_AT_lower_bound",
+        0x0025 => "DW_AT_producer",
+        0x0027 => "DW_AT_prototyped",
+        0x002A => "DW_AT_return_addr",
+        0x002C => "DW_AT_start_scope",
+        0x002E => "DW_AT_bit_stride",
+        0x002F => "DW_AT_upper_bound",
+        0x0031 => "DW_AT_abstract_origin",
+        0x0032 => "DW_AT_accessibility",
+        0x0033 => "DW_AT_address_class",
+        0x0034 => "DW_AT_artificial",
+        0x0035 => "DW_AT_base_types",
+        0x0036 => "DW_AT_calling_convention",
+        0x0037 => "DW_AT_count",
+        0x0038 => "DW_AT_data_member_location",
+        0x0039 => "DW_AT_decl_column",
+        0x003A => "DW_AT_decl_file",
+        0x003B => "DW_AT_decl_line",
+        0x003C => "DW_AT_declaration",
+        0x003D => "DW_AT_discr_list",
+        0x003E => "DW_AT_encoding",
+        0x003F => "DW_AT_external",
+        0x0040 => "DW_AT_frame_base",
+        0x0041 => "DW_AT_friend",
+        0x0042 => "DW_AT_identifier_case",
+        0x0043 => "DW_AT_macro_info",
+        0x0044 => "DW_AT_namelist_item",
+        0x0045 => "DW_AT_priority",
+        0x0046 => "DW_AT_segment",
+        0x0047 => "DW_AT_specification",
+        0x0048 => "DW_AT_static_link",
+        0x0049 => "DW_AT_type",
+        0x004A => "DW_AT_use_location",
+        0x004B => "DW_AT_variable_parameter",
+        0x004C => "DW_AT_virtuality",
+        0x004D => "DW_AT_vtable_elem_location",
+        0x004E => "DW_AT_allocated",
+        0x004F => "DW_AT_associated",
+        0x0050 => "DW_AT_data_location",
+        0x0051 => "DW_AT_byte_stride",
+        0x0052 => "DW_AT_entry_pc",
+        0x0053 => "DW_AT_use_UTF8",
+        0x0054 => "DW_AT_extension",
+        0x0055 => "DW_AT_ranges",
+        0x0056 => "DW_AT_trampoline",
+        0x0057 => "DW_AT_call_column",
+        0x0058 => "DW_AT_call_file",
+        0x0059 => "DW_AT_call_line",
+        0x005A => "DW_AT_description",
+        0x005B => "DW_AT_binary_scale",
+        0x005C => "DW_AT_decimal_scale",
+        0x005D => "DW_AT_small",
+        0x005E => "DW_AT_decimal_sign",
+        0x005F => "DW_AT_digit_count",
+        0x0060 => "DW_AT_picture_string",
+        0x0061 => "DW_AT_mutable",
+        0x0062 => "DW_AT_threads_scaled",
+        0x0063 => "DW_AT_explicit",
+        0x0064 => "DW_AT_object_pointer",
+        0x0065 => "DW_AT_endianity",
+        0x0066 => "DW_AT_elemental",
+        0x0067 => "DW_AT_pure",
+        0x0068 => "DW_AT_recursive",
+        0x0069 => "DW_AT_signature",
+        0x006A => "DW_AT_main_subprogram",
+        0x006B => "DW_AT_data_bit_offset",
+        0x006C => "DW_AT_const_expr",
+        0x006D => "DW_AT_enum_class",
+        0x006E => "DW_AT_linkage_name",
+        0x006F => "DW_AT_string_length_bit_size",
+        0x0070 => "DW_AT_string_length_byte_size",
+        0x0071 => "DW_AT_rank",
+        0x0072 => "DW_AT_str_offsets_base",
+        0x0073 => "DW_AT_addr_base",
+        0x0074 => "DW_AT_rnglists_base",
+        0x0076 => "DW_AT_dwo_name",
+        0x0077 => "DW_AT_reference",
+        0x0078 => "DW_AT_rvalue_reference",
+        0x0079 => "DW_AT_macros",
+        0x007A => "DW_AT_call_all_calls",
+        0x007B => "DW_AT_call_all_source_calls",
+        0x007C => "DW_AT_call_all_tail_calls",
+        0x007D => "DW_AT_call_return_pc",
+        0x007E => "DW_AT_call_value",
+        0x007F => "DW_AT_call_origin",
+        0x0080 => "DW_AT_call_parameter",
+        0x0081 => "DW_AT_call_pc",
+        0x0082 => "DW_AT_call_tail_call",
+        0x0083 => "DW_AT_call_target",
+        0x0084 => "DW_AT_call_target_clobbered",
+        0x0085 => "DW_AT_call_data_location",
+        0x0086 => "DW_AT_call_data_value",
+        0x0087 => "DW_AT_noreturn",
+        0x0088 => "DW_AT_alignment",
+        0x0089 => "DW_AT_export_symbols",
+        0x008A => "DW_AT_deleted",
+        0x008B => "DW_AT_defaulted",
+        0x008C => "DW_AT_loclists_base",
+        0x2007 => "DW_AT_MIPS_linkage_name",
+        0x2101 => "DW_AT_sf_names",
+        0x2102 => "DW_AT_src_info",
+        0x2103 => "DW_AT_mac_info",
+        0x2104 => "DW_AT_src_coords",
+        0x2105 => "DW_AT_body_begin",
+        0x2106 => "DW_AT_body_end",
+        0x2107 => "DW_AT_GNU_vector",
+        0x2110 => "DW_AT_GNU_template_name",
+        0x2111 => "DW_AT_GNU_call_site_value",
+        0x2113 => "DW_AT_GNU_call_site_target",
+        0x2115 => "DW_AT_GNU_tail_call",
+        0x2116 => "DW_AT_GNU_all_tail_call_sites",
+        0x2117 => "DW_AT_GNU_all_call_sites",
+        0x2119 => "DW_AT_GNU_macros",
+        0x211A => "DW_AT_GNU_deleted",
+        0x2130 => "DW_AT_GNU_dwo_name",
+        0x2131 => "DW_AT_GNU_dwo_id",
+        0x2132 => "DW_AT_GNU_ranges_base",
+        0x2133 => "DW_AT_GNU_addr_base",
+        0x2134 => "DW_AT_GNU_pubnames",
+        0x2136 => "DW_AT_GNU_discriminator",
+        0x2137 => "DW_AT_GNU_locviews",
+        0x2138 => "DW_AT_GNU_entry_view",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Name of a `DW_UT_*` value
+pub fn ut_name(value: u8) -> &'static str {
+    match value {
+        0x0001 => "DW_UT_compile",
+        0x0002 => "DW_UT_type",
+        0x0003 => "DW_UT_partial",
+        0x0004 => "DW_UT_skeleton",
+        0x0005 => "DW_UT_split_compile",
+        0x0006 => "DW_UT_split_type",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Name of a `DW_FORM_*` value
+pub fn form_name(value: u64) -> &'static str {
+    match value {
+        0x0001 => "DW_FORM_addr",
+        0x0003 => "DW_FORM_block2",
+        0x0004 => "DW_FORM_block4",
+        0x0005 => "DW_FORM_data2",
+        0x0006 => "DW_FORM_data4",
+        0x0007 => "DW_FORM_data8",
+        0x0008 => "DW_FORM_string",
+        0x0009 => "DW_FORM_block",
+        0x000A => "DW_FORM_block1",
+        0x000B => "DW_FORM_data1",
+        0x000C => "DW_FORM_flag",
+        0x000D => "DW_FORM_sdata",
+        0x000E => "DW_FORM_strp",
+        0x000F => "DW_FORM_udata",
+        0x0010 => "DW_FORM_ref_addr",
+        0x0011 => "DW_FORM_ref1",
+        0x0012 => "DW_FORM_ref2",
+        0x0013 => "DW_FORM_ref4",
+        0x0014 => "DW_FORM_ref8",
+        0x0015 => "DW_FORM_ref_udata",
+        0x0016 => "DW_FORM_indirect",
+        0x0017 => "DW_FORM_sec_offset",
+        0x0018 => "DW_FORM_exprloc",
+        0x0019 => "DW_FORM_flag_present",
+        0x001A => "DW_FORM_strx",
+        0x001B => "DW_FORM_addrx",
+        0x001C => "DW_FORM_ref_sup4",
+        0x001D => "DW_FORM_strp_sup",
+        0x001E => "DW_FORM_data16",
+        0x001F => "DW_FORM_line_strp",
+        0x0020 => "DW_FORM_ref_sig8",
+        0x0021 => "DW_FORM_implicit_const",
+        0x0022 => "DW_FORM_loclistx",
+        0x0023 => "DW_FORM_rnglistx",
+        0x0024 => "DW_FORM_ref_sup8",
+        0x0025 => "DW_FORM_strx1",
+        0x0026 => "DW_FORM_strx2",
+        0x0027 => "DW_FORM_strx3",
+        0x0028 => "DW_FORM_strx4",
+        0x0029 => "DW_FORM_addrx1",
+        0x002A => "DW_FORM_addrx2",
+        0x002B => "DW_FORM_addrx3",
+        0x002C => "DW_FORM_addrx4",
+        0x1F01 => "DW_FORM_GNU_addr_index",
+        0x1F02 => "DW_FORM_GNU_str_index",
+        0x1F20 => "DW_FORM_GNU_ref_alt",
+        0x1F21 => "DW_FORM_GNU_strp_alt",
+        _ => "UNKNOWN",
+    }
+}
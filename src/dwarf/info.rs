@@ -0,0 +1,570 @@
+use crate::dwarf::common::{at, form, ut};
+use crate::dwarf::{Cursor, Dwarf, Encoding, Error, Value};
+use crate::elf::reader::Reader;
+use std::collections::HashMap;
+
+/// How an attribute of an abbreviation is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeSpec {
+    pub name: u64,
+    pub form: u64,
+    /// Value of the `DW_FORM_implicit_const` attributes, stored in the abbreviation itself.
+    pub implicit_const: i64,
+}
+
+/// The tag and attribute layout shared by the entries using the same abbreviation code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abbreviation {
+    code: u64,
+    tag: u64,
+    has_children: bool,
+    attributes: Vec<AttributeSpec>,
+}
+
+impl Abbreviation {
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    pub fn tag(&self) -> u64 {
+        self.tag
+    }
+
+    pub fn has_children(&self) -> bool {
+        self.has_children
+    }
+
+    pub fn attributes(&self) -> &[AttributeSpec] {
+        &self.attributes
+    }
+}
+
+/// The abbreviation table at `offset` of `.debug_abbrev`, by code.
+fn abbreviations(
+    dwarf: &Dwarf,
+    offset: u64,
+    unit: usize,
+) -> Result<HashMap<u64, Abbreviation>, Error> {
+    let section = dwarf.section(".debug_abbrev");
+    let mut cursor = Cursor::new(".debug_abbrev", section, dwarf.reader());
+    cursor.bytes(offset)?;
+
+    let mut abbreviations = HashMap::new();
+
+    loop {
+        let code = cursor.uleb()?;
+        if code == 0 {
+            return Ok(abbreviations);
+        }
+
+        let tag = cursor.uleb()?;
+        let has_children = cursor.uint(1)? != 0;
+
+        let mut attributes = Vec::new();
+        loop {
+            let name = cursor.uleb()?;
+            let form = cursor.uleb()?;
+            if name == 0 && form == 0 {
+                break;
+            }
+
+            let implicit_const = match form {
+                form::IMPLICIT_CONST => cursor.sleb()?,
+                _ => 0,
+            };
+
+            attributes.push(AttributeSpec {
+                name,
+                form,
+                implicit_const,
+            });
+        }
+
+        let abbreviation = Abbreviation {
+            code,
+            tag,
+            has_children,
+            attributes,
+        };
+        if abbreviations.insert(code, abbreviation).is_some() {
+            return Err(Error::Info {
+                offset: unit,
+                err: format!("abbreviation {code} is defined twice"),
+            });
+        }
+    }
+}
+
+/// An attribute of an entry and its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attribute<'d> {
+    pub name: u64,
+    pub form: u64,
+    pub value: Value<'d>,
+}
+
+/// A debugging information entry, e.g. a function, a variable or a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Die<'d> {
+    offset: usize,
+    size: usize,
+    tag: u64,
+    has_children: bool,
+    attributes: Vec<Attribute<'d>>,
+}
+
+impl<'d> Die<'d> {
+    /// Offset of the entry in `.debug_info`.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Size of the entry in `.debug_info`, its abbreviation code and attributes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn tag(&self) -> u64 {
+        self.tag
+    }
+
+    pub fn has_children(&self) -> bool {
+        self.has_children
+    }
+
+    pub fn attributes(&self) -> &[Attribute<'d>] {
+        &self.attributes
+    }
+
+    /// Value of the attribute `name`, `DW_AT_*`.
+    pub fn attr(&self, name: u64) -> Option<Value<'d>> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| attribute.value)
+    }
+
+    pub fn name(&self) -> Option<&'d str> {
+        self.attr(at::NAME)?.string()
+    }
+}
+
+/// A unit of `.debug_info`, the header and the tree of entries after it.
+#[derive(Debug, Clone)]
+pub struct Unit<'d> {
+    dwarf: &'d Dwarf<'d>,
+    offset: usize,
+    encoding: Encoding,
+    unit_type: u8,
+    abbrev_offset: u64,
+    /// `dwo_id` of skeleton and split units, signature of type units.
+    id: Option<u64>,
+    type_offset: Option<u64>,
+    abbreviations: HashMap<u64, Abbreviation>,
+    entries: Cursor<'d>,
+    str_offsets_base: u64,
+    addr_base: u64,
+}
+
+impl<'d> Unit<'d> {
+    fn new(dwarf: &'d Dwarf<'d>, cursor: &mut Cursor<'d>) -> Result<Self, Error> {
+        let offset = cursor.offset();
+        let malformed = |err: String| Error::Info { offset, err };
+
+        let (length, offset_size) = cursor.initial_length()?;
+        let mut unit = cursor.split(length)?;
+
+        let version = unit.uint(2)? as u16;
+        let (unit_type, address_size, abbrev_offset) = match version {
+            2..=4 => {
+                let abbrev_offset = unit.uint(offset_size)?;
+                (ut::COMPILE, unit.uint(1)? as u8, abbrev_offset)
+            }
+            5 => (
+                unit.uint(1)? as u8,
+                unit.uint(1)? as u8,
+                unit.uint(offset_size)?,
+            ),
+            _ => return Err(malformed(format!("unsupported version {version}"))),
+        };
+
+        let (id, type_offset) = match unit_type {
+            ut::SKELETON | ut::SPLIT_COMPILE if version == 5 => (Some(unit.uint(8)?), None),
+            ut::TYPE | ut::SPLIT_TYPE if version == 5 => {
+                (Some(unit.uint(8)?), Some(unit.uint(offset_size)?))
+            }
+            _ => (None, None),
+        };
+
+        // The bases point past the header of `.debug_str_offsets` and `.debug_addr` when the
+        // unit leaves them out
+        let mut unit = Self {
+            dwarf,
+            offset,
+            encoding: Encoding {
+                version,
+                address_size,
+                offset_size,
+            },
+            unit_type,
+            abbrev_offset,
+            id,
+            type_offset,
+            abbreviations: abbreviations(dwarf, abbrev_offset, offset)?,
+            entries: unit,
+            str_offsets_base: 2 * u64::from(offset_size),
+            addr_base: 2 * u64::from(offset_size),
+        };
+
+        let root = unit.entry(&mut unit.entries.clone())?;
+        if let Some(root) = root {
+            if let Some(Value::SectionOffset(base)) = root.attr(at::STR_OFFSETS_BASE) {
+                unit.str_offsets_base = base;
+            }
+            if let Some(Value::SectionOffset(base)) = root
+                .attr(at::ADDR_BASE)
+                .or_else(|| root.attr(at::GNU_ADDR_BASE))
+            {
+                unit.addr_base = base;
+            }
+        }
+
+        Ok(unit)
+    }
+
+    /// Offset of the unit header in `.debug_info`.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn version(&self) -> u16 {
+        self.encoding.version
+    }
+
+    /// `DW_UT_*`, always `DW_UT_compile` before DWARF 5.
+    pub fn unit_type(&self) -> u8 {
+        self.unit_type
+    }
+
+    pub fn abbrev_offset(&self) -> u64 {
+        self.abbrev_offset
+    }
+
+    /// `dwo_id` of skeleton and split units, type signature of type units.
+    pub fn id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Offset of the type of a type unit from the start of the unit.
+    pub fn type_offset(&self) -> Option<u64> {
+        self.type_offset
+    }
+
+    /// Entries of the unit in the order of the section, with their depth in the tree.
+    pub fn entries(&self) -> Entries<'d, '_> {
+        Entries {
+            unit: self,
+            cursor: self.entries.clone(),
+            depth: 0,
+        }
+    }
+
+    /// Entries of the unit, linked to their parent and children.
+    pub fn tree(&self) -> Result<DieTree<'d>, Error> {
+        let mut tree = DieTree {
+            unit: self.offset,
+            encoding: self.encoding,
+            reader: self.dwarf.reader(),
+            dies: Vec::new(),
+            parents: Vec::new(),
+            children: Vec::new(),
+            depths: Vec::new(),
+        };
+        let mut parents: Vec<usize> = Vec::new();
+
+        for entry in self.entries() {
+            let (depth, die) = entry?;
+            let index = tree.dies.len();
+
+            parents.truncate(depth);
+            let parent = parents.last().copied();
+            if let Some(parent) = parent {
+                tree.children[parent].push(index);
+            }
+            if die.has_children {
+                parents.push(index);
+            }
+
+            tree.dies.push(die);
+            tree.parents.push(parent);
+            tree.children.push(Vec::new());
+            tree.depths.push(depth);
+        }
+
+        Ok(tree)
+    }
+
+    /// The entry at `cursor`, `None` for the null entries ending a list of children.
+    fn entry(&self, cursor: &mut Cursor<'d>) -> Result<Option<Die<'d>>, Error> {
+        let offset = cursor.offset();
+
+        let code = cursor.uleb()?;
+        if code == 0 {
+            return Ok(None);
+        }
+
+        let abbreviation = self.abbreviations.get(&code).ok_or(Error::Info {
+            offset: self.offset,
+            err: format!("unknown abbreviation {code} at {offset:#x}"),
+        })?;
+
+        let attributes = abbreviation
+            .attributes
+            .iter()
+            .map(|spec| {
+                let value = match spec.form {
+                    form::IMPLICIT_CONST => Value::Signed(spec.implicit_const),
+                    form => self.dwarf.value(cursor, form, self.encoding)?,
+                };
+
+                Ok(Attribute {
+                    name: spec.name,
+                    form: spec.form,
+                    value: self.resolve(value),
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Some(Die {
+            offset,
+            size: cursor.offset() - offset,
+            tag: abbreviation.tag,
+            has_children: abbreviation.has_children,
+            attributes,
+        }))
+    }
+
+    /// Reads the strings and addresses `value` is the index of, leaves it as is when the
+    /// index is out of the sections.
+    fn resolve(&self, value: Value<'d>) -> Value<'d> {
+        let entry = |section, base: u64, index: u64, size: u8| {
+            let offset = base.checked_add(index.checked_mul(size.into())?)?;
+            let mut cursor = Cursor::new(section, self.dwarf.section(section), self.dwarf.reader());
+            cursor.bytes(offset).ok()?;
+            cursor.uint(size).ok()
+        };
+
+        match value {
+            Value::StringIndex(index) => entry(
+                ".debug_str_offsets",
+                self.str_offsets_base,
+                index,
+                self.encoding.offset_size,
+            )
+            .map_or(value, |offset| Value::String(self.dwarf.string(offset))),
+            Value::AddressIndex(index) => entry(
+                ".debug_addr",
+                self.addr_base,
+                index,
+                self.encoding.address_size,
+            )
+            .map_or(value, Value::Address),
+            value => value,
+        }
+    }
+}
+
+/// Entries of a unit in pre-order, with their depth. Stops after the first error.
+#[derive(Debug, Clone)]
+pub struct Entries<'d, 'u> {
+    unit: &'u Unit<'d>,
+    cursor: Cursor<'d>,
+    depth: usize,
+}
+
+impl<'d> Iterator for Entries<'d, '_> {
+    type Item = Result<(usize, Die<'d>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.cursor.is_empty() {
+                return None;
+            }
+
+            match self.unit.entry(&mut self.cursor) {
+                Ok(Some(die)) => {
+                    let depth = self.depth;
+                    if die.has_children {
+                        self.depth += 1;
+                    }
+
+                    return Some(Ok((depth, die)));
+                }
+                // Padding after the last entry is null entries too
+                Ok(None) => self.depth = self.depth.saturating_sub(1),
+                Err(err) => {
+                    self.cursor.skip_all();
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Units of `.debug_info`, in the order of the section. Stops after the first error, the
+/// units after a malformed one cannot be found.
+#[derive(Debug, Clone)]
+pub struct Units<'d> {
+    dwarf: &'d Dwarf<'d>,
+    cursor: Cursor<'d>,
+}
+
+impl<'d> Units<'d> {
+    pub(crate) fn new(dwarf: &'d Dwarf<'d>, cursor: Cursor<'d>) -> Self {
+        Self { dwarf, cursor }
+    }
+}
+
+impl<'d> Iterator for Units<'d> {
+    type Item = Result<Unit<'d>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor.is_empty() {
+            return None;
+        }
+
+        let unit = Unit::new(self.dwarf, &mut self.cursor);
+        if unit.is_err() {
+            self.cursor.skip_all();
+        }
+
+        Some(unit)
+    }
+}
+
+/// The entries of a unit, by index in the order of the section, the root at index 0.
+#[derive(Debug, Clone)]
+pub struct DieTree<'d> {
+    unit: usize,
+    encoding: Encoding,
+    reader: Reader,
+    dies: Vec<Die<'d>>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    depths: Vec<usize>,
+}
+
+impl<'d> DieTree<'d> {
+    /// Offset of the unit in `.debug_info`.
+    pub fn unit(&self) -> usize {
+        self.unit
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn reader(&self) -> Reader {
+        self.reader
+    }
+
+    pub fn dies(&self) -> &[Die<'d>] {
+        &self.dies
+    }
+
+    pub fn root(&self) -> Option<&Die<'d>> {
+        self.dies.first()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Die<'d>> {
+        self.dies.get(index)
+    }
+
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents.get(index).copied().flatten()
+    }
+
+    pub fn children(&self, index: usize) -> &[usize] {
+        self.children.get(index).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn depth(&self, index: usize) -> usize {
+        self.depths.get(index).copied().unwrap_or(0)
+    }
+
+    /// Index of the entry at `offset` of `.debug_info`.
+    pub fn by_offset(&self, offset: usize) -> Option<usize> {
+        self.dies.binary_search_by_key(&offset, Die::offset).ok()
+    }
+
+    /// Index of the entry a reference points to, `None` for the ones out of the unit.
+    pub fn reference(&self, value: Value) -> Option<usize> {
+        let offset = match value {
+            Value::Reference(offset) => self.unit as u64 + offset,
+            Value::InfoReference(offset) => offset,
+            _ => return None,
+        };
+
+        self.by_offset(usize::try_from(offset).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dwarf::common::{at, tag};
+    use crate::dwarf::{Dwarf, Value};
+    use crate::elf::Elf;
+
+    #[test]
+    fn units_of_each_version() {
+        for (file, version) in [
+            (&include_bytes!("../../test/debug2")[..], 2),
+            (include_bytes!("../../test/debug4"), 4),
+            (include_bytes!("../../test/debug5"), 5),
+        ] {
+            let elf = Elf::parse(file).unwrap();
+            let dwarf = Dwarf::new(&elf).unwrap();
+
+            let unit = dwarf.units().next().unwrap().unwrap();
+            assert_eq!(unit.version(), version);
+            assert_eq!(unit.encoding().address_size, 8);
+
+            let tree = unit.tree().unwrap();
+            let root = tree.root().unwrap();
+            assert_eq!(root.tag(), tag::COMPILE_UNIT);
+            assert!(root.name().unwrap().ends_with("debug.c"), "{version}");
+
+            let shape = tree
+                .dies()
+                .iter()
+                .position(|die| die.tag() == tag::STRUCTURE_TYPE && die.name() == Some("shape"))
+                .unwrap();
+            assert_eq!(tree.parent(shape), Some(0));
+            assert_eq!(tree.depth(shape), 1);
+            assert_eq!(
+                tree.get(shape).unwrap().attr(at::BYTE_SIZE),
+                Some(Value::Unsigned(64))
+            );
+
+            let members: Vec<_> = tree
+                .children(shape)
+                .iter()
+                .map(|&index| tree.get(index).unwrap().name().unwrap())
+                .collect();
+            assert_eq!(
+                members,
+                ["kind", "origin", "sides", "visible", "layer", "value", "corners", "next"]
+            );
+
+            // `next` points back to the structure
+            let next = tree.get(*tree.children(shape).last().unwrap()).unwrap();
+            let pointer = tree.reference(next.attr(at::TYPE).unwrap()).unwrap();
+            let target = tree.get(pointer).unwrap().attr(at::TYPE).unwrap();
+            assert_eq!(tree.reference(target), Some(shape));
+        }
+    }
+}
@@ -0,0 +1,754 @@
+use crate::dwarf::common::{at, op, tag};
+use crate::dwarf::info::{Die, DieTree};
+use crate::dwarf::{Cursor, Value};
+use crate::elf::reader::Endian;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Range;
+
+/// Size of the cachelines the layouts are split in.
+pub const CACHELINE: u64 = 64;
+
+/// Types referring to each other deeper than this are cut, e.g. corrupt loops.
+const MAX_DEPTH: usize = 32;
+
+/// A data member of a structure and where it lies in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    name: Option<String>,
+    type_name: String,
+    /// Array dimensions, after the name.
+    suffix: String,
+    offset: u64,
+    size: u64,
+    /// Bit offset from the start of the storage at `offset` and width of bitfields.
+    bits: Option<(u64, u64)>,
+    hole: u64,
+    bit_hole: u64,
+    die: Range<usize>,
+}
+
+impl Member {
+    /// `None` for anonymous structures and unions, and for base classes.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// The name with the array dimensions and bitfield width, as declared.
+    pub fn declarator(&self) -> String {
+        let mut declarator = format!("{}{}", self.name.as_deref().unwrap_or(""), self.suffix);
+
+        if let Some((_, width)) = self.bits {
+            declarator += &format!(":{width}");
+        }
+
+        declarator
+    }
+
+    /// Offset of the member, of the storage unit holding it for bitfields.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Size of the member, of the storage unit holding it for bitfields.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Bit offset in the storage unit, in the order of the bytes, and width of bitfields.
+    pub fn bits(&self) -> Option<(u64, u64)> {
+        self.bits
+    }
+
+    /// Unused bytes between the member and the next one.
+    pub fn hole(&self) -> u64 {
+        self.hole
+    }
+
+    /// Unused bits between the member and the next one, for bitfields the rest of their
+    /// storage unit.
+    pub fn bit_hole(&self) -> u64 {
+        self.bit_hole
+    }
+
+    /// The entry of the member in `.debug_info`.
+    pub fn die(&self) -> Range<usize> {
+        self.die.clone()
+    }
+
+    /// First bit of the member, `None` when a crafted offset overflows it.
+    fn start(&self) -> Option<u64> {
+        self.offset
+            .checked_mul(8)?
+            .checked_add(self.bits.map_or(0, |(offset, _)| offset))
+    }
+
+    /// Bit past the last one of the member.
+    fn end(&self) -> Option<u64> {
+        match self.bits {
+            Some((offset, width)) => self
+                .offset
+                .checked_mul(8)?
+                .checked_add(offset)?
+                .checked_add(width),
+            None => self.limit(),
+        }
+    }
+
+    /// Bit past the storage unit of the member, the same as [`Member::end`] but for bitfields.
+    fn limit(&self) -> Option<u64> {
+        self.offset.checked_add(self.size)?.checked_mul(8)
+    }
+}
+
+/// Where the members of a structure, union or class lie, and the bytes lost in between, as
+/// `pahole` shows them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    kind: &'static str,
+    name: Option<String>,
+    /// Name of the typedef of an anonymous type.
+    typedef: Option<String>,
+    size: u64,
+    members: Vec<Member>,
+    padding: u64,
+    bit_padding: u64,
+    die: Range<usize>,
+}
+
+impl Layout {
+    /// Layout of the structure, union or class at `index` of `tree`, `None` for other entries
+    /// and declarations without a size.
+    pub fn new(tree: &DieTree, index: usize) -> Option<Self> {
+        let die = tree.get(index)?;
+        let kind = match die.tag() {
+            tag::STRUCTURE_TYPE => "struct",
+            tag::UNION_TYPE => "union",
+            tag::CLASS_TYPE => "class",
+            _ => return None,
+        };
+        let size = die.attr(at::BYTE_SIZE)?.unsigned()?;
+
+        let mut members: Vec<_> = tree
+            .children(index)
+            .iter()
+            .filter_map(|&child| member(tree, child))
+            .collect();
+
+        // In the order of memory, which is not the one of the declarations in e.g. Rust.
+        // Holes are counted from the furthest byte and bit used so far, members may overlap
+        if kind != "union" {
+            members.sort_by_key(Member::start);
+        }
+
+        let (mut end, mut limit) = (0, 0);
+        let mut previous: Option<usize> = None;
+        for index in 0..members.len() {
+            let start = members[index].start()?;
+
+            if let (Some(previous), "struct" | "class") = (previous, kind) {
+                let (hole, bit_hole) = hole(end, limit, start);
+                members[previous].hole = hole;
+                members[previous].bit_hole = bit_hole;
+            }
+
+            let member = &members[index];
+            end = end.max(member.end()?);
+            limit = limit.max(member.limit()?);
+            previous = Some(index);
+        }
+        let (padding, bit_padding) = hole(end, limit, size.checked_mul(8)?);
+
+        Some(Self {
+            kind,
+            name: die.name().map(String::from),
+            typedef: None,
+            size,
+            members,
+            padding,
+            bit_padding,
+            die: die.offset()..die.offset() + die.size(),
+        })
+    }
+
+    /// Layouts of the named structures, unions and classes of a unit, and of the anonymous
+    /// ones with a typedef.
+    pub fn of_unit(tree: &DieTree) -> Vec<Self> {
+        let typedefs: HashMap<usize, &str> = tree
+            .dies()
+            .iter()
+            .filter(|die| die.tag() == tag::TYPEDEF)
+            .filter_map(|die| Some((tree.reference(die.attr(at::TYPE)?)?, die.name()?)))
+            .collect();
+
+        (0..tree.dies().len())
+            .filter_map(|index| {
+                let mut layout = Self::new(tree, index)?;
+                if layout.name.is_none() {
+                    layout.typedef = Some(typedefs.get(&index)?.to_string());
+                }
+
+                Some(layout)
+            })
+            .collect()
+    }
+
+    /// `struct`, `union` or `class`.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Name of the type, or of its typedef when it is anonymous.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref().or(self.typedef.as_deref())
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    /// Unused bytes after the last member.
+    pub fn padding(&self) -> u64 {
+        self.padding
+    }
+
+    /// Unused bits after the last member, up to the end of its storage unit.
+    pub fn bit_padding(&self) -> u64 {
+        self.bit_padding
+    }
+
+    /// The entry of the type in `.debug_info`.
+    pub fn die(&self) -> Range<usize> {
+        self.die.clone()
+    }
+
+    pub fn cachelines(&self) -> u64 {
+        self.size.div_ceil(CACHELINE)
+    }
+
+    /// Bytes of the members, leaving out bitfields.
+    pub fn sum_members(&self) -> u64 {
+        self.members
+            .iter()
+            .filter(|member| member.bits.is_none())
+            .map(|member| member.size)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Bits of the bitfields.
+    pub fn sum_bitfields(&self) -> u64 {
+        self.members
+            .iter()
+            .filter_map(|member| member.bits)
+            .map(|(_, width)| width)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// The number of holes and the bytes lost to them.
+    pub fn holes(&self) -> (usize, u64) {
+        let holes = self.members.iter().filter(|member| member.hole != 0);
+        (
+            holes.clone().count(),
+            holes.map(|member| member.hole).fold(0, u64::saturating_add),
+        )
+    }
+
+    /// The number of bit holes and the bits lost to them.
+    pub fn bit_holes(&self) -> (usize, u64) {
+        let holes = self.members.iter().filter(|member| member.bit_hole != 0);
+        (
+            holes.clone().count(),
+            holes
+                .map(|member| member.bit_hole)
+                .fold(0, u64::saturating_add),
+        )
+    }
+}
+
+/// `struct shape { ... };` with the offset and size of each member, the holes after them and
+/// a summary, as `pahole` prints it.
+impl Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.name, &self.typedef) {
+            (Some(name), _) => writeln!(f, "{} {} {{", self.kind, name)?,
+            (None, Some(_)) => writeln!(f, "typedef {} {{", self.kind)?,
+            (None, None) => writeln!(f, "{} {{", self.kind)?,
+        }
+
+        let types = self.members.iter().map(|member| member.type_name.len());
+        let declarators = self.members.iter().map(|member| member.declarator().len());
+        let types = types.max().unwrap_or(0);
+        let declarators = declarators.max().unwrap_or(0) + 1;
+
+        let mut cacheline = 1;
+        for member in &self.members {
+            if member.offset >= cacheline * CACHELINE {
+                cacheline = member.offset / CACHELINE;
+                let boundary = cacheline * CACHELINE;
+
+                match member.offset - boundary {
+                    0 => writeln!(f, "\t/* --- cacheline {cacheline} boundary ({boundary} bytes) --- */")?,
+                    ago => writeln!(
+                        f,
+                        "\t/* --- cacheline {cacheline} boundary ({boundary} bytes) was {ago} bytes ago --- */"
+                    )?,
+                }
+                cacheline += 1;
+            }
+
+            let position = match member.bits {
+                Some((bit, _)) => format!("{}:{}", member.offset, bit),
+                None => member.offset.to_string(),
+            };
+            writeln!(
+                f,
+                "\t{:types$} {:declarators$} /* {:>8} {:>5} */",
+                member.type_name,
+                format!("{};", member.declarator()),
+                position,
+                member.size
+            )?;
+
+            let holes = [(member.bit_hole, "bits"), (member.hole, "bytes")];
+            let holes: Vec<_> = holes.iter().filter(|(size, _)| *size != 0).collect();
+
+            if !holes.is_empty() {
+                writeln!(f)?;
+                for (size, unit) in holes {
+                    writeln!(f, "\t/* XXX {size} {unit} hole, try to pack */")?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        let (holes, sum_holes) = self.holes();
+        let (bit_holes, sum_bit_holes) = self.bit_holes();
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "\t/* size: {}, cachelines: {}, members: {} */",
+            self.size,
+            self.cachelines(),
+            self.members.len()
+        )?;
+        // The members of unions overlap, there is nothing to sum
+        if self.kind != "union" {
+            writeln!(
+                f,
+                "\t/* sum members: {}, holes: {holes}, sum holes: {sum_holes} */",
+                self.sum_members()
+            )?;
+        }
+        if self.sum_bitfields() != 0 {
+            writeln!(
+                f,
+                "\t/* sum bitfield members: {} bits, bit holes: {bit_holes}, sum bit holes: {sum_bit_holes} bits */",
+                self.sum_bitfields()
+            )?;
+        }
+        if self.padding != 0 {
+            writeln!(f, "\t/* padding: {} */", self.padding)?;
+        }
+        if self.bit_padding != 0 {
+            writeln!(f, "\t/* bit_padding: {} bits */", self.bit_padding)?;
+        }
+        if !self.size.is_multiple_of(CACHELINE) {
+            writeln!(f, "\t/* last cacheline: {} bytes */", self.size % CACHELINE)?;
+        }
+
+        match (&self.name, &self.typedef) {
+            (None, Some(typedef)) => write!(f, "}} {typedef};"),
+            _ => write!(f, "}};"),
+        }
+    }
+}
+
+/// The unused bytes and bits from `end`, the furthest bit used, to `start`, the next one
+/// used. The bits up to `limit`, the end of the storage unit of a bitfield, and those left
+/// over from the last full byte are the bit hole.
+fn hole(end: u64, limit: u64, start: u64) -> (u64, u64) {
+    let limit = limit.max(end);
+    let bits = start.min(limit).saturating_sub(end);
+    let gap = start.saturating_sub(limit);
+
+    (gap / 8, bits + gap % 8)
+}
+
+/// The data member or base class at `index`, `None` for other entries, e.g. the static
+/// members and functions of classes.
+fn member(tree: &DieTree, index: usize) -> Option<Member> {
+    let die = tree.get(index)?;
+    if !matches!(die.tag(), tag::MEMBER | tag::INHERITANCE) || die.attr(at::DECLARATION).is_some() {
+        return None;
+    }
+
+    let r#type = die.attr(at::TYPE).and_then(|value| tree.reference(value));
+    let (type_name, suffix) = describe(tree, r#type, 0);
+    let type_size = size(tree, r#type, 0).unwrap_or(0);
+
+    // Members of unions have no location
+    let location = match die.attr(at::DATA_MEMBER_LOCATION) {
+        Some(Value::Block(expression)) => location(tree, expression),
+        Some(value) => value.unsigned(),
+        None => None,
+    };
+
+    let bit_size = die.attr(at::BIT_SIZE).and_then(|value| value.unsigned());
+    let byte_size = die.attr(at::BYTE_SIZE).and_then(|value| value.unsigned());
+
+    let (offset, size, bits) = match (bit_size, die.attr(at::DATA_BIT_OFFSET)) {
+        (Some(width), Some(bit_offset)) => {
+            let size = byte_size.unwrap_or(type_size).max(1);
+            let bit_offset = bit_offset.unsigned()?;
+            let offset = bit_offset / size.checked_mul(8)? * size;
+
+            (offset, size, Some((bit_offset - offset * 8, width)))
+        }
+        // Before DWARF 4 the bit offset is from the most significant bit of the storage unit
+        (Some(width), None) => {
+            let size = byte_size.unwrap_or(type_size).max(1);
+            let msb = die
+                .attr(at::BIT_OFFSET)
+                .and_then(|value| value.unsigned())
+                .unwrap_or(0);
+            let bit = match tree.reader().endian() {
+                Endian::Little => size.checked_mul(8)?.saturating_sub(msb.checked_add(width)?),
+                Endian::Big => msb,
+            };
+
+            (location.unwrap_or(0), size, Some((bit, width)))
+        }
+        (None, _) => (location.unwrap_or(0), type_size, None),
+    };
+
+    let member = Member {
+        name: die.name().map(String::from),
+        type_name,
+        suffix,
+        offset,
+        size,
+        bits,
+        hole: 0,
+        bit_hole: 0,
+        die: die.offset()..die.offset() + die.size(),
+    };
+
+    // Crafted sizes and offsets can put the bits of a member past the end of a u64
+    member.start()?;
+    member.end()?;
+    member.limit()?;
+
+    Some(member)
+}
+
+/// Offset of a member from the location expression of DWARF 2 and 3, `DW_OP_plus_uconst`.
+fn location(tree: &DieTree, expression: &[u8]) -> Option<u64> {
+    let mut cursor = Cursor::new(".debug_info", expression, tree.reader());
+
+    match cursor.uint(1).ok()? as u8 {
+        op::PLUS_UCONST | op::CONSTU => cursor.uleb().ok(),
+        _ => None,
+    }
+}
+
+/// The name of a type, split around the name of what it declares, e.g. `("char", "[16]")`.
+fn describe(tree: &DieTree, index: Option<usize>, depth: usize) -> (String, String) {
+    let Some((index, die)) = index.and_then(|index| Some((index, tree.get(index)?))) else {
+        return ("void".into(), String::new());
+    };
+    if depth > MAX_DEPTH {
+        return ("...".into(), String::new());
+    }
+
+    let inner = || {
+        let r#type = die.attr(at::TYPE).and_then(|value| tree.reference(value));
+        describe(tree, r#type, depth + 1)
+    };
+    let named = |keyword: &str| {
+        let name = die.name().unwrap_or("{...}");
+        match keyword {
+            "" => name.to_string(),
+            keyword => format!("{keyword} {name}"),
+        }
+    };
+
+    match die.tag() {
+        tag::STRUCTURE_TYPE => (named("struct"), String::new()),
+        tag::UNION_TYPE => (named("union"), String::new()),
+        tag::CLASS_TYPE => (named("class"), String::new()),
+        tag::ENUMERATION_TYPE => (named("enum"), String::new()),
+        tag::POINTER_TYPE => match subroutine(tree, die) {
+            Some(result) => (format!("{result} (*)()"), String::new()),
+            None => {
+                let (name, suffix) = inner();
+                (format!("{name} *"), suffix)
+            }
+        },
+        tag::REFERENCE_TYPE => (format!("{} &", inner().0), String::new()),
+        tag::RVALUE_REFERENCE_TYPE => (format!("{} &&", inner().0), String::new()),
+        tag::CONST_TYPE => qualified("const", inner()),
+        tag::VOLATILE_TYPE => qualified("volatile", inner()),
+        tag::ATOMIC_TYPE => qualified("_Atomic", inner()),
+        tag::RESTRICT_TYPE => {
+            let (name, suffix) = inner();
+            (format!("{name} restrict"), suffix)
+        }
+        tag::ARRAY_TYPE => {
+            let (name, suffix) = inner();
+            let dimensions: String = tree
+                .children(index)
+                .iter()
+                .filter_map(|&index| tree.get(index))
+                .filter(|die| die.tag() == tag::SUBRANGE_TYPE)
+                .map(|subrange| match count(subrange) {
+                    Some(count) => format!("[{count}]"),
+                    None => "[]".into(),
+                })
+                .collect();
+
+            (name, dimensions + &suffix)
+        }
+        tag::SUBROUTINE_TYPE => ("void ()".into(), String::new()),
+        _ => (named(""), String::new()),
+    }
+}
+
+/// `const int`, but `char * const` for pointers as the qualifier applies to what is left of it.
+fn qualified(qualifier: &str, (name, suffix): (String, String)) -> (String, String) {
+    match name.ends_with('*') {
+        true => (format!("{name} {qualifier}"), suffix),
+        false => (format!("{qualifier} {name}"), suffix),
+    }
+}
+
+/// Return type of the function a pointer points to.
+fn subroutine(tree: &DieTree, pointer: &Die) -> Option<String> {
+    let index = tree.reference(pointer.attr(at::TYPE)?)?;
+    let die = tree.get(index)?;
+
+    (die.tag() == tag::SUBROUTINE_TYPE).then(|| {
+        let r#type = die.attr(at::TYPE).and_then(|value| tree.reference(value));
+        describe(tree, r#type, 1).0
+    })
+}
+
+/// Elements of the dimension of an array, `None` for flexible arrays.
+fn count(subrange: &Die) -> Option<u64> {
+    if let Some(count) = subrange.attr(at::COUNT) {
+        return count.unsigned();
+    }
+
+    let lower = subrange
+        .attr(at::LOWER_BOUND)
+        .and_then(|value| value.unsigned())
+        .unwrap_or(0);
+    let upper = subrange.attr(at::UPPER_BOUND)?;
+
+    // Flexible arrays of old compilers have an upper bound of -1
+    match upper {
+        Value::Signed(-1) => None,
+        upper => upper.unsigned()?.checked_add(1)?.checked_sub(lower),
+    }
+}
+
+/// Size in bytes of the type at `index`, following typedefs and qualifiers.
+fn size(tree: &DieTree, index: Option<usize>, depth: usize) -> Option<u64> {
+    let die = tree.get(index?)?;
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    if let Some(size) = die.attr(at::BYTE_SIZE) {
+        return size.unsigned();
+    }
+
+    let inner = || {
+        let r#type = die.attr(at::TYPE).and_then(|value| tree.reference(value));
+        size(tree, r#type, depth + 1)
+    };
+
+    match die.tag() {
+        tag::POINTER_TYPE
+        | tag::REFERENCE_TYPE
+        | tag::RVALUE_REFERENCE_TYPE
+        | tag::PTR_TO_MEMBER_TYPE => Some(tree.encoding().address_size.into()),
+        tag::TYPEDEF
+        | tag::CONST_TYPE
+        | tag::VOLATILE_TYPE
+        | tag::RESTRICT_TYPE
+        | tag::ATOMIC_TYPE
+        | tag::IMMUTABLE_TYPE
+        | tag::PACKED_TYPE
+        | tag::SHARED_TYPE => inner(),
+        tag::ARRAY_TYPE => {
+            let elements = tree
+                .children(index?)
+                .iter()
+                .filter_map(|&index| tree.get(index))
+                .filter(|die| die.tag() == tag::SUBRANGE_TYPE)
+                .map(|subrange| count(subrange).unwrap_or(0))
+                .product::<u64>();
+
+            inner()?.checked_mul(elements)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Layout;
+    use crate::dwarf::Dwarf;
+    use crate::elf::reader::{Class, Endian, Reader};
+    use crate::elf::Elf;
+    use std::borrow::Cow;
+
+    #[test]
+    fn layout_of_each_version() {
+        for (file, version) in [
+            (&include_bytes!("../../test/debug2")[..], 2),
+            (include_bytes!("../../test/debug4"), 4),
+            (include_bytes!("../../test/debug5"), 5),
+        ] {
+            let elf = Elf::parse(file).unwrap();
+            let dwarf = Dwarf::new(&elf).unwrap();
+            let tree = dwarf.units().next().unwrap().unwrap().tree().unwrap();
+            let layouts = Layout::of_unit(&tree);
+
+            let shape = layouts
+                .iter()
+                .find(|layout| layout.name() == Some("shape"))
+                .unwrap();
+            assert_eq!(shape.size(), 64, "{version}");
+            assert_eq!(shape.cachelines(), 1);
+
+            let members: Vec<_> = shape
+                .members()
+                .iter()
+                .map(|member| (member.type_name(), member.declarator(), member.offset()))
+                .collect();
+            assert_eq!(members[0], ("char", "kind".into(), 0));
+            assert_eq!(members[1], ("struct point", "origin".into(), 4));
+            assert_eq!(members[4], ("unsigned int", "layer:4".into(), 12));
+            assert_eq!(members[6], ("struct point", "corners[4]".into(), 24));
+            assert_eq!(members[7], ("struct shape *", "next".into(), 56));
+
+            assert_eq!(shape.members()[3].bits(), Some((16, 1)));
+            assert_eq!(shape.members()[4].bits(), Some((17, 4)));
+
+            assert_eq!(shape.members()[0].hole(), 3);
+            assert_eq!(shape.holes(), (1, 3));
+            assert_eq!(shape.bit_holes(), (1, 11));
+            assert_eq!(shape.sum_members(), 59);
+            assert_eq!(shape.sum_bitfields(), 5);
+            assert_eq!(shape.padding(), 0);
+
+            let text = shape.to_string();
+            assert!(text.starts_with("struct shape {\n"));
+            assert!(text.contains("/* XXX 3 bytes hole, try to pack */"));
+            assert!(text.contains("/* size: 64, cachelines: 1, members: 8 */"));
+            assert!(text.ends_with("};"));
+
+            let value = layouts
+                .iter()
+                .find(|layout| layout.name() == Some("value"))
+                .unwrap();
+            assert_eq!(value.kind(), "union");
+            assert_eq!(value.members()[2].type_name(), "const char *");
+            assert_eq!(value.holes(), (0, 0));
+        }
+    }
+
+    #[test]
+    fn layout_of_objects() {
+        let layouts = |file| {
+            let elf = Elf::parse(file).unwrap();
+            let dwarf = Dwarf::new(&elf).unwrap();
+            let tree = dwarf.units().next().unwrap().unwrap().tree().unwrap();
+
+            Layout::of_unit(&tree)
+                .iter()
+                .map(|layout| layout.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Strings only resolve once the relocations of .rela.debug_info are applied
+        assert_eq!(
+            layouts(&include_bytes!("../../test/debug.o")[..]),
+            layouts(&include_bytes!("../../test/debug5")[..])
+        );
+
+        // i386 keeps the addends in the relocated fields of .rel.debug_info
+        let layouts = layouts(&include_bytes!("../../test/debug32.o")[..]);
+        let shape = layouts
+            .iter()
+            .find(|text| text.starts_with("struct shape {"))
+            .unwrap();
+        assert!(shape.contains("\tchar           kind;       /*        0     1 */\n"));
+        assert!(shape.contains("\tstruct shape * next;       /*       56     4 */\n"));
+        assert!(shape.contains("/* size: 60, cachelines: 1, members: 8 */"));
+    }
+
+    #[test]
+    fn layout_of_oversized_members() {
+        #[rustfmt::skip]
+        let abbrev = [
+            // compile_unit with children
+            0x01, 0x11, 0x01, 0x00, 0x00,
+            // structure_type with children, name string and byte_size data8
+            0x02, 0x13, 0x01, 0x03, 0x08, 0x0B, 0x07, 0x00, 0x00,
+            // member, name string, byte_size data8, bit_size data1 and data_bit_offset data8
+            0x03, 0x0D, 0x00, 0x03, 0x08, 0x0B, 0x07, 0x0D, 0x0B, 0x6B, 0x07, 0x00, 0x00,
+            0x00,
+        ];
+
+        // A DWARF 4 unit: `s` with a bitfield in 4 bytes and one in 2^61 bytes, whose size in
+        // bits does not fit in a u64, then `t` of 2^62 bytes
+        let mut info = vec![0; 4];
+        info.extend(4u16.to_le_bytes());
+        info.extend(0u32.to_le_bytes());
+        info.extend([0x08, 0x01, 0x02, b's', 0x00]);
+        info.extend(8u64.to_le_bytes());
+        info.extend([0x03, b'a', 0x00]);
+        info.extend(4u64.to_le_bytes());
+        info.push(0x01);
+        info.extend(0u64.to_le_bytes());
+        info.extend([0x03, b'b', 0x00]);
+        info.extend((1u64 << 61).to_le_bytes());
+        info.push(0x01);
+        info.extend(0x20u64.to_le_bytes());
+        info.extend([0x00, 0x02, b't', 0x00]);
+        info.extend((1u64 << 62).to_le_bytes());
+        info.extend([0x00, 0x00]);
+        let length = info.len() as u32 - 4;
+        info[..4].copy_from_slice(&length.to_le_bytes());
+
+        let dwarf = Dwarf {
+            reader: Reader::new(Class::Elf64, Endian::Little),
+            debug_abbrev: Cow::Borrowed(&abbrev),
+            debug_addr: Cow::Borrowed(&[]),
+            debug_info: Cow::Owned(info),
+            debug_line: Cow::Borrowed(&[]),
+            debug_line_str: Cow::Borrowed(&[]),
+            debug_str: Cow::Borrowed(&[]),
+            debug_str_offsets: Cow::Borrowed(&[]),
+        };
+        let tree = dwarf.units().next().unwrap().unwrap().tree().unwrap();
+        let layouts = Layout::of_unit(&tree);
+
+        // `b` is left out and `t` has no layout
+        assert_eq!(layouts.len(), 1);
+        assert_eq!(layouts[0].name(), Some("s"));
+        assert_eq!(layouts[0].members().len(), 1);
+        assert_eq!(layouts[0].members()[0].name(), Some("a"));
+        assert_eq!(layouts[0].members()[0].bits(), Some((0, 1)));
+    }
+}
@@ -0,0 +1,664 @@
+//! `.debug_line`, the tables mapping addresses to the source lines they were compiled from.
+
+use crate::dwarf::common::{lnct, lne, lns};
+use crate::dwarf::{Cursor, Dwarf, Encoding, Error, Value};
+use std::fmt::Display;
+
+/// An entry of the file name table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileEntry<'d> {
+    name: Option<&'d str>,
+    directory: u64,
+    timestamp: u64,
+    size: u64,
+    md5: Option<&'d [u8]>,
+}
+
+impl<'d> FileEntry<'d> {
+    pub fn name(&self) -> Option<&'d str> {
+        self.name
+    }
+
+    /// Index of its directory in [`LineProgram::directories`], see [`LineProgram::directory`].
+    pub fn directory(&self) -> u64 {
+        self.directory
+    }
+
+    /// Modification time of the file, 0 when unknown.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Size of the file in bytes, 0 when unknown.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// `DW_LNCT_MD5` of DWARF 5 tables.
+    pub fn md5(&self) -> Option<&'d [u8]> {
+        self.md5
+    }
+}
+
+/// Registers of the line number state machine, a row of the line table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub address: u64,
+    /// Index of the operation in a VLIW instruction, always 0 elsewhere.
+    pub op_index: u64,
+    /// Index into the file name table, see [`LineProgram::file`].
+    pub file: u64,
+    /// Source line, from 1, 0 for instructions without one.
+    pub line: u64,
+    /// Column of the line, from 1, 0 for unknown.
+    pub column: u64,
+    pub is_stmt: bool,
+    pub basic_block: bool,
+    /// First address past the end of a sequence of instructions, not an instruction itself.
+    pub end_sequence: bool,
+    pub prologue_end: bool,
+    pub epilogue_begin: bool,
+    pub isa: u64,
+    pub discriminator: u64,
+}
+
+impl Row {
+    fn new(default_is_stmt: bool) -> Self {
+        Self {
+            address: 0,
+            op_index: 0,
+            file: 1,
+            line: 1,
+            column: 0,
+            is_stmt: default_is_stmt,
+            basic_block: false,
+            end_sequence: false,
+            prologue_end: false,
+            epilogue_begin: false,
+            isa: 0,
+            discriminator: 0,
+        }
+    }
+}
+
+/// The line number program of a compilation unit, its header and opcodes.
+#[derive(Debug, Clone)]
+pub struct LineProgram<'d> {
+    offset: usize,
+    encoding: Encoding,
+    minimum_instruction_length: u8,
+    maximum_operations_per_instruction: u8,
+    default_is_stmt: bool,
+    line_base: i8,
+    line_range: u8,
+    opcode_base: u8,
+    standard_opcode_lengths: &'d [u8],
+    directories: Vec<Option<&'d str>>,
+    files: Vec<FileEntry<'d>>,
+    program: Cursor<'d>,
+}
+
+impl<'d> LineProgram<'d> {
+    fn new(dwarf: &'d Dwarf, cursor: &mut Cursor<'d>) -> Result<Self, Error> {
+        let offset = cursor.offset();
+        let malformed = |err: &str| Error::Line {
+            offset,
+            err: err.into(),
+        };
+
+        let (length, offset_size) = cursor.initial_length()?;
+        let mut unit = cursor.split(length)?;
+
+        let version = unit.uint(2)? as u16;
+        if !(2..=5).contains(&version) {
+            return Err(malformed(&format!("unsupported version {version}")));
+        }
+
+        // Before DWARF 5 the size of addresses is only known from `DW_LNE_set_address`
+        let address_size = match version {
+            5 => {
+                let address_size = unit.uint(1)? as u8;
+                let _segment_selector_size = unit.uint(1)?;
+                address_size
+            }
+            _ => dwarf.reader().wordsize() as u8,
+        };
+
+        let encoding = Encoding {
+            version,
+            address_size,
+            offset_size,
+        };
+
+        let header_length = unit.uint(offset_size)?;
+        let mut header = unit.split(header_length)?;
+
+        let minimum_instruction_length = header.uint(1)? as u8;
+        let maximum_operations_per_instruction = match version {
+            4.. => header.uint(1)? as u8,
+            _ => 1,
+        };
+        let default_is_stmt = header.uint(1)? != 0;
+        let line_base = header.uint(1)? as i8;
+        let line_range = header.uint(1)? as u8;
+        let opcode_base = header.uint(1)? as u8;
+
+        if line_range == 0 {
+            return Err(malformed("line range of 0"));
+        }
+
+        let standard_opcode_lengths = header.bytes(opcode_base.saturating_sub(1).into())?;
+
+        let (directories, files) = match version {
+            5 => {
+                let directories = entries(dwarf, &mut header, encoding)?
+                    .into_iter()
+                    .map(|entry| entry.name)
+                    .collect();
+
+                (directories, entries(dwarf, &mut header, encoding)?)
+            }
+            _ => {
+                let mut directories = Vec::new();
+                loop {
+                    match header.str()? {
+                        Some("") => break,
+                        directory => directories.push(directory),
+                    }
+                }
+
+                let mut files = Vec::new();
+                loop {
+                    let name = header.str()?;
+                    if name == Some("") {
+                        break;
+                    }
+
+                    files.push(FileEntry {
+                        name,
+                        directory: header.uleb()?,
+                        timestamp: header.uleb()?,
+                        size: header.uleb()?,
+                        md5: None,
+                    });
+                }
+
+                (directories, files)
+            }
+        };
+
+        Ok(Self {
+            offset,
+            encoding,
+            minimum_instruction_length,
+            maximum_operations_per_instruction,
+            default_is_stmt,
+            line_base,
+            line_range,
+            opcode_base,
+            standard_opcode_lengths,
+            directories,
+            files,
+            program: unit,
+        })
+    }
+
+    /// Offset of the program in `.debug_line`, as `DW_AT_stmt_list` refers to it.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn version(&self) -> u16 {
+        self.encoding.version
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn minimum_instruction_length(&self) -> u8 {
+        self.minimum_instruction_length
+    }
+
+    pub fn maximum_operations_per_instruction(&self) -> u8 {
+        self.maximum_operations_per_instruction
+    }
+
+    pub fn default_is_stmt(&self) -> bool {
+        self.default_is_stmt
+    }
+
+    pub fn line_base(&self) -> i8 {
+        self.line_base
+    }
+
+    pub fn line_range(&self) -> u8 {
+        self.line_range
+    }
+
+    pub fn opcode_base(&self) -> u8 {
+        self.opcode_base
+    }
+
+    /// Include directories. From DWARF 5 the first one is the compilation directory, before it
+    /// is implicit and directory index 0 refers to it.
+    pub fn directories(&self) -> &[Option<&'d str>] {
+        &self.directories
+    }
+
+    /// File names. From DWARF 5 the first one is the primary source file and file index 0
+    /// refers to it, before indexes start at 1.
+    pub fn files(&self) -> &[FileEntry<'d>] {
+        &self.files
+    }
+
+    /// Entry of the file index `index`, e.g. of [`Row::file`].
+    pub fn file(&self, index: u64) -> Option<&FileEntry<'d>> {
+        let index = match self.encoding.version {
+            5 => index,
+            _ => index.checked_sub(1)?,
+        };
+
+        self.files.get(usize::try_from(index).ok()?)
+    }
+
+    /// Directory of the directory index `index`, e.g. of [`FileEntry::directory`]. `None` for
+    /// the compilation directory before DWARF 5, it is only in `.debug_info`.
+    pub fn directory(&self, index: u64) -> Option<&'d str> {
+        let index = match self.encoding.version {
+            5 => index,
+            _ => index.checked_sub(1)?,
+        };
+
+        self.directories
+            .get(usize::try_from(index).ok()?)
+            .copied()
+            .flatten()
+    }
+
+    /// Path of the file index `index`, joined with its directory. Relative before DWARF 5 for
+    /// the files of the compilation directory.
+    pub fn path(&self, index: u64) -> Option<String> {
+        let file = self.file(index)?;
+        let name = file.name?;
+
+        // Directories of DWARF 5 tables are relative to the compilation directory
+        let directory = match (self.directory(file.directory), self.encoding.version) {
+            (Some(directory), 5) if !directory.starts_with('/') && file.directory != 0 => {
+                self.directory(0).map(|base| join(base, directory))
+            }
+            (directory, _) => directory.map(Into::into),
+        };
+
+        match directory {
+            Some(directory) if !name.starts_with('/') => Some(join(&directory, name)),
+            _ => Some(name.into()),
+        }
+    }
+
+    /// Runs the program, the rows of the table in the order they are made.
+    pub fn rows(&self) -> LineRows<'d, '_> {
+        LineRows {
+            program: self,
+            cursor: self.program.clone(),
+            row: Row::new(self.default_is_stmt),
+        }
+    }
+}
+
+fn join(directory: &str, name: &str) -> String {
+    format!("{}/{}", directory.trim_end_matches('/'), name)
+}
+
+/// Directory or file name entries of a DWARF 5 header, described by their entry format.
+fn entries<'d>(
+    dwarf: &'d Dwarf,
+    header: &mut Cursor<'d>,
+    encoding: Encoding,
+) -> Result<Vec<FileEntry<'d>>, Error> {
+    let count = header.uint(1)?;
+    let mut format = Vec::new();
+    for _ in 0..count {
+        format.push((header.uleb()?, header.uleb()?));
+    }
+
+    let count = header.uleb()?;
+    let mut entries = Vec::new();
+
+    for _ in 0..count {
+        let mut entry = FileEntry {
+            name: None,
+            directory: 0,
+            timestamp: 0,
+            size: 0,
+            md5: None,
+        };
+
+        for &(content, form) in &format {
+            let value = dwarf.value(header, form, encoding)?;
+
+            match (content, value) {
+                (lnct::PATH, value) => entry.name = value.string(),
+                (lnct::DIRECTORY_INDEX, value) => entry.directory = value.unsigned().unwrap_or(0),
+                (lnct::TIMESTAMP, Value::Block(_)) => {}
+                (lnct::TIMESTAMP, value) => entry.timestamp = value.unsigned().unwrap_or(0),
+                (lnct::SIZE, value) => entry.size = value.unsigned().unwrap_or(0),
+                (lnct::MD5, Value::Block(md5)) => entry.md5 = Some(md5),
+                // Vendor defined content, e.g. `DW_LNCT_LLVM_source`
+                _ => {}
+            }
+        }
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// Rows of a line table, made by running its program. Stops after the first error.
+#[derive(Debug, Clone)]
+pub struct LineRows<'d, 'p> {
+    program: &'p LineProgram<'d>,
+    cursor: Cursor<'d>,
+    row: Row,
+}
+
+impl LineRows<'_, '_> {
+    /// Advances the address and op_index by `advance` operations.
+    fn advance(&mut self, advance: u64) {
+        let program = self.program;
+        let maximum = program.maximum_operations_per_instruction.max(1) as u64;
+        let operations = self.row.op_index.wrapping_add(advance);
+
+        self.row.address = self.row.address.wrapping_add(
+            (program.minimum_instruction_length as u64).wrapping_mul(operations / maximum),
+        );
+        self.row.op_index = operations % maximum;
+    }
+
+    /// The row to emit, resetting the registers that only apply to one row.
+    fn emit(&mut self) -> Row {
+        let row = self.row;
+
+        self.row.basic_block = false;
+        self.row.prologue_end = false;
+        self.row.epilogue_begin = false;
+        self.row.discriminator = 0;
+
+        row
+    }
+
+    fn step(&mut self) -> Result<Option<Row>, Error> {
+        let program = self.program;
+        let opcode = self.cursor.uint(1)? as u8;
+
+        // Special opcodes advance both the address and the line in one byte
+        if opcode >= program.opcode_base {
+            let adjusted = opcode - program.opcode_base;
+            self.advance((adjusted / program.line_range).into());
+            self.row.line = self.row.line.wrapping_add_signed(
+                program.line_base as i64 + (adjusted % program.line_range) as i64,
+            );
+
+            return Ok(Some(self.emit()));
+        }
+
+        match opcode {
+            0 => {
+                let length = self.cursor.uleb()?;
+                let mut operands = self.cursor.split(length)?;
+
+                match operands.uint(1)? as u8 {
+                    lne::END_SEQUENCE => {
+                        self.row.end_sequence = true;
+                        let row = self.emit();
+                        self.row = Row::new(program.default_is_stmt);
+
+                        return Ok(Some(row));
+                    }
+                    lne::SET_ADDRESS => {
+                        self.row.address = operands.uint(length.saturating_sub(1) as u8)?;
+                        self.row.op_index = 0;
+                    }
+                    lne::SET_DISCRIMINATOR => self.row.discriminator = operands.uleb()?,
+                    // `DW_LNE_define_file` is gone from DWARF 5 and no producer uses it, its
+                    // files are not added to the table
+                    _ => {}
+                }
+            }
+            lns::COPY => return Ok(Some(self.emit())),
+            lns::ADVANCE_PC => {
+                let advance = self.cursor.uleb()?;
+                self.advance(advance);
+            }
+            lns::ADVANCE_LINE => {
+                let advance = self.cursor.sleb()?;
+                self.row.line = self.row.line.wrapping_add_signed(advance);
+            }
+            lns::SET_FILE => self.row.file = self.cursor.uleb()?,
+            lns::SET_COLUMN => self.row.column = self.cursor.uleb()?,
+            lns::NEGATE_STMT => self.row.is_stmt = !self.row.is_stmt,
+            lns::SET_BASIC_BLOCK => self.row.basic_block = true,
+            lns::CONST_ADD_PC => {
+                let adjusted = 255 - program.opcode_base;
+                self.advance((adjusted / program.line_range).into());
+            }
+            lns::FIXED_ADVANCE_PC => {
+                let advance = self.cursor.uint(2)?;
+                self.row.address = self.row.address.wrapping_add(advance);
+                self.row.op_index = 0;
+            }
+            lns::SET_PROLOGUE_END => self.row.prologue_end = true,
+            lns::SET_EPILOGUE_BEGIN => self.row.epilogue_begin = true,
+            lns::SET_ISA => self.row.isa = self.cursor.uleb()?,
+            // Opcodes of later versions, skipped by their number of operands
+            _ => {
+                let operands = program.standard_opcode_lengths[opcode as usize - 1];
+                for _ in 0..operands {
+                    self.cursor.uleb()?;
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Iterator for LineRows<'_, '_> {
+    type Item = Result<Row, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.cursor.is_empty() {
+            match self.step() {
+                Ok(Some(row)) => return Some(Ok(row)),
+                Ok(None) => continue,
+                Err(err) => {
+                    self.cursor.skip_all();
+                    return Some(Err(err));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Line number programs of `.debug_line`, in the order of the section. Stops after the first
+/// error, the units after a malformed one cannot be found.
+#[derive(Debug, Clone)]
+pub struct LinePrograms<'d> {
+    dwarf: &'d Dwarf<'d>,
+    cursor: Cursor<'d>,
+}
+
+impl<'d> LinePrograms<'d> {
+    pub(crate) fn new(dwarf: &'d Dwarf<'d>, cursor: Cursor<'d>) -> Self {
+        Self { dwarf, cursor }
+    }
+}
+
+impl<'d> Iterator for LinePrograms<'d> {
+    type Item = Result<LineProgram<'d>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor.is_empty() {
+            return None;
+        }
+
+        let program = LineProgram::new(self.dwarf, &mut self.cursor);
+        if program.is_err() {
+            self.cursor.skip_all();
+        }
+
+        Some(program)
+    }
+}
+
+/// Source location of an address, the row of a line table covering it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'d, 'i> {
+    program: &'i LineProgram<'d>,
+    row: Row,
+}
+
+/// `path:line:column`, as `addr2line`, without the column when it is unknown.
+impl Display for Line<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = self.path();
+        write!(f, "{}:{}", path.as_deref().unwrap_or("??"), self.row.line)?;
+
+        match self.row.column {
+            0 => Ok(()),
+            column => write!(f, ":{}", column),
+        }
+    }
+}
+
+impl<'d, 'i> Line<'d, 'i> {
+    pub fn program(&self) -> &'i LineProgram<'d> {
+        self.program
+    }
+
+    pub fn row(&self) -> Row {
+        self.row
+    }
+
+    pub fn path(&self) -> Option<String> {
+        self.program.path(self.row.file)
+    }
+}
+
+/// The rows of every line table sorted by address, to look up many addresses.
+#[derive(Debug, Clone)]
+pub struct LineIndex<'d> {
+    programs: Vec<LineProgram<'d>>,
+    /// Index of the program and row, sorted by address with the end of a sequence before the
+    /// start of the next one at the same address.
+    rows: Vec<(usize, Row)>,
+}
+
+impl<'d> LineIndex<'d> {
+    pub fn new(dwarf: &'d Dwarf<'d>) -> Result<Self, Error> {
+        let programs = dwarf.line_programs().collect::<Result<Vec<_>, _>>()?;
+
+        let mut rows = Vec::new();
+        for (index, program) in programs.iter().enumerate() {
+            for row in program.rows() {
+                rows.push((index, row?));
+            }
+        }
+
+        // Stable, so the rows of an address stay in the order of their program
+        rows.sort_by_key(|(_, row)| (row.address, !row.end_sequence));
+
+        Ok(Self { programs, rows })
+    }
+
+    pub fn programs(&self) -> &[LineProgram<'d>] {
+        &self.programs
+    }
+
+    /// Source location of the instruction at `address`, the last row at or before it.
+    pub fn lookup(&self, address: u64) -> Option<Line<'d, '_>> {
+        let index = self.rows.partition_point(|(_, row)| row.address <= address);
+        let &(program, row) = self.rows[..index].last()?;
+
+        // Past the end of the sequence, in between functions
+        if row.end_sequence {
+            return None;
+        }
+
+        Some(Line {
+            program: &self.programs[program],
+            row,
+        })
+    }
+
+    /// Statements of line `line` of the file at `path`, or any ending with `/path`, in the
+    /// order of their address.
+    pub fn addresses(&self, path: &str, line: u64) -> Vec<Line<'d, '_>> {
+        let matches = |full: &str| {
+            full == path
+                || full
+                    .strip_suffix(path)
+                    .is_some_and(|directory| directory.ends_with('/'))
+        };
+
+        self.rows
+            .iter()
+            .filter(|(_, row)| row.line == line && row.is_stmt && !row.end_sequence)
+            .map(|&(program, row)| Line {
+                program: &self.programs[program],
+                row,
+            })
+            .filter(|line| line.path().is_some_and(|full| matches(&full)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LineIndex;
+    use crate::dwarf::Dwarf;
+    use crate::elf::Elf;
+
+    #[test]
+    fn lines_of_each_version() {
+        for (file, version) in [
+            (&include_bytes!("../../test/debug2")[..], 2),
+            (include_bytes!("../../test/debug4"), 4),
+            (include_bytes!("../../test/debug5"), 5),
+        ] {
+            let elf = Elf::parse(file).unwrap();
+            let dwarf = Dwarf::new(&elf).unwrap();
+            let index = LineIndex::new(&dwarf).unwrap();
+
+            let program = &index.programs()[0];
+            assert_eq!(program.version(), version);
+
+            let symbols = elf.address_index().unwrap();
+            let main = elf
+                .symbols()
+                .unwrap()
+                .unwrap()
+                .find(|symbol| symbol.name() == Some("main"))
+                .unwrap();
+            assert_eq!(symbols.lookup(main.value()).to_string(), "main+0x0");
+
+            let line = index.lookup(main.value()).unwrap();
+            assert!(line.path().unwrap().ends_with("src/debug.c"), "{version}");
+            assert_eq!(line.row().line, 9);
+
+            // Relative to the compilation directory before DWARF 5
+            let area = index.addresses("debug.h", 27);
+            assert_eq!(area.len(), 1);
+            assert!(area[0].path().unwrap().ends_with("src/debug.h"));
+            assert_eq!(
+                index.lookup(area[0].row().address).unwrap().row(),
+                area[0].row()
+            );
+
+            assert!(index.lookup(0).is_none());
+        }
+    }
+}
@@ -0,0 +1,650 @@
+//! DWARF debugging information, read from the `.debug_*` sections of an ELF file.
+
+pub mod common;
+pub mod info;
+pub mod layout;
+pub mod line;
+
+use crate::dwarf::common::form;
+use crate::dwarf::info::Units;
+use crate::dwarf::line::LinePrograms;
+use crate::elf::common::{e, sh};
+use crate::elf::reader::{Endian, Reader};
+use crate::elf::reloc::{Fixup, Relocations};
+use crate::elf::section::StringTable;
+use crate::elf::{self, Elf};
+use std::borrow::Cow;
+use std::error;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum Error {
+    Elf(elf::Error),
+    Truncated {
+        section: &'static str,
+        offset: usize,
+    },
+    Form {
+        section: &'static str,
+        offset: usize,
+        form: u64,
+    },
+    Line {
+        offset: usize,
+        err: String,
+    },
+    Info {
+        offset: usize,
+        err: String,
+    },
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Elf(err) => write!(f, "{}", err),
+            Self::Truncated { section, offset } => {
+                write!(f, "truncated {} at {:#x}", section, offset)
+            }
+            Self::Form {
+                section,
+                offset,
+                form,
+            } => {
+                write!(
+                    f,
+                    "unknown form {:#x} in {} at {:#x}",
+                    form, section, offset
+                )
+            }
+            Self::Line { offset, err } => {
+                write!(f, "malformed line table at {:#x}: {}", offset, err)
+            }
+            Self::Info { offset, err } => {
+                write!(f, "malformed unit at {:#x}: {}", offset, err)
+            }
+        }
+    }
+}
+
+impl From<elf::Error> for Error {
+    fn from(err: elf::Error) -> Self {
+        Self::Elf(err)
+    }
+}
+
+/// Sizes the fields of a unit are read with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encoding {
+    pub version: u16,
+    pub address_size: u8,
+    /// 4 for the 32-bit DWARF format and 8 for the 64-bit one, the size of section offsets.
+    pub offset_size: u8,
+}
+
+/// Value of an attribute or of a field of a line table entry, as its form encodes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value<'d> {
+    Address(u64),
+    /// Index into `.debug_addr`, `DW_FORM_addrx*`.
+    AddressIndex(u64),
+    Unsigned(u64),
+    Signed(i64),
+    Flag(bool),
+    /// Blocks, expressions and `DW_FORM_data16`.
+    Block(&'d [u8]),
+    /// Inline or read from `.debug_str` and `.debug_line_str`, `None` when corrupt.
+    String(Option<&'d str>),
+    /// Index into `.debug_str_offsets`, `DW_FORM_strx*`.
+    StringIndex(u64),
+    /// Offset of an entry from the start of its unit.
+    Reference(u64),
+    /// Offset of an entry in `.debug_info`, `DW_FORM_ref_addr`.
+    InfoReference(u64),
+    /// Type signature of a type unit, `DW_FORM_ref_sig8`.
+    Signature(u64),
+    /// Offset into another section, e.g. `.debug_rnglists`.
+    SectionOffset(u64),
+    /// Index into the offsets of `.debug_loclists` or `.debug_rnglists`.
+    ListIndex(u64),
+    /// Offset into the supplementary object file, e.g. from `dwz`.
+    Supplementary(u64),
+}
+
+impl<'d> Value<'d> {
+    /// The value of constants and of the forms holding indexes or sizes.
+    pub fn unsigned(&self) -> Option<u64> {
+        match *self {
+            Self::Unsigned(value) => Some(value),
+            Self::Signed(value) => u64::try_from(value).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self) -> Option<&'d str> {
+        match *self {
+            Self::String(string) => string,
+            _ => None,
+        }
+    }
+}
+
+/// The DWARF sections of a file.
+///
+/// Sections missing from the file are empty, so a file without debugging information just
+/// has no units.
+#[derive(Debug, Clone)]
+pub struct Dwarf<'a> {
+    reader: Reader,
+    debug_abbrev: Cow<'a, [u8]>,
+    debug_addr: Cow<'a, [u8]>,
+    debug_info: Cow<'a, [u8]>,
+    debug_line: Cow<'a, [u8]>,
+    debug_line_str: Cow<'a, [u8]>,
+    debug_str: Cow<'a, [u8]>,
+    debug_str_offsets: Cow<'a, [u8]>,
+}
+
+/// Applies the `SHT_REL` and `SHT_RELA` sections of a relocatable object to its section at
+/// `index`. Until the object is linked the offsets into the other debug sections and the
+/// addresses are left for the linker to fill in, mostly as zeroes.
+fn relocate<'a>(
+    elf: &Elf<'a>,
+    index: usize,
+    mut contents: Cow<'a, [u8]>,
+) -> Result<Cow<'a, [u8]>, Error> {
+    let header = elf.header();
+    let reader = header.reader();
+    let sections = elf.sections();
+
+    for (table, section) in sections.clone().enumerate() {
+        if !matches!(section.r#type(), sh::r#type::REL | sh::r#type::RELA)
+            || section.info() as usize != index
+        {
+            continue;
+        }
+
+        let bytes = contents.to_mut();
+
+        for relocation in Relocations::new(elf.data(), &header, &sections, table)? {
+            // Kinds binutils does not apply, and broken entries, leave their field as it is
+            let Some(fixup) = relocation.fixup() else {
+                continue;
+            };
+            let size = fixup.size();
+            if size == 0 {
+                continue;
+            }
+
+            let Some(field) = usize::try_from(relocation.offset())
+                .ok()
+                .and_then(|start| bytes.get_mut(start..start.checked_add(size)?))
+            else {
+                continue;
+            };
+
+            let symbol = match (relocation.sym(), relocation.symbol()) {
+                (0, _) => 0,
+                (_, Some(symbol)) => symbol.value(),
+                (_, None) => continue,
+            };
+
+            let current = match size {
+                1 => field[0] as u64,
+                2 => reader.u16(field) as u64,
+                4 => reader.u32(field) as u64,
+                _ => reader.u64(field),
+            };
+            // `SHT_REL` entries keep their addend in the field they relocate
+            let addend = match (relocation.addend(), fixup) {
+                (Some(addend), _) => addend as u64,
+                (None, Fixup::Write(_)) => current,
+                (None, _) => 0,
+            };
+
+            let value = fixup.apply(current, symbol.wrapping_add(addend));
+            match reader.endian() {
+                Endian::Little => field.copy_from_slice(&value.to_le_bytes()[..size]),
+                Endian::Big => field.copy_from_slice(&value.to_be_bytes()[8 - size..]),
+            }
+        }
+    }
+
+    Ok(contents)
+}
+
+impl<'a> Dwarf<'a> {
+    pub fn new(elf: &Elf<'a>) -> Result<Self, Error> {
+        let sections = elf.sections();
+        // Compressed sections are read decompressed, the legacy ones are named `.zdebug_*`
+        let section = |name: &'static str| -> Result<Cow<'a, [u8]>, Error> {
+            let zdebug = name.replacen(".debug", ".zdebug", 1);
+            let find = |name: &str| {
+                sections
+                    .clone()
+                    .enumerate()
+                    .find(|(_, section)| section.name() == Some(name))
+            };
+
+            let Some((index, section)) = find(name).or_else(|| find(&zdebug)) else {
+                return Ok(Cow::Borrowed(&[]));
+            };
+            let contents = section.contents(elf.data())?;
+
+            match elf.header().r#type() {
+                e::r#type::REL => relocate(elf, index, contents),
+                _ => Ok(contents),
+            }
+        };
+
+        Ok(Self {
+            reader: elf.header().reader(),
+            debug_abbrev: section(".debug_abbrev")?,
+            debug_addr: section(".debug_addr")?,
+            debug_info: section(".debug_info")?,
+            debug_line: section(".debug_line")?,
+            debug_line_str: section(".debug_line_str")?,
+            debug_str: section(".debug_str")?,
+            debug_str_offsets: section(".debug_str_offsets")?,
+        })
+    }
+
+    pub fn reader(&self) -> Reader {
+        self.reader
+    }
+
+    /// Bytes of the DWARF section `name`, empty for the ones not read.
+    pub fn section(&self, name: &str) -> &[u8] {
+        match name {
+            ".debug_abbrev" => &self.debug_abbrev,
+            ".debug_addr" => &self.debug_addr,
+            ".debug_info" => &self.debug_info,
+            ".debug_line" => &self.debug_line,
+            ".debug_line_str" => &self.debug_line_str,
+            ".debug_str" => &self.debug_str,
+            ".debug_str_offsets" => &self.debug_str_offsets,
+            _ => &[],
+        }
+    }
+
+    /// Units of `.debug_info`, compilation units and the type units of DWARF 5.
+    pub fn units(&self) -> Units<'_> {
+        Units::new(
+            self,
+            Cursor::new(".debug_info", &self.debug_info, self.reader),
+        )
+    }
+
+    /// String at `offset` of `.debug_str`.
+    pub(crate) fn string(&self, offset: u64) -> Option<&str> {
+        string(&self.debug_str, offset)
+    }
+
+    /// Line number programs of `.debug_line`, one per compilation unit.
+    pub fn line_programs(&self) -> LinePrograms<'_> {
+        LinePrograms::new(
+            self,
+            Cursor::new(".debug_line", &self.debug_line, self.reader),
+        )
+    }
+
+    /// Reads a value encoded as `form` at `cursor`, resolving the offsets into the string
+    /// sections. `DW_FORM_implicit_const` has no data, its value is in the abbreviation.
+    pub(crate) fn value<'d>(
+        &'d self,
+        cursor: &mut Cursor<'d>,
+        form: u64,
+        encoding: Encoding,
+    ) -> Result<Value<'d>, Error> {
+        let offset = cursor.offset();
+
+        // The form follows in the data, once: a chain of them only nests without end
+        let form = match form {
+            form::INDIRECT => match cursor.uleb()? {
+                form::INDIRECT => {
+                    return Err(Error::Form {
+                        section: cursor.section,
+                        offset,
+                        form,
+                    })
+                }
+                form => form,
+            },
+            form => form,
+        };
+
+        let value = match form {
+            form::ADDR => Value::Address(cursor.uint(encoding.address_size)?),
+            form::ADDRX | form::GNU_ADDR_INDEX => Value::AddressIndex(cursor.uleb()?),
+            form::ADDRX1 => Value::AddressIndex(cursor.uint(1)?),
+            form::ADDRX2 => Value::AddressIndex(cursor.uint(2)?),
+            form::ADDRX3 => Value::AddressIndex(cursor.uint(3)?),
+            form::ADDRX4 => Value::AddressIndex(cursor.uint(4)?),
+            form::DATA1 => Value::Unsigned(cursor.uint(1)?),
+            form::DATA2 => Value::Unsigned(cursor.uint(2)?),
+            form::DATA4 => Value::Unsigned(cursor.uint(4)?),
+            form::DATA8 => Value::Unsigned(cursor.uint(8)?),
+            form::UDATA => Value::Unsigned(cursor.uleb()?),
+            form::SDATA => Value::Signed(cursor.sleb()?),
+            form::DATA16 => Value::Block(cursor.bytes(16)?),
+            form::FLAG => Value::Flag(cursor.uint(1)? != 0),
+            form::FLAG_PRESENT => Value::Flag(true),
+            form::BLOCK1 => {
+                let size = cursor.uint(1)?;
+                Value::Block(cursor.bytes(size)?)
+            }
+            form::BLOCK2 => {
+                let size = cursor.uint(2)?;
+                Value::Block(cursor.bytes(size)?)
+            }
+            form::BLOCK4 => {
+                let size = cursor.uint(4)?;
+                Value::Block(cursor.bytes(size)?)
+            }
+            form::BLOCK | form::EXPRLOC => {
+                let size = cursor.uleb()?;
+                Value::Block(cursor.bytes(size)?)
+            }
+            form::STRING => Value::String(cursor.str()?),
+            form::STRP => {
+                let offset = cursor.uint(encoding.offset_size)?;
+                Value::String(self.string(offset))
+            }
+            form::LINE_STRP => {
+                let offset = cursor.uint(encoding.offset_size)?;
+                Value::String(string(&self.debug_line_str, offset))
+            }
+            form::STRX | form::GNU_STR_INDEX => Value::StringIndex(cursor.uleb()?),
+            form::STRX1 => Value::StringIndex(cursor.uint(1)?),
+            form::STRX2 => Value::StringIndex(cursor.uint(2)?),
+            form::STRX3 => Value::StringIndex(cursor.uint(3)?),
+            form::STRX4 => Value::StringIndex(cursor.uint(4)?),
+            form::REF1 => Value::Reference(cursor.uint(1)?),
+            form::REF2 => Value::Reference(cursor.uint(2)?),
+            form::REF4 => Value::Reference(cursor.uint(4)?),
+            form::REF8 => Value::Reference(cursor.uint(8)?),
+            form::REF_UDATA => Value::Reference(cursor.uleb()?),
+            // An address sized offset before DWARF 3
+            form::REF_ADDR => Value::InfoReference(match encoding.version {
+                ..=2 => cursor.uint(encoding.address_size)?,
+                _ => cursor.uint(encoding.offset_size)?,
+            }),
+            form::REF_SIG8 => Value::Signature(cursor.uint(8)?),
+            form::SEC_OFFSET => Value::SectionOffset(cursor.uint(encoding.offset_size)?),
+            form::LOCLISTX | form::RNGLISTX => Value::ListIndex(cursor.uleb()?),
+            form::REF_SUP4 => Value::Supplementary(cursor.uint(4)?),
+            form::REF_SUP8 => Value::Supplementary(cursor.uint(8)?),
+            form::STRP_SUP | form::GNU_REF_ALT | form::GNU_STRP_ALT => {
+                Value::Supplementary(cursor.uint(encoding.offset_size)?)
+            }
+            _ => {
+                return Err(Error::Form {
+                    section: cursor.section,
+                    offset,
+                    form,
+                })
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+/// String at `offset` of a string section.
+fn string(section: &[u8], offset: u64) -> Option<&str> {
+    StringTable(section).get(usize::try_from(offset).ok()?)
+}
+
+/// Reads the fields of a section in order, erroring out with the offset of the first one
+/// past its end.
+#[derive(Debug, Clone)]
+pub(crate) struct Cursor<'d> {
+    section: &'static str,
+    bytes: &'d [u8],
+    /// Offset of `bytes` in the section.
+    start: usize,
+    position: usize,
+    reader: Reader,
+}
+
+impl<'d> Cursor<'d> {
+    pub(crate) fn new(section: &'static str, bytes: &'d [u8], reader: Reader) -> Self {
+        Self {
+            section,
+            bytes,
+            start: 0,
+            position: 0,
+            reader,
+        }
+    }
+
+    /// Offset of the next field in the section.
+    pub(crate) fn offset(&self) -> usize {
+        self.start + self.position
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    /// Skips the rest of the bytes, e.g. after an error leaves the cursor in the middle of a
+    /// field.
+    pub(crate) fn skip_all(&mut self) {
+        self.position = self.bytes.len();
+    }
+
+    pub(crate) fn bytes(&mut self, size: u64) -> Result<&'d [u8], Error> {
+        let bytes = usize::try_from(size)
+            .ok()
+            .and_then(|size| self.position.checked_add(size))
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or(Error::Truncated {
+                section: self.section,
+                offset: self.offset(),
+            })?;
+
+        self.position += bytes.len();
+        Ok(bytes)
+    }
+
+    /// The next `size` bytes as a cursor of their own, e.g. a unit of the section.
+    pub(crate) fn split(&mut self, size: u64) -> Result<Cursor<'d>, Error> {
+        let start = self.offset();
+
+        Ok(Self {
+            start,
+            position: 0,
+            bytes: self.bytes(size)?,
+            ..*self
+        })
+    }
+
+    /// An unsigned integer of 1, 2, 3, 4 or 8 bytes.
+    pub(crate) fn uint(&mut self, size: u8) -> Result<u64, Error> {
+        let offset = self.offset();
+        let bytes = self.bytes(size.into())?;
+
+        Ok(match size {
+            1 => bytes[0].into(),
+            2 => self.reader.u16(bytes).into(),
+            4 => self.reader.u32(bytes).into(),
+            8 => self.reader.u64(bytes),
+            3 => {
+                let bytes = [bytes[0], bytes[1], bytes[2]];
+                let bytes = match self.reader.endian() {
+                    Endian::Little => bytes,
+                    Endian::Big => [bytes[2], bytes[1], bytes[0]],
+                };
+
+                u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]).into()
+            }
+            _ => {
+                return Err(Error::Truncated {
+                    section: self.section,
+                    offset,
+                })
+            }
+        })
+    }
+
+    pub(crate) fn uleb(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.bytes(1)?[0];
+
+            // Bits past the 64th are dropped
+            if shift < 64 {
+                value |= u64::from(byte & 0x7F) << shift;
+            }
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    pub(crate) fn sleb(&mut self) -> Result<i64, Error> {
+        let mut value = 0i64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.bytes(1)?[0];
+
+            if shift < 64 {
+                value |= i64::from(byte & 0x7F) << shift;
+            }
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+
+                return Ok(value);
+            }
+        }
+    }
+
+    /// A NUL terminated string, `None` when it is not UTF-8.
+    pub(crate) fn str(&mut self) -> Result<Option<&'d str>, Error> {
+        let rest = &self.bytes[self.position.min(self.bytes.len())..];
+        let size = rest
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or(Error::Truncated {
+                section: self.section,
+                offset: self.offset(),
+            })?;
+
+        let string = self.bytes(size as u64)?;
+        self.position += 1;
+
+        Ok(std::str::from_utf8(string).ok())
+    }
+
+    /// The length at the start of units, and the offset size of the DWARF format it picks.
+    pub(crate) fn initial_length(&mut self) -> Result<(u64, u8), Error> {
+        match self.uint(4)? {
+            0xFFFFFFFF => Ok((self.uint(8)?, 8)),
+            length => Ok((length, 4)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cursor, Dwarf, Encoding, Value};
+    use crate::dwarf::common::{at, form, tag};
+    use crate::elf::reader::{Class, Endian, Reader};
+    use crate::elf::Elf;
+
+    #[test]
+    fn cursor() {
+        let bytes = [
+            0xE5, 0x8E, 0x26, 0x7F, 0x80, 0x7F, b'm', b'a', b'i', b'n', 0x00, 0x01, 0x02, 0x03,
+        ];
+        let mut cursor = Cursor::new(
+            ".debug_test",
+            &bytes,
+            Reader::new(Class::Elf64, Endian::Big),
+        );
+
+        assert_eq!(cursor.uleb().unwrap(), 624485);
+        assert_eq!(cursor.sleb().unwrap(), -1);
+        assert_eq!(cursor.sleb().unwrap(), -128);
+        assert_eq!(cursor.str().unwrap(), Some("main"));
+        assert_eq!(cursor.uint(3).unwrap(), 0x010203);
+        assert!(cursor.is_empty());
+
+        assert!(cursor.uint(1).is_err());
+        assert_eq!(
+            cursor.uint(1).unwrap_err().to_string(),
+            "truncated .debug_test at 0xe"
+        );
+    }
+
+    #[test]
+    fn indirect_forms() {
+        let elf = Elf::parse(include_bytes!("../../test/debug4")).unwrap();
+        let dwarf = Dwarf::new(&elf).unwrap();
+        let reader = Reader::new(Class::Elf64, Endian::Little);
+        let encoding = Encoding {
+            version: 4,
+            address_size: 8,
+            offset_size: 4,
+        };
+
+        let bytes = [form::DATA1 as u8, 0x2A];
+        let mut cursor = Cursor::new(".debug_info", &bytes, reader);
+        assert_eq!(
+            dwarf.value(&mut cursor, form::INDIRECT, encoding).unwrap(),
+            Value::Unsigned(0x2A)
+        );
+
+        // Rejected rather than followed, however long the chain
+        let bytes = [form::INDIRECT as u8; 0x10000];
+        let mut cursor = Cursor::new(".debug_info", &bytes, reader);
+        assert!(dwarf.value(&mut cursor, form::INDIRECT, encoding).is_err());
+    }
+
+    #[test]
+    fn relocations_of_objects() {
+        let die = |file, name| {
+            let elf = Elf::parse(file).unwrap();
+            let dwarf = Dwarf::new(&elf).unwrap();
+            let tree = dwarf.units().next().unwrap().unwrap().tree().unwrap();
+            let die = tree
+                .dies()
+                .iter()
+                .find(|die| die.tag() != tag::STRUCTURE_TYPE && die.name() == Some(name))
+                .unwrap();
+
+            let location = match die.attr(at::LOCATION) {
+                Some(Value::Block(block)) => block.to_vec(),
+                _ => Vec::new(),
+            };
+            let size = match die.attr(at::HIGH_PC) {
+                Some(Value::Unsigned(size)) => Some(size),
+                _ => None,
+            };
+
+            (location, size)
+        };
+
+        // The variable `counter` follows the 4 bytes of `calls` in .tbss, 8 on x86_64 with the
+        // alignment
+        let (location, _) = die(&include_bytes!("../../test/tls.o")[..], "counter");
+        assert_eq!(location, [0x0E, 8, 0, 0, 0, 0, 0, 0, 0, 0x9B]);
+        let (location, _) = die(&include_bytes!("../../test/tls32.o")[..], "counter");
+        assert_eq!(location, [0x0C, 4, 0, 0, 0, 0x9B]);
+
+        // The size of `hit` is the end of its code minus its start
+        let (_, size) = die(&include_bytes!("../../test/riscv.o")[..], "hit");
+        assert_eq!(size, Some(44));
+    }
+}
@@ -0,0 +1,298 @@
+use crate::elf::common::{p, sh, stb, stt};
+use crate::elf::header::Error;
+use crate::elf::section::SectionHeader;
+use crate::elf::segment::ProgramHeader;
+use crate::elf::symbol::Symbol;
+use crate::elf::Elf;
+use std::fmt::Display;
+
+/// Where an address falls in the file: the `PT_LOAD` segment and section mapping it, and the
+/// nearest symbol at or before it.
+#[derive(Debug, Clone, Copy)]
+pub struct Location<'a> {
+    address: u64,
+    offset: Option<u64>,
+    segment: Option<(usize, ProgramHeader<'a>)>,
+    section: Option<(usize, SectionHeader<'a>)>,
+    symbol: Option<Symbol<'a>>,
+}
+
+/// `foo+0x1c`, the section name and offset in it for addresses without a symbol, the address
+/// alone for unmapped ones.
+impl Display for Location<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.symbol, self.section) {
+            (Some(symbol), _) => write!(
+                f,
+                "{}+{:#x}",
+                symbol.name().unwrap_or("<corrupt>"),
+                self.symbol_offset().unwrap_or(0)
+            ),
+            (None, Some((_, section))) => write!(
+                f,
+                "{}+{:#x}",
+                section.name().unwrap_or("<corrupt>"),
+                self.address - section.addr()
+            ),
+            (None, None) => write!(f, "{:#x}", self.address),
+        }
+    }
+}
+
+impl<'a> Location<'a> {
+    /// The virtual address looked up, or the one the file offset looked up is loaded at.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// File offset of the address, `None` for addresses not backed by the file, e.g. in `.bss`.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Index and header of the `PT_LOAD` segment mapping the address.
+    pub fn segment(&self) -> Option<(usize, ProgramHeader<'a>)> {
+        self.segment
+    }
+
+    /// Index and header of the `SHF_ALLOC` section the address is in.
+    pub fn section(&self) -> Option<(usize, SectionHeader<'a>)> {
+        self.section
+    }
+
+    /// Symbol the address is in, or the nearest one before it for symbols without a size.
+    pub fn symbol(&self) -> Option<Symbol<'a>> {
+        self.symbol
+    }
+
+    /// Offset of the address from the start of [`Location::symbol`].
+    pub fn symbol_offset(&self) -> Option<u64> {
+        self.symbol.map(|symbol| self.address - symbol.value())
+    }
+}
+
+/// The sections and symbols of a file sorted by address, to look up many addresses in it.
+///
+/// Symbols come from `.symtab`, or `.dynsym` for stripped files. Only defined functions, objects
+/// and untyped symbols are indexed, leaving out sections, files, TLS offsets and the mapping
+/// symbols of ARM and RISC-V, e.g. `$x` or `$d`.
+///
+/// In relocatable files every section starts at address 0, so symbols are only matched with
+/// addresses in the section they are defined in. Look up file offsets to tell sections apart.
+#[derive(Debug, Clone)]
+pub struct AddressIndex<'a> {
+    segments: Vec<(usize, ProgramHeader<'a>)>,
+    sections: Vec<(usize, SectionHeader<'a>)>,
+    symbols: Vec<Symbol<'a>>,
+}
+
+impl<'a> AddressIndex<'a> {
+    pub fn new(elf: &Elf<'a>) -> Result<Self, Error> {
+        let segments = elf
+            .segments()
+            .enumerate()
+            .filter(|(_, segment)| segment.r#type() == p::r#type::LOAD)
+            .collect();
+
+        // `.tbss` takes no room in the address space, the sections after it start at its address
+        let mut sections: Vec<_> = elf
+            .sections()
+            .enumerate()
+            .filter(|(_, section)| section.flags() & sh::flags::ALLOC != 0 && section.size() != 0)
+            .filter(|(_, section)| {
+                section.r#type() != sh::r#type::NOBITS || section.flags() & sh::flags::TLS == 0
+            })
+            .collect();
+        sections.sort_by_key(|(_, section)| section.addr());
+
+        let symbols = match elf.symbols()? {
+            Some(symbols) => Some(symbols),
+            None => elf.dynamic_symbols()?,
+        };
+
+        let mut symbols: Vec<_> = symbols
+            .into_iter()
+            .flatten()
+            .filter(|symbol| {
+                matches!(symbol.r#type(), stt::NOTYPE | stt::OBJECT | stt::FUNC)
+                    && symbol.section_index().is_some()
+                    && symbol
+                        .name()
+                        .is_some_and(|name| !name.is_empty() && !name.starts_with('$'))
+            })
+            .collect();
+
+        // Of the symbols at the same address the last one wins, so put the ones with a size,
+        // then the global ones, then the functions last
+        symbols.sort_by_key(|symbol| {
+            (
+                symbol.value(),
+                symbol.size() != 0,
+                symbol.bind() != stb::LOCAL,
+                symbol.r#type() == stt::FUNC,
+            )
+        });
+
+        Ok(Self {
+            segments,
+            sections,
+            symbols,
+        })
+    }
+
+    /// Where the virtual address `address` is.
+    pub fn lookup(&self, address: u64) -> Location<'a> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|(_, segment)| contains(segment.vaddr(), segment.memsz(), address))
+            .copied();
+
+        let index = self
+            .sections
+            .partition_point(|(_, section)| section.addr() <= address);
+        let section = self.sections[..index]
+            .iter()
+            .rev()
+            .find(|(_, section)| contains(section.addr(), section.size(), address))
+            .copied();
+
+        let offset = match (segment, section) {
+            (Some((_, segment)), _) => {
+                let offset = address - segment.vaddr();
+                (offset < segment.filesz())
+                    .then(|| segment.offset().checked_add(offset))
+                    .flatten()
+            }
+            // Relocatable files have no segments
+            (None, Some((_, section))) if section.r#type() != sh::r#type::NOBITS => {
+                section.offset().checked_add(address - section.addr())
+            }
+            (None, _) => None,
+        };
+
+        // The symbols before an address the file does not map are not around it
+        let symbol = match (segment, section) {
+            (None, None) => None,
+            _ => self.symbol(address, section.map(|(index, _)| index)),
+        };
+
+        Location {
+            address,
+            offset,
+            segment,
+            section,
+            symbol,
+        }
+    }
+
+    /// Where the file offset `offset` is, `None` when the file is not loaded from there.
+    pub fn lookup_offset(&self, offset: u64) -> Option<Location<'a>> {
+        if let Some((_, segment)) = self
+            .segments
+            .iter()
+            .find(|(_, segment)| contains(segment.offset(), segment.filesz(), offset))
+        {
+            let address = segment.vaddr().checked_add(offset - segment.offset())?;
+            return Some(self.lookup(address));
+        }
+
+        // Without segments look in the sections, where relocatable files load from
+        let &(index, section) = self.sections.iter().find(|(_, section)| {
+            section.r#type() != sh::r#type::NOBITS
+                && contains(section.offset(), section.size(), offset)
+        })?;
+        let address = section.addr().checked_add(offset - section.offset())?;
+
+        Some(Location {
+            address,
+            offset: Some(offset),
+            segment: None,
+            section: Some((index, section)),
+            symbol: self.symbol(address, Some(index)),
+        })
+    }
+
+    /// Nearest symbol at or before `address`, in section `section` if it is known. A symbol
+    /// with a size does not cover the addresses past its end.
+    fn symbol(&self, address: u64, section: Option<usize>) -> Option<Symbol<'a>> {
+        let index = self
+            .symbols
+            .partition_point(|symbol| symbol.value() <= address);
+
+        let symbol = self.symbols[..index].iter().rev().find(|symbol| {
+            section.is_none_or(|section| symbol.section_index() == Some(section as u32))
+        })?;
+
+        (symbol.size() == 0 || address - symbol.value() < symbol.size()).then_some(*symbol)
+    }
+}
+
+fn contains(start: u64, size: u64, address: u64) -> bool {
+    address >= start && address - start < size
+}
+
+#[cfg(test)]
+mod test {
+    use crate::elf::Elf;
+
+    #[test]
+    fn lookup_of_exe() {
+        let elf = Elf::parse(include_bytes!("../../test/exe")).unwrap();
+        let index = elf.address_index().unwrap();
+
+        let main = index.lookup(0x116c);
+        assert_eq!(main.to_string(), "main+0xc");
+        assert_eq!(main.section().unwrap().1.name(), Some(".text"));
+        assert_eq!(main.segment().unwrap().0, 3);
+        assert_eq!(main.offset(), Some(0x116c));
+
+        // Past the end of _start, and in the padding between .text and .fini
+        assert_eq!(index.lookup(0x1070).to_string(), ".text+0x30");
+        assert_eq!(index.lookup(0x1176).to_string(), "0x1176");
+        assert_eq!(index.lookup(0x1176).segment().unwrap().0, 3);
+        assert_eq!(index.lookup(0x1004).to_string(), "_init+0x4");
+
+        let bss = index.lookup(0x401c);
+        assert_eq!(bss.section().unwrap().1.name(), Some(".bss"));
+        assert_eq!(bss.offset(), None);
+
+        assert_eq!(index.lookup(0x100000).to_string(), "0x100000");
+        assert!(index.lookup(0x100000).segment().is_none());
+
+        assert_eq!(index.lookup_offset(0x1145).unwrap().to_string(), "foo+0xc");
+        assert!(index.lookup_offset(0x10000).is_none());
+    }
+
+    #[test]
+    fn lookup_offset_of_object() {
+        let elf = Elf::parse(include_bytes!("../../test/lib.o")).unwrap();
+        let index = elf.address_index().unwrap();
+
+        let foo = index.lookup_offset(0x50).unwrap();
+        assert_eq!(foo.to_string(), "foo+0x10");
+        assert_eq!(foo.address(), 0x10);
+
+        let rodata = index.lookup_offset(0x68).unwrap();
+        assert_eq!(rodata.to_string(), ".rodata+0x1");
+        assert!(rodata.symbol().is_none());
+    }
+
+    #[test]
+    fn lookup_of_crafted_segment() {
+        // The executable segment, program header 3, moved to the end of the offsets and then
+        // of the addresses
+        let mut file = include_bytes!("../../test/exe").to_vec();
+        let phdr = 0x40 + 3 * 0x38;
+
+        file[phdr + 0x08..phdr + 0x10].copy_from_slice(&(u64::MAX - 0x100).to_le_bytes());
+        let elf = Elf::parse(&file).unwrap();
+        assert_eq!(elf.address_index().unwrap().lookup(0x116c).offset(), None);
+        assert_eq!(elf.segments().translate(0x116c), None);
+
+        file[phdr + 0x08..phdr + 0x10].copy_from_slice(&0x1000u64.to_le_bytes());
+        file[phdr + 0x10..phdr + 0x18].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+        let elf = Elf::parse(&file).unwrap();
+        assert!(elf.address_index().unwrap().lookup_offset(0x1145).is_none());
+    }
+}
@@ -84,21 +84,40 @@ pub mod e {
     pub const    TYPE: usize = 0x10;
     pub const MACHINE: usize = 0x12;
     pub const VERSION: usize = 0x14;
+    pub const   ENTRY: usize = 0x18;
+
+    // (ELFCLASS32, ELFCLASS64) offsets of the fields after e_entry
+    pub const     PHOFF: (usize, usize) = (0x1C, 0x20);
+    pub const     SHOFF: (usize, usize) = (0x20, 0x28);
+    pub const     FLAGS: (usize, usize) = (0x24, 0x30);
+    pub const    EHSIZE: (usize, usize) = (0x28, 0x34);
+    pub const PHENTSIZE: (usize, usize) = (0x2A, 0x36);
+    pub const     PHNUM: (usize, usize) = (0x2C, 0x38);
+    pub const SHENTSIZE: (usize, usize) = (0x2E, 0x3A);
+    pub const     SHNUM: (usize, usize) = (0x30, 0x3C);
+    pub const  SHSTRNDX: (usize, usize) = (0x32, 0x3E);
 
     pub mod r#type {
-        use std::ops::Range;
+        use std::ops::RangeInclusive;
 
-        pub const NONE:        usize = 0x00;
-        pub const  REL:        usize = 0x04;
-        pub const EXEC:        usize = 0x05;
-        pub const  DYN:        usize = 0x06;
-        pub const CORE:        usize = 0x07;
-        pub const   OS: Range<usize> = 0xFE00..0xFF00;
-        pub const PROC: Range<usize> = 0xFF00..0xFFFF;
+        pub const NONE:                   u16 = 0x0000;
+        pub const  REL:                   u16 = 0x0001;
+        pub const EXEC:                   u16 = 0x0002;
+        pub const  DYN:                   u16 = 0x0003;
+        pub const CORE:                   u16 = 0x0004;
+        pub const   OS: RangeInclusive<u16> = 0xFE00..=0xFEFF;
+        pub const PROC: RangeInclusive<u16> = 0xFF00..=0xFFFF;
     }
 
     pub mod machine {
-
+        pub const    NONE: u16 = 0x0000;
+        pub const     X86: u16 = 0x0003;
+        pub const     PPC: u16 = 0x0014;
+        pub const   PPC64: u16 = 0x0015;
+        pub const     ARM: u16 = 0x0028;
+        pub const  X86_64: u16 = 0x003E;
+        pub const AARCH64: u16 = 0x00B7;
+        pub const   RISCV: u16 = 0x00F3;
     }
 }
 
@@ -111,7 +130,7 @@ pub fn type_name(r#type: u16) -> &'static str {
         0x0004 => "Core file",
         0xFE00..=0xFEFF => "Reserved inclusive range. Operating system specific.",
         0xFF00..=0xFFFF => "Reserved inclusive range. Processor specific.",
-        _ => unreachable!(),
+        _ => "Unknown",
     }
 }
 
@@ -185,7 +204,7 @@ pub fn machine_name(isa: u16) -> &'static str {
         0x00F3 => "RISC-V",
         0x00F7 => "Berkeley Packet Filter",
         0x0101 => "WDC 65C816",
-        _ => unreachable!(),
+        _ => "Unknown",
         // TODO:
         // #define EM_NONE		  0	/* No machine */
         // #define EM_M32		  1	/* AT&T WE 32100 */
@@ -442,3 +461,1250 @@ pub fn machine_name(isa: u16) -> &'static str {
         // #define EM_56800EF	262	/* NXP 56800EF Digital Signal Controller (DSC) */
     }
 }
+
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod p {
+    // (ELFCLASS32, ELFCLASS64) offsets of the fields of Elf32_Phdr/Elf64_Phdr
+    pub const   TYPE: (usize, usize) = (0x00, 0x00);
+    pub const  FLAGS: (usize, usize) = (0x18, 0x04);
+    pub const OFFSET: (usize, usize) = (0x04, 0x08);
+    pub const  VADDR: (usize, usize) = (0x08, 0x10);
+    pub const  PADDR: (usize, usize) = (0x0C, 0x18);
+    pub const FILESZ: (usize, usize) = (0x10, 0x20);
+    pub const  MEMSZ: (usize, usize) = (0x14, 0x28);
+    pub const  ALIGN: (usize, usize) = (0x1C, 0x30);
+
+    pub mod r#type {
+        pub const         NULL: u32 = 0x00000000;
+        pub const         LOAD: u32 = 0x00000001;
+        pub const      DYNAMIC: u32 = 0x00000002;
+        pub const       INTERP: u32 = 0x00000003;
+        pub const         NOTE: u32 = 0x00000004;
+        pub const        SHLIB: u32 = 0x00000005;
+        pub const         PHDR: u32 = 0x00000006;
+        pub const          TLS: u32 = 0x00000007;
+        pub const GNU_EH_FRAME: u32 = 0x6474E550;
+        pub const    GNU_STACK: u32 = 0x6474E551;
+        pub const    GNU_RELRO: u32 = 0x6474E552;
+        pub const GNU_PROPERTY: u32 = 0x6474E553;
+        pub const   GNU_SFRAME: u32 = 0x6474E554;
+    }
+
+    pub mod flags {
+        pub const X: u32 = 0x01;
+        pub const W: u32 = 0x02;
+        pub const R: u32 = 0x04;
+    }
+}
+
+pub fn p_type_name(r#type: u32) -> &'static str {
+    match r#type {
+        0x00000000 => "NULL",
+        0x00000001 => "LOAD",
+        0x00000002 => "DYNAMIC",
+        0x00000003 => "INTERP",
+        0x00000004 => "NOTE",
+        0x00000005 => "SHLIB",
+        0x00000006 => "PHDR",
+        0x00000007 => "TLS",
+        0x6474E550 => "GNU_EH_FRAME",
+        0x6474E551 => "GNU_STACK",
+        0x6474E552 => "GNU_RELRO",
+        0x6474E553 => "GNU_PROPERTY",
+        0x6474E554 => "GNU_SFRAME",
+        0x60000000..=0x6FFFFFFF => "LOOS+",
+        0x70000000..=0x7FFFFFFF => "LOPROC+",
+        _ => "UNKNOWN",
+    }
+}
+
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod sh {
+    // (ELFCLASS32, ELFCLASS64) offsets of the fields of Elf32_Shdr/Elf64_Shdr
+    pub const      NAME: (usize, usize) = (0x00, 0x00);
+    pub const      TYPE: (usize, usize) = (0x04, 0x04);
+    pub const     FLAGS: (usize, usize) = (0x08, 0x08);
+    pub const      ADDR: (usize, usize) = (0x0C, 0x10);
+    pub const    OFFSET: (usize, usize) = (0x10, 0x18);
+    pub const      SIZE: (usize, usize) = (0x14, 0x20);
+    pub const      LINK: (usize, usize) = (0x18, 0x28);
+    pub const      INFO: (usize, usize) = (0x1C, 0x2C);
+    pub const ADDRALIGN: (usize, usize) = (0x20, 0x30);
+    pub const   ENTSIZE: (usize, usize) = (0x24, 0x38);
+
+    pub mod r#type {
+        pub const           NULL: u32 = 0x00000000;
+        pub const       PROGBITS: u32 = 0x00000001;
+        pub const         SYMTAB: u32 = 0x00000002;
+        pub const         STRTAB: u32 = 0x00000003;
+        pub const           RELA: u32 = 0x00000004;
+        pub const           HASH: u32 = 0x00000005;
+        pub const        DYNAMIC: u32 = 0x00000006;
+        pub const           NOTE: u32 = 0x00000007;
+        pub const         NOBITS: u32 = 0x00000008;
+        pub const            REL: u32 = 0x00000009;
+        pub const          SHLIB: u32 = 0x0000000A;
+        pub const         DYNSYM: u32 = 0x0000000B;
+        pub const     INIT_ARRAY: u32 = 0x0000000E;
+        pub const     FINI_ARRAY: u32 = 0x0000000F;
+        pub const  PREINIT_ARRAY: u32 = 0x00000010;
+        pub const          GROUP: u32 = 0x00000011;
+        pub const   SYMTAB_SHNDX: u32 = 0x00000012;
+        pub const           RELR: u32 = 0x00000013;
+        pub const     GNU_SFRAME: u32 = 0x6FFFFFF4;
+        pub const GNU_ATTRIBUTES: u32 = 0x6FFFFFF5;
+        pub const       GNU_HASH: u32 = 0x6FFFFFF6;
+        pub const    GNU_LIBLIST: u32 = 0x6FFFFFF7;
+        pub const       CHECKSUM: u32 = 0x6FFFFFF8;
+        pub const     GNU_VERDEF: u32 = 0x6FFFFFFD;
+        pub const    GNU_VERNEED: u32 = 0x6FFFFFFE;
+        pub const     GNU_VERSYM: u32 = 0x6FFFFFFF;
+    }
+
+    pub mod flags {
+        pub const            WRITE: u64 = 0x00000001;
+        pub const            ALLOC: u64 = 0x00000002;
+        pub const        EXECINSTR: u64 = 0x00000004;
+        pub const            MERGE: u64 = 0x00000010;
+        pub const          STRINGS: u64 = 0x00000020;
+        pub const        INFO_LINK: u64 = 0x00000040;
+        pub const       LINK_ORDER: u64 = 0x00000080;
+        pub const OS_NONCONFORMING: u64 = 0x00000100;
+        pub const            GROUP: u64 = 0x00000200;
+        pub const              TLS: u64 = 0x00000400;
+        pub const       COMPRESSED: u64 = 0x00000800;
+        pub const       GNU_RETAIN: u64 = 0x00200000;
+        pub const           MASKOS: u64 = 0x0FF00000;
+        pub const          EXCLUDE: u64 = 0x80000000;
+        pub const         MASKPROC: u64 = 0xF0000000;
+    }
+}
+
+/// Special section indexes
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod shn {
+    pub const      UNDEF: u16 = 0x0000;
+    pub const  LORESERVE: u16 = 0xFF00;
+    pub const     LOPROC: u16 = 0xFF00;
+    pub const     HIPROC: u16 = 0xFF1F;
+    pub const       LOOS: u16 = 0xFF20;
+    pub const       HIOS: u16 = 0xFF3F;
+    pub const        ABS: u16 = 0xFFF1;
+    pub const     COMMON: u16 = 0xFFF2;
+    pub const     XINDEX: u16 = 0xFFFF;
+}
+
+/// Compression header at the start of the contents of `SHF_COMPRESSED` sections
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod ch {
+    // (ELFCLASS32, ELFCLASS64) offsets of the fields of Elf32_Chdr/Elf64_Chdr
+    pub const      TYPE: (usize, usize) = (0x00, 0x00);
+    pub const      SIZE: (usize, usize) = (0x04, 0x08);
+    pub const ADDRALIGN: (usize, usize) = (0x08, 0x10);
+
+    pub mod r#type {
+        pub const ZLIB: u32 = 0x00000001;
+        pub const ZSTD: u32 = 0x00000002;
+    }
+}
+
+pub fn ch_type_name(r#type: u32) -> &'static str {
+    match r#type {
+        ch::r#type::ZLIB => "ZLIB",
+        ch::r#type::ZSTD => "ZSTD",
+        _ => "UNKNOWN",
+    }
+}
+
+pub fn sh_type_name(r#type: u32) -> &'static str {
+    match r#type {
+        0x00000000 => "NULL",
+        0x00000001 => "PROGBITS",
+        0x00000002 => "SYMTAB",
+        0x00000003 => "STRTAB",
+        0x00000004 => "RELA",
+        0x00000005 => "HASH",
+        0x00000006 => "DYNAMIC",
+        0x00000007 => "NOTE",
+        0x00000008 => "NOBITS",
+        0x00000009 => "REL",
+        0x0000000A => "SHLIB",
+        0x0000000B => "DYNSYM",
+        0x0000000E => "INIT_ARRAY",
+        0x0000000F => "FINI_ARRAY",
+        0x00000010 => "PREINIT_ARRAY",
+        0x00000011 => "GROUP",
+        0x00000012 => "SYMTAB SECTION INDICES",
+        0x00000013 => "RELR",
+        0x6FFFFFF4 => "GNU_SFRAME",
+        0x6FFFFFF5 => "GNU_ATTRIBUTES",
+        0x6FFFFFF6 => "GNU_HASH",
+        0x6FFFFFF7 => "GNU_LIBLIST",
+        0x6FFFFFF8 => "CHECKSUM",
+        0x6FFFFFFD => "VERDEF",
+        0x6FFFFFFE => "VERNEED",
+        0x6FFFFFFF => "VERSYM",
+        0x60000000..=0x6FFFFFFF => "LOOS+",
+        0x70000000..=0x7FFFFFFF => "LOPROC+",
+        0x80000000..=0xFFFFFFFF => "LOUSER+",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Same keys as `readelf -S`, see: binutils/readelf.c get_elf_section_flags
+pub fn sh_flags_name(flags: u64) -> String {
+    const KEYS: [(u64, char); 13] = [
+        (sh::flags::WRITE, 'W'),
+        (sh::flags::ALLOC, 'A'),
+        (sh::flags::EXECINSTR, 'X'),
+        (sh::flags::MERGE, 'M'),
+        (sh::flags::STRINGS, 'S'),
+        (sh::flags::INFO_LINK, 'I'),
+        (sh::flags::LINK_ORDER, 'L'),
+        (sh::flags::OS_NONCONFORMING, 'O'),
+        (sh::flags::GROUP, 'G'),
+        (sh::flags::TLS, 'T'),
+        (sh::flags::COMPRESSED, 'C'),
+        (sh::flags::GNU_RETAIN, 'R'),
+        (sh::flags::EXCLUDE, 'E'),
+    ];
+
+    let mut name: String = KEYS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, key)| key)
+        .collect();
+
+    let known = KEYS.iter().fold(0, |acc, (flag, _)| acc | flag);
+    let unknown = flags & !known;
+
+    if unknown & sh::flags::MASKOS != 0 {
+        name.push('o');
+    }
+    if unknown & sh::flags::MASKPROC != 0 {
+        name.push('p');
+    }
+    if unknown & !(sh::flags::MASKOS | sh::flags::MASKPROC) != 0 {
+        name.push('x');
+    }
+
+    name
+}
+
+/// (ELFCLASS32, ELFCLASS64) offsets of the fields of Elf32_Sym/Elf64_Sym
+#[rustfmt::skip]
+pub mod st {
+    pub const  NAME: (usize, usize) = (0x00, 0x00);
+    pub const VALUE: (usize, usize) = (0x04, 0x08);
+    pub const  SIZE: (usize, usize) = (0x08, 0x10);
+    pub const  INFO: (usize, usize) = (0x0C, 0x04);
+    pub const OTHER: (usize, usize) = (0x0D, 0x05);
+    pub const SHNDX: (usize, usize) = (0x0E, 0x06);
+}
+
+/// Symbol binding, the high nibble of `st_info`
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod stb {
+    pub const      LOCAL: u8 = 0x00;
+    pub const     GLOBAL: u8 = 0x01;
+    pub const       WEAK: u8 = 0x02;
+    pub const GNU_UNIQUE: u8 = 0x0A;
+}
+
+/// Symbol type, the low nibble of `st_info`
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod stt {
+    pub const    NOTYPE: u8 = 0x00;
+    pub const    OBJECT: u8 = 0x01;
+    pub const      FUNC: u8 = 0x02;
+    pub const   SECTION: u8 = 0x03;
+    pub const      FILE: u8 = 0x04;
+    pub const    COMMON: u8 = 0x05;
+    pub const       TLS: u8 = 0x06;
+    pub const GNU_IFUNC: u8 = 0x0A;
+}
+
+/// Symbol visibility, the low 2 bits of `st_other`
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod stv {
+    pub const   DEFAULT: u8 = 0x00;
+    pub const  INTERNAL: u8 = 0x01;
+    pub const    HIDDEN: u8 = 0x02;
+    pub const PROTECTED: u8 = 0x03;
+}
+
+pub fn st_bind_name(bind: u8) -> &'static str {
+    match bind {
+        0x00 => "LOCAL",
+        0x01 => "GLOBAL",
+        0x02 => "WEAK",
+        0x0A => "UNIQUE",
+        0x0B..=0x0C => "OS",
+        0x0D..=0x0F => "PROC",
+        _ => "UNKNOWN",
+    }
+}
+
+pub fn st_type_name(r#type: u8) -> &'static str {
+    match r#type {
+        0x00 => "NOTYPE",
+        0x01 => "OBJECT",
+        0x02 => "FUNC",
+        0x03 => "SECTION",
+        0x04 => "FILE",
+        0x05 => "COMMON",
+        0x06 => "TLS",
+        0x0A => "IFUNC",
+        0x0B..=0x0C => "OS",
+        0x0D..=0x0F => "PROC",
+        _ => "UNKNOWN",
+    }
+}
+
+pub fn st_visibility_name(visibility: u8) -> &'static str {
+    match visibility {
+        0x00 => "DEFAULT",
+        0x01 => "INTERNAL",
+        0x02 => "HIDDEN",
+        0x03 => "PROTECTED",
+        _ => unreachable!(),
+    }
+}
+
+/// Relocation type names for `e_machine`, see: binutils/include/elf/*.h
+pub fn r_type_name(machine: u16, r#type: u32) -> Option<&'static str> {
+    match machine {
+        e::machine::X86 => r_386_name(r#type),
+        e::machine::ARM => r_arm_name(r#type),
+        e::machine::X86_64 => r_x86_64_name(r#type),
+        e::machine::AARCH64 => r_aarch64_name(r#type),
+        e::machine::RISCV => r_riscv_name(r#type),
+        e::machine::PPC => r_ppc_name(r#type),
+        e::machine::PPC64 => r_ppc64_name(r#type),
+        _ => None,
+    }
+}
+
+fn r_x86_64_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_X86_64_NONE",
+        1 => "R_X86_64_64",
+        2 => "R_X86_64_PC32",
+        3 => "R_X86_64_GOT32",
+        4 => "R_X86_64_PLT32",
+        5 => "R_X86_64_COPY",
+        6 => "R_X86_64_GLOB_DAT",
+        7 => "R_X86_64_JUMP_SLOT",
+        8 => "R_X86_64_RELATIVE",
+        9 => "R_X86_64_GOTPCREL",
+        10 => "R_X86_64_32",
+        11 => "R_X86_64_32S",
+        12 => "R_X86_64_16",
+        13 => "R_X86_64_PC16",
+        14 => "R_X86_64_8",
+        15 => "R_X86_64_PC8",
+        16 => "R_X86_64_DTPMOD64",
+        17 => "R_X86_64_DTPOFF64",
+        18 => "R_X86_64_TPOFF64",
+        19 => "R_X86_64_TLSGD",
+        20 => "R_X86_64_TLSLD",
+        21 => "R_X86_64_DTPOFF32",
+        22 => "R_X86_64_GOTTPOFF",
+        23 => "R_X86_64_TPOFF32",
+        24 => "R_X86_64_PC64",
+        25 => "R_X86_64_GOTOFF64",
+        26 => "R_X86_64_GOTPC32",
+        27 => "R_X86_64_GOT64",
+        28 => "R_X86_64_GOTPCREL64",
+        29 => "R_X86_64_GOTPC64",
+        30 => "R_X86_64_GOTPLT64",
+        31 => "R_X86_64_PLTOFF64",
+        32 => "R_X86_64_SIZE32",
+        33 => "R_X86_64_SIZE64",
+        34 => "R_X86_64_GOTPC32_TLSDESC",
+        35 => "R_X86_64_TLSDESC_CALL",
+        36 => "R_X86_64_TLSDESC",
+        37 => "R_X86_64_IRELATIVE",
+        38 => "R_X86_64_RELATIVE64",
+        41 => "R_X86_64_GOTPCRELX",
+        42 => "R_X86_64_REX_GOTPCRELX",
+        _ => return None,
+    })
+}
+
+fn r_386_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_386_NONE",
+        1 => "R_386_32",
+        2 => "R_386_PC32",
+        3 => "R_386_GOT32",
+        4 => "R_386_PLT32",
+        5 => "R_386_COPY",
+        6 => "R_386_GLOB_DAT",
+        7 => "R_386_JUMP_SLOT",
+        8 => "R_386_RELATIVE",
+        9 => "R_386_GOTOFF",
+        10 => "R_386_GOTPC",
+        11 => "R_386_32PLT",
+        14 => "R_386_TLS_TPOFF",
+        15 => "R_386_TLS_IE",
+        16 => "R_386_TLS_GOTIE",
+        17 => "R_386_TLS_LE",
+        18 => "R_386_TLS_GD",
+        19 => "R_386_TLS_LDM",
+        20 => "R_386_16",
+        21 => "R_386_PC16",
+        22 => "R_386_8",
+        23 => "R_386_PC8",
+        24 => "R_386_TLS_GD_32",
+        25 => "R_386_TLS_GD_PUSH",
+        26 => "R_386_TLS_GD_CALL",
+        27 => "R_386_TLS_GD_POP",
+        28 => "R_386_TLS_LDM_32",
+        29 => "R_386_TLS_LDM_PUSH",
+        30 => "R_386_TLS_LDM_CALL",
+        31 => "R_386_TLS_LDM_POP",
+        32 => "R_386_TLS_LDO_32",
+        33 => "R_386_TLS_IE_32",
+        34 => "R_386_TLS_LE_32",
+        35 => "R_386_TLS_DTPMOD32",
+        36 => "R_386_TLS_DTPOFF32",
+        37 => "R_386_TLS_TPOFF32",
+        38 => "R_386_SIZE32",
+        39 => "R_386_TLS_GOTDESC",
+        40 => "R_386_TLS_DESC_CALL",
+        41 => "R_386_TLS_DESC",
+        42 => "R_386_IRELATIVE",
+        43 => "R_386_GOT32X",
+        _ => return None,
+    })
+}
+
+fn r_aarch64_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_AARCH64_NONE",
+        257 => "R_AARCH64_ABS64",
+        258 => "R_AARCH64_ABS32",
+        259 => "R_AARCH64_ABS16",
+        260 => "R_AARCH64_PREL64",
+        261 => "R_AARCH64_PREL32",
+        262 => "R_AARCH64_PREL16",
+        263 => "R_AARCH64_MOVW_UABS_G0",
+        264 => "R_AARCH64_MOVW_UABS_G0_NC",
+        265 => "R_AARCH64_MOVW_UABS_G1",
+        266 => "R_AARCH64_MOVW_UABS_G1_NC",
+        267 => "R_AARCH64_MOVW_UABS_G2",
+        268 => "R_AARCH64_MOVW_UABS_G2_NC",
+        269 => "R_AARCH64_MOVW_UABS_G3",
+        270 => "R_AARCH64_MOVW_SABS_G0",
+        271 => "R_AARCH64_MOVW_SABS_G1",
+        272 => "R_AARCH64_MOVW_SABS_G2",
+        273 => "R_AARCH64_LD_PREL_LO19",
+        274 => "R_AARCH64_ADR_PREL_LO21",
+        275 => "R_AARCH64_ADR_PREL_PG_HI21",
+        276 => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+        277 => "R_AARCH64_ADD_ABS_LO12_NC",
+        278 => "R_AARCH64_LDST8_ABS_LO12_NC",
+        279 => "R_AARCH64_TSTBR14",
+        280 => "R_AARCH64_CONDBR19",
+        282 => "R_AARCH64_JUMP26",
+        283 => "R_AARCH64_CALL26",
+        284 => "R_AARCH64_LDST16_ABS_LO12_NC",
+        285 => "R_AARCH64_LDST32_ABS_LO12_NC",
+        286 => "R_AARCH64_LDST64_ABS_LO12_NC",
+        299 => "R_AARCH64_LDST128_ABS_LO12_NC",
+        309 => "R_AARCH64_GOT_LD_PREL19",
+        311 => "R_AARCH64_ADR_GOT_PAGE",
+        312 => "R_AARCH64_LD64_GOT_LO12_NC",
+        512 => "R_AARCH64_TLSGD_ADR_PAGE21",
+        514 => "R_AARCH64_TLSGD_ADD_LO12_NC",
+        541 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+        542 => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+        549 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+        550 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+        551 => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+        562 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+        563 => "R_AARCH64_TLSDESC_LD64_LO12",
+        564 => "R_AARCH64_TLSDESC_ADD_LO12",
+        569 => "R_AARCH64_TLSDESC_CALL",
+        1024 => "R_AARCH64_COPY",
+        1025 => "R_AARCH64_GLOB_DAT",
+        1026 => "R_AARCH64_JUMP_SLOT",
+        1027 => "R_AARCH64_RELATIVE",
+        1028 => "R_AARCH64_TLS_DTPMOD",
+        1029 => "R_AARCH64_TLS_DTPREL",
+        1030 => "R_AARCH64_TLS_TPREL",
+        1031 => "R_AARCH64_TLSDESC",
+        1032 => "R_AARCH64_IRELATIVE",
+        _ => return None,
+    })
+}
+
+fn r_arm_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_ARM_NONE",
+        1 => "R_ARM_PC24",
+        2 => "R_ARM_ABS32",
+        3 => "R_ARM_REL32",
+        4 => "R_ARM_LDR_PC_G0",
+        5 => "R_ARM_ABS16",
+        6 => "R_ARM_ABS12",
+        7 => "R_ARM_THM_ABS5",
+        8 => "R_ARM_ABS8",
+        9 => "R_ARM_SBREL32",
+        10 => "R_ARM_THM_CALL",
+        11 => "R_ARM_THM_PC8",
+        17 => "R_ARM_TLS_DTPMOD32",
+        18 => "R_ARM_TLS_DTPOFF32",
+        19 => "R_ARM_TLS_TPOFF32",
+        20 => "R_ARM_COPY",
+        21 => "R_ARM_GLOB_DAT",
+        22 => "R_ARM_JUMP_SLOT",
+        23 => "R_ARM_RELATIVE",
+        24 => "R_ARM_GOTOFF32",
+        25 => "R_ARM_BASE_PREL",
+        26 => "R_ARM_GOT_BREL",
+        27 => "R_ARM_PLT32",
+        28 => "R_ARM_CALL",
+        29 => "R_ARM_JUMP24",
+        30 => "R_ARM_THM_JUMP24",
+        38 => "R_ARM_TARGET1",
+        40 => "R_ARM_V4BX",
+        41 => "R_ARM_TARGET2",
+        42 => "R_ARM_PREL31",
+        43 => "R_ARM_MOVW_ABS_NC",
+        44 => "R_ARM_MOVT_ABS",
+        45 => "R_ARM_MOVW_PREL_NC",
+        46 => "R_ARM_MOVT_PREL",
+        47 => "R_ARM_THM_MOVW_ABS_NC",
+        48 => "R_ARM_THM_MOVT_ABS",
+        51 => "R_ARM_THM_JUMP19",
+        102 => "R_ARM_THM_JUMP11",
+        103 => "R_ARM_THM_JUMP8",
+        104 => "R_ARM_TLS_GD32",
+        105 => "R_ARM_TLS_LDM32",
+        106 => "R_ARM_TLS_LDO32",
+        107 => "R_ARM_TLS_IE32",
+        108 => "R_ARM_TLS_LE32",
+        160 => "R_ARM_IRELATIVE",
+        _ => return None,
+    })
+}
+
+fn r_riscv_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_RISCV_NONE",
+        1 => "R_RISCV_32",
+        2 => "R_RISCV_64",
+        3 => "R_RISCV_RELATIVE",
+        4 => "R_RISCV_COPY",
+        5 => "R_RISCV_JUMP_SLOT",
+        6 => "R_RISCV_TLS_DTPMOD32",
+        7 => "R_RISCV_TLS_DTPMOD64",
+        8 => "R_RISCV_TLS_DTPREL32",
+        9 => "R_RISCV_TLS_DTPREL64",
+        10 => "R_RISCV_TLS_TPREL32",
+        11 => "R_RISCV_TLS_TPREL64",
+        12 => "R_RISCV_TLSDESC",
+        16 => "R_RISCV_BRANCH",
+        17 => "R_RISCV_JAL",
+        18 => "R_RISCV_CALL",
+        19 => "R_RISCV_CALL_PLT",
+        20 => "R_RISCV_GOT_HI20",
+        21 => "R_RISCV_TLS_GOT_HI20",
+        22 => "R_RISCV_TLS_GD_HI20",
+        23 => "R_RISCV_PCREL_HI20",
+        24 => "R_RISCV_PCREL_LO12_I",
+        25 => "R_RISCV_PCREL_LO12_S",
+        26 => "R_RISCV_HI20",
+        27 => "R_RISCV_LO12_I",
+        28 => "R_RISCV_LO12_S",
+        29 => "R_RISCV_TPREL_HI20",
+        30 => "R_RISCV_TPREL_LO12_I",
+        31 => "R_RISCV_TPREL_LO12_S",
+        32 => "R_RISCV_TPREL_ADD",
+        33 => "R_RISCV_ADD8",
+        34 => "R_RISCV_ADD16",
+        35 => "R_RISCV_ADD32",
+        36 => "R_RISCV_ADD64",
+        37 => "R_RISCV_SUB8",
+        38 => "R_RISCV_SUB16",
+        39 => "R_RISCV_SUB32",
+        40 => "R_RISCV_SUB64",
+        41 => "R_RISCV_GOT32_PCREL",
+        43 => "R_RISCV_ALIGN",
+        44 => "R_RISCV_RVC_BRANCH",
+        45 => "R_RISCV_RVC_JUMP",
+        51 => "R_RISCV_RELAX",
+        52 => "R_RISCV_SUB6",
+        53 => "R_RISCV_SET6",
+        54 => "R_RISCV_SET8",
+        55 => "R_RISCV_SET16",
+        56 => "R_RISCV_SET32",
+        57 => "R_RISCV_32_PCREL",
+        58 => "R_RISCV_IRELATIVE",
+        59 => "R_RISCV_PLT32",
+        60 => "R_RISCV_SET_ULEB128",
+        61 => "R_RISCV_SUB_ULEB128",
+        62 => "R_RISCV_TLSDESC_HI20",
+        63 => "R_RISCV_TLSDESC_LOAD_LO12",
+        64 => "R_RISCV_TLSDESC_ADD_LO12",
+        65 => "R_RISCV_TLSDESC_CALL",
+        _ => return None,
+    })
+}
+
+fn r_ppc_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_PPC_NONE",
+        1 => "R_PPC_ADDR32",
+        2 => "R_PPC_ADDR24",
+        3 => "R_PPC_ADDR16",
+        4 => "R_PPC_ADDR16_LO",
+        5 => "R_PPC_ADDR16_HI",
+        6 => "R_PPC_ADDR16_HA",
+        7 => "R_PPC_ADDR14",
+        8 => "R_PPC_ADDR14_BRTAKEN",
+        9 => "R_PPC_ADDR14_BRNTAKEN",
+        10 => "R_PPC_REL24",
+        11 => "R_PPC_REL14",
+        12 => "R_PPC_REL14_BRTAKEN",
+        13 => "R_PPC_REL14_BRNTAKEN",
+        14 => "R_PPC_GOT16",
+        15 => "R_PPC_GOT16_LO",
+        16 => "R_PPC_GOT16_HI",
+        17 => "R_PPC_GOT16_HA",
+        18 => "R_PPC_PLTREL24",
+        19 => "R_PPC_COPY",
+        20 => "R_PPC_GLOB_DAT",
+        21 => "R_PPC_JMP_SLOT",
+        22 => "R_PPC_RELATIVE",
+        23 => "R_PPC_LOCAL24PC",
+        24 => "R_PPC_UADDR32",
+        25 => "R_PPC_UADDR16",
+        26 => "R_PPC_REL32",
+        27 => "R_PPC_PLT32",
+        28 => "R_PPC_PLTREL32",
+        29 => "R_PPC_PLT16_LO",
+        30 => "R_PPC_PLT16_HI",
+        31 => "R_PPC_PLT16_HA",
+        32 => "R_PPC_SDAREL16",
+        33 => "R_PPC_SECTOFF",
+        34 => "R_PPC_SECTOFF_LO",
+        35 => "R_PPC_SECTOFF_HI",
+        36 => "R_PPC_SECTOFF_HA",
+        37 => "R_PPC_ADDR30",
+        67 => "R_PPC_TLS",
+        68 => "R_PPC_DTPMOD32",
+        69 => "R_PPC_TPREL16",
+        70 => "R_PPC_TPREL16_LO",
+        71 => "R_PPC_TPREL16_HI",
+        72 => "R_PPC_TPREL16_HA",
+        73 => "R_PPC_TPREL32",
+        74 => "R_PPC_DTPREL16",
+        75 => "R_PPC_DTPREL16_LO",
+        76 => "R_PPC_DTPREL16_HI",
+        77 => "R_PPC_DTPREL16_HA",
+        78 => "R_PPC_DTPREL32",
+        248 => "R_PPC_IRELATIVE",
+        249 => "R_PPC_REL16",
+        250 => "R_PPC_REL16_LO",
+        251 => "R_PPC_REL16_HI",
+        252 => "R_PPC_REL16_HA",
+        _ => return None,
+    })
+}
+
+fn r_ppc64_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        0 => "R_PPC64_NONE",
+        1 => "R_PPC64_ADDR32",
+        2 => "R_PPC64_ADDR24",
+        3 => "R_PPC64_ADDR16",
+        4 => "R_PPC64_ADDR16_LO",
+        5 => "R_PPC64_ADDR16_HI",
+        6 => "R_PPC64_ADDR16_HA",
+        7 => "R_PPC64_ADDR14",
+        8 => "R_PPC64_ADDR14_BRTAKEN",
+        9 => "R_PPC64_ADDR14_BRNTAKEN",
+        10 => "R_PPC64_REL24",
+        11 => "R_PPC64_REL14",
+        12 => "R_PPC64_REL14_BRTAKEN",
+        13 => "R_PPC64_REL14_BRNTAKEN",
+        14 => "R_PPC64_GOT16",
+        15 => "R_PPC64_GOT16_LO",
+        16 => "R_PPC64_GOT16_HI",
+        17 => "R_PPC64_GOT16_HA",
+        19 => "R_PPC64_COPY",
+        20 => "R_PPC64_GLOB_DAT",
+        21 => "R_PPC64_JMP_SLOT",
+        22 => "R_PPC64_RELATIVE",
+        24 => "R_PPC64_UADDR32",
+        25 => "R_PPC64_UADDR16",
+        26 => "R_PPC64_REL32",
+        27 => "R_PPC64_PLT32",
+        28 => "R_PPC64_PLTREL32",
+        29 => "R_PPC64_PLT16_LO",
+        30 => "R_PPC64_PLT16_HI",
+        31 => "R_PPC64_PLT16_HA",
+        33 => "R_PPC64_SECTOFF",
+        34 => "R_PPC64_SECTOFF_LO",
+        35 => "R_PPC64_SECTOFF_HI",
+        36 => "R_PPC64_SECTOFF_HA",
+        37 => "R_PPC64_ADDR30",
+        38 => "R_PPC64_ADDR64",
+        39 => "R_PPC64_ADDR16_HIGHER",
+        40 => "R_PPC64_ADDR16_HIGHERA",
+        41 => "R_PPC64_ADDR16_HIGHEST",
+        42 => "R_PPC64_ADDR16_HIGHESTA",
+        43 => "R_PPC64_UADDR64",
+        44 => "R_PPC64_REL64",
+        45 => "R_PPC64_PLT64",
+        46 => "R_PPC64_PLTREL64",
+        47 => "R_PPC64_TOC16",
+        48 => "R_PPC64_TOC16_LO",
+        49 => "R_PPC64_TOC16_HI",
+        50 => "R_PPC64_TOC16_HA",
+        51 => "R_PPC64_TOC",
+        52 => "R_PPC64_PLTGOT16",
+        53 => "R_PPC64_PLTGOT16_LO",
+        54 => "R_PPC64_PLTGOT16_HI",
+        55 => "R_PPC64_PLTGOT16_HA",
+        56 => "R_PPC64_ADDR16_DS",
+        57 => "R_PPC64_ADDR16_LO_DS",
+        58 => "R_PPC64_GOT16_DS",
+        59 => "R_PPC64_GOT16_LO_DS",
+        60 => "R_PPC64_PLT16_LO_DS",
+        61 => "R_PPC64_SECTOFF_DS",
+        62 => "R_PPC64_SECTOFF_LO_DS",
+        63 => "R_PPC64_TOC16_DS",
+        64 => "R_PPC64_TOC16_LO_DS",
+        65 => "R_PPC64_PLTGOT16_DS",
+        66 => "R_PPC64_PLTGOT16_LO_DS",
+        67 => "R_PPC64_TLS",
+        68 => "R_PPC64_DTPMOD64",
+        69 => "R_PPC64_TPREL16",
+        70 => "R_PPC64_TPREL16_LO",
+        71 => "R_PPC64_TPREL16_HI",
+        72 => "R_PPC64_TPREL16_HA",
+        73 => "R_PPC64_TPREL64",
+        74 => "R_PPC64_DTPREL16",
+        75 => "R_PPC64_DTPREL16_LO",
+        76 => "R_PPC64_DTPREL16_HI",
+        77 => "R_PPC64_DTPREL16_HA",
+        78 => "R_PPC64_DTPREL64",
+        107 => "R_PPC64_TLSGD",
+        108 => "R_PPC64_TLSLD",
+        109 => "R_PPC64_TOCSAVE",
+        116 => "R_PPC64_REL24_NOTOC",
+        248 => "R_PPC64_IRELATIVE",
+        249 => "R_PPC64_REL16",
+        250 => "R_PPC64_REL16_LO",
+        251 => "R_PPC64_REL16_HI",
+        252 => "R_PPC64_REL16_HA",
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod dt {
+    pub const            NULL: u64 = 0x00000000;
+    pub const          NEEDED: u64 = 0x00000001;
+    pub const        PLTRELSZ: u64 = 0x00000002;
+    pub const          PLTGOT: u64 = 0x00000003;
+    pub const            HASH: u64 = 0x00000004;
+    pub const          STRTAB: u64 = 0x00000005;
+    pub const          SYMTAB: u64 = 0x00000006;
+    pub const            RELA: u64 = 0x00000007;
+    pub const          RELASZ: u64 = 0x00000008;
+    pub const         RELAENT: u64 = 0x00000009;
+    pub const           STRSZ: u64 = 0x0000000A;
+    pub const          SYMENT: u64 = 0x0000000B;
+    pub const            INIT: u64 = 0x0000000C;
+    pub const            FINI: u64 = 0x0000000D;
+    pub const          SONAME: u64 = 0x0000000E;
+    pub const           RPATH: u64 = 0x0000000F;
+    pub const        SYMBOLIC: u64 = 0x00000010;
+    pub const             REL: u64 = 0x00000011;
+    pub const           RELSZ: u64 = 0x00000012;
+    pub const          RELENT: u64 = 0x00000013;
+    pub const          PLTREL: u64 = 0x00000014;
+    pub const           DEBUG: u64 = 0x00000015;
+    pub const         TEXTREL: u64 = 0x00000016;
+    pub const          JMPREL: u64 = 0x00000017;
+    pub const        BIND_NOW: u64 = 0x00000018;
+    pub const      INIT_ARRAY: u64 = 0x00000019;
+    pub const      FINI_ARRAY: u64 = 0x0000001A;
+    pub const    INIT_ARRAYSZ: u64 = 0x0000001B;
+    pub const    FINI_ARRAYSZ: u64 = 0x0000001C;
+    pub const         RUNPATH: u64 = 0x0000001D;
+    pub const           FLAGS: u64 = 0x0000001E;
+    pub const   PREINIT_ARRAY: u64 = 0x00000020;
+    pub const PREINIT_ARRAYSZ: u64 = 0x00000021;
+    pub const    SYMTAB_SHNDX: u64 = 0x00000022;
+    pub const          RELRSZ: u64 = 0x00000023;
+    pub const            RELR: u64 = 0x00000024;
+    pub const         RELRENT: u64 = 0x00000025;
+    pub const   GNU_PRELINKED: u64 = 0x6FFFFDF5;
+    pub const  GNU_CONFLICTSZ: u64 = 0x6FFFFDF6;
+    pub const   GNU_LIBLISTSZ: u64 = 0x6FFFFDF7;
+    pub const        CHECKSUM: u64 = 0x6FFFFDF8;
+    pub const        PLTPADSZ: u64 = 0x6FFFFDF9;
+    pub const         MOVEENT: u64 = 0x6FFFFDFA;
+    pub const          MOVESZ: u64 = 0x6FFFFDFB;
+    pub const         FEATURE: u64 = 0x6FFFFDFC;
+    pub const       POSFLAG_1: u64 = 0x6FFFFDFD;
+    pub const         SYMINSZ: u64 = 0x6FFFFDFE;
+    pub const        SYMINENT: u64 = 0x6FFFFDFF;
+    pub const        GNU_HASH: u64 = 0x6FFFFEF5;
+    pub const     TLSDESC_PLT: u64 = 0x6FFFFEF6;
+    pub const     TLSDESC_GOT: u64 = 0x6FFFFEF7;
+    pub const    GNU_CONFLICT: u64 = 0x6FFFFEF8;
+    pub const     GNU_LIBLIST: u64 = 0x6FFFFEF9;
+    pub const          CONFIG: u64 = 0x6FFFFEFA;
+    pub const        DEPAUDIT: u64 = 0x6FFFFEFB;
+    pub const           AUDIT: u64 = 0x6FFFFEFC;
+    pub const          PLTPAD: u64 = 0x6FFFFEFD;
+    pub const         MOVETAB: u64 = 0x6FFFFEFE;
+    pub const         SYMINFO: u64 = 0x6FFFFEFF;
+    pub const          VERSYM: u64 = 0x6FFFFFF0;
+    pub const       RELACOUNT: u64 = 0x6FFFFFF9;
+    pub const        RELCOUNT: u64 = 0x6FFFFFFA;
+    pub const         FLAGS_1: u64 = 0x6FFFFFFB;
+    pub const          VERDEF: u64 = 0x6FFFFFFC;
+    pub const       VERDEFNUM: u64 = 0x6FFFFFFD;
+    pub const         VERNEED: u64 = 0x6FFFFFFE;
+    pub const      VERNEEDNUM: u64 = 0x6FFFFFFF;
+    pub const       AUXILIARY: u64 = 0x7FFFFFFD;
+    pub const            USED: u64 = 0x7FFFFFFE;
+    pub const          FILTER: u64 = 0x7FFFFFFF;
+
+    /// Values of `DT_FLAGS`
+    pub mod flags {
+        pub const     ORIGIN: u64 = 0x00000001;
+        pub const   SYMBOLIC: u64 = 0x00000002;
+        pub const    TEXTREL: u64 = 0x00000004;
+        pub const   BIND_NOW: u64 = 0x00000008;
+        pub const STATIC_TLS: u64 = 0x00000010;
+    }
+
+    /// Values of `DT_FLAGS_1`
+    pub mod flags_1 {
+        pub const        NOW: u64 = 0x00000001;
+        pub const     GLOBAL: u64 = 0x00000002;
+        pub const      GROUP: u64 = 0x00000004;
+        pub const   NODELETE: u64 = 0x00000008;
+        pub const   LOADFLTR: u64 = 0x00000010;
+        pub const  INITFIRST: u64 = 0x00000020;
+        pub const     NOOPEN: u64 = 0x00000040;
+        pub const     ORIGIN: u64 = 0x00000080;
+        pub const     DIRECT: u64 = 0x00000100;
+        pub const      TRANS: u64 = 0x00000200;
+        pub const  INTERPOSE: u64 = 0x00000400;
+        pub const   NODEFLIB: u64 = 0x00000800;
+        pub const     NODUMP: u64 = 0x00001000;
+        pub const    CONFALT: u64 = 0x00002000;
+        pub const  ENDFILTEE: u64 = 0x00004000;
+        pub const DISPRELDNE: u64 = 0x00008000;
+        pub const DISPRELPND: u64 = 0x00010000;
+        pub const   NODIRECT: u64 = 0x00020000;
+        pub const  IGNMULDEF: u64 = 0x00040000;
+        pub const    NOKSYMS: u64 = 0x00080000;
+        pub const      NOHDR: u64 = 0x00100000;
+        pub const     EDITED: u64 = 0x00200000;
+        pub const    NORELOC: u64 = 0x00400000;
+        pub const SYMINTPOSE: u64 = 0x00800000;
+        pub const  GLOBAUDIT: u64 = 0x01000000;
+        pub const  SINGLETON: u64 = 0x02000000;
+        pub const       STUB: u64 = 0x04000000;
+        pub const        PIE: u64 = 0x08000000;
+        pub const       KMOD: u64 = 0x10000000;
+        pub const WEAKFILTER: u64 = 0x20000000;
+        pub const   NOCOMMON: u64 = 0x40000000;
+    }
+}
+
+pub fn d_tag_name(tag: u64) -> &'static str {
+    match tag {
+        0x00000000 => "NULL",
+        0x00000001 => "NEEDED",
+        0x00000002 => "PLTRELSZ",
+        0x00000003 => "PLTGOT",
+        0x00000004 => "HASH",
+        0x00000005 => "STRTAB",
+        0x00000006 => "SYMTAB",
+        0x00000007 => "RELA",
+        0x00000008 => "RELASZ",
+        0x00000009 => "RELAENT",
+        0x0000000A => "STRSZ",
+        0x0000000B => "SYMENT",
+        0x0000000C => "INIT",
+        0x0000000D => "FINI",
+        0x0000000E => "SONAME",
+        0x0000000F => "RPATH",
+        0x00000010 => "SYMBOLIC",
+        0x00000011 => "REL",
+        0x00000012 => "RELSZ",
+        0x00000013 => "RELENT",
+        0x00000014 => "PLTREL",
+        0x00000015 => "DEBUG",
+        0x00000016 => "TEXTREL",
+        0x00000017 => "JMPREL",
+        0x00000018 => "BIND_NOW",
+        0x00000019 => "INIT_ARRAY",
+        0x0000001A => "FINI_ARRAY",
+        0x0000001B => "INIT_ARRAYSZ",
+        0x0000001C => "FINI_ARRAYSZ",
+        0x0000001D => "RUNPATH",
+        0x0000001E => "FLAGS",
+        0x00000020 => "PREINIT_ARRAY",
+        0x00000021 => "PREINIT_ARRAYSZ",
+        0x00000022 => "SYMTAB_SHNDX",
+        0x00000023 => "RELRSZ",
+        0x00000024 => "RELR",
+        0x00000025 => "RELRENT",
+        0x6FFFFDF5 => "GNU_PRELINKED",
+        0x6FFFFDF6 => "GNU_CONFLICTSZ",
+        0x6FFFFDF7 => "GNU_LIBLISTSZ",
+        0x6FFFFDF8 => "CHECKSUM",
+        0x6FFFFDF9 => "PLTPADSZ",
+        0x6FFFFDFA => "MOVEENT",
+        0x6FFFFDFB => "MOVESZ",
+        0x6FFFFDFC => "FEATURE",
+        0x6FFFFDFD => "POSFLAG_1",
+        0x6FFFFDFE => "SYMINSZ",
+        0x6FFFFDFF => "SYMINENT",
+        0x6FFFFEF5 => "GNU_HASH",
+        0x6FFFFEF6 => "TLSDESC_PLT",
+        0x6FFFFEF7 => "TLSDESC_GOT",
+        0x6FFFFEF8 => "GNU_CONFLICT",
+        0x6FFFFEF9 => "GNU_LIBLIST",
+        0x6FFFFEFA => "CONFIG",
+        0x6FFFFEFB => "DEPAUDIT",
+        0x6FFFFEFC => "AUDIT",
+        0x6FFFFEFD => "PLTPAD",
+        0x6FFFFEFE => "MOVETAB",
+        0x6FFFFEFF => "SYMINFO",
+        0x6FFFFFF0 => "VERSYM",
+        0x6FFFFFF9 => "RELACOUNT",
+        0x6FFFFFFA => "RELCOUNT",
+        0x6FFFFFFB => "FLAGS_1",
+        0x6FFFFFFC => "VERDEF",
+        0x6FFFFFFD => "VERDEFNUM",
+        0x6FFFFFFE => "VERNEED",
+        0x6FFFFFFF => "VERNEEDNUM",
+        0x7FFFFFFD => "AUXILIARY",
+        0x7FFFFFFE => "USED",
+        0x7FFFFFFF => "FILTER",
+        0x6000000D..=0x6FFFF000 => "LOOS+",
+        0x70000000..=0x7FFFFFFF => "LOPROC+",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Names of the bits set in a `DT_FLAGS` value
+pub fn d_flags_names(flags: u64) -> Vec<&'static str> {
+    const NAMES: [(u64, &str); 5] = [
+        (dt::flags::ORIGIN, "ORIGIN"),
+        (dt::flags::SYMBOLIC, "SYMBOLIC"),
+        (dt::flags::TEXTREL, "TEXTREL"),
+        (dt::flags::BIND_NOW, "BIND_NOW"),
+        (dt::flags::STATIC_TLS, "STATIC_TLS"),
+    ];
+
+    NAMES
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Names of the bits set in a `DT_FLAGS_1` value
+pub fn d_flags_1_names(flags: u64) -> Vec<&'static str> {
+    const NAMES: [(u64, &str); 31] = [
+        (dt::flags_1::NOW, "NOW"),
+        (dt::flags_1::GLOBAL, "GLOBAL"),
+        (dt::flags_1::GROUP, "GROUP"),
+        (dt::flags_1::NODELETE, "NODELETE"),
+        (dt::flags_1::LOADFLTR, "LOADFLTR"),
+        (dt::flags_1::INITFIRST, "INITFIRST"),
+        (dt::flags_1::NOOPEN, "NOOPEN"),
+        (dt::flags_1::ORIGIN, "ORIGIN"),
+        (dt::flags_1::DIRECT, "DIRECT"),
+        (dt::flags_1::TRANS, "TRANS"),
+        (dt::flags_1::INTERPOSE, "INTERPOSE"),
+        (dt::flags_1::NODEFLIB, "NODEFLIB"),
+        (dt::flags_1::NODUMP, "NODUMP"),
+        (dt::flags_1::CONFALT, "CONFALT"),
+        (dt::flags_1::ENDFILTEE, "ENDFILTEE"),
+        (dt::flags_1::DISPRELDNE, "DISPRELDNE"),
+        (dt::flags_1::DISPRELPND, "DISPRELPND"),
+        (dt::flags_1::NODIRECT, "NODIRECT"),
+        (dt::flags_1::IGNMULDEF, "IGNMULDEF"),
+        (dt::flags_1::NOKSYMS, "NOKSYMS"),
+        (dt::flags_1::NOHDR, "NOHDR"),
+        (dt::flags_1::EDITED, "EDITED"),
+        (dt::flags_1::NORELOC, "NORELOC"),
+        (dt::flags_1::SYMINTPOSE, "SYMINTPOSE"),
+        (dt::flags_1::GLOBAUDIT, "GLOBAUDIT"),
+        (dt::flags_1::SINGLETON, "SINGLETON"),
+        (dt::flags_1::STUB, "STUB"),
+        (dt::flags_1::PIE, "PIE"),
+        (dt::flags_1::KMOD, "KMOD"),
+        (dt::flags_1::WEAKFILTER, "WEAKFILTER"),
+        (dt::flags_1::NOCOMMON, "NOCOMMON"),
+    ];
+
+    NAMES
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Note types, their meaning depends on the owner in the note name
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod nt {
+    pub mod gnu {
+        pub const          ABI_TAG: u32 = 0x00000001;
+        pub const            HWCAP: u32 = 0x00000002;
+        pub const         BUILD_ID: u32 = 0x00000003;
+        pub const     GOLD_VERSION: u32 = 0x00000004;
+        pub const  PROPERTY_TYPE_0: u32 = 0x00000005;
+    }
+
+    pub mod fdo {
+        pub const PACKAGING_METADATA: u32 = 0xCAFE1A7E;
+    }
+
+    /// Notes of core dumps, owned by `CORE` or `LINUX`
+    pub mod core {
+        pub const         PRSTATUS: u32 = 0x00000001;
+        pub const         FPREGSET: u32 = 0x00000002;
+        pub const         PRPSINFO: u32 = 0x00000003;
+        pub const       TASKSTRUCT: u32 = 0x00000004;
+        pub const             AUXV: u32 = 0x00000006;
+        pub const          PPC_VMX: u32 = 0x00000100;
+        pub const       X86_XSTATE: u32 = 0x00000202;
+        pub const          ARM_VFP: u32 = 0x00000400;
+        pub const          ARM_TLS: u32 = 0x00000401;
+        pub const          SIGINFO: u32 = 0x53494749;
+        pub const             FILE: u32 = 0x46494C45;
+        pub const         PRXFPREG: u32 = 0x46E62B7F;
+    }
+}
+
+/// Properties of `NT_GNU_PROPERTY_TYPE_0` notes
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod gnu_property {
+    pub const             STACK_SIZE: u32 = 0x00000001;
+    pub const   NO_COPY_ON_PROTECTED: u32 = 0x00000002;
+    pub const               NEEDED_1: u32 = 0xB0008000;
+    pub const    AARCH64_FEATURE_1_AND: u32 = 0xC0000000;
+    pub const        X86_FEATURE_1_AND: u32 = 0xC0000002;
+    pub const     X86_FEATURE_2_NEEDED: u32 = 0xC0008001;
+    pub const         X86_ISA_1_NEEDED: u32 = 0xC0008002;
+    pub const       X86_FEATURE_2_USED: u32 = 0xC0010001;
+    pub const           X86_ISA_1_USED: u32 = 0xC0010002;
+
+    pub mod x86_feature_1 {
+        pub const     IBT: u32 = 0x00000001;
+        pub const   SHSTK: u32 = 0x00000002;
+        pub const LAM_U48: u32 = 0x00000004;
+        pub const LAM_U57: u32 = 0x00000008;
+    }
+
+    pub mod aarch64_feature_1 {
+        pub const BTI: u32 = 0x00000001;
+        pub const PAC: u32 = 0x00000002;
+        pub const GCS: u32 = 0x00000004;
+    }
+}
+
+pub fn n_type_name(owner: &str, r#type: u32) -> Option<&'static str> {
+    match (owner, r#type) {
+        ("GNU", 0x00000001) => Some("NT_GNU_ABI_TAG (ABI version tag)"),
+        ("GNU", 0x00000002) => Some("NT_GNU_HWCAP (DSO-supplied software HWCAP info)"),
+        ("GNU", 0x00000003) => Some("NT_GNU_BUILD_ID (unique build ID bitstring)"),
+        ("GNU", 0x00000004) => Some("NT_GNU_GOLD_VERSION (gold version)"),
+        ("GNU", 0x00000005) => Some("NT_GNU_PROPERTY_TYPE_0"),
+        ("FDO", 0xCAFE1A7E) => Some("FDO_PACKAGING_METADATA"),
+        ("CORE" | "LINUX", r#type) => match r#type {
+            nt::core::PRSTATUS => Some("NT_PRSTATUS (prstatus structure)"),
+            nt::core::FPREGSET => Some("NT_FPREGSET (floating point registers)"),
+            nt::core::PRPSINFO => Some("NT_PRPSINFO (prpsinfo structure)"),
+            nt::core::TASKSTRUCT => Some("NT_TASKSTRUCT (task structure)"),
+            nt::core::AUXV => Some("NT_AUXV (auxiliary vector)"),
+            nt::core::PPC_VMX => Some("NT_PPC_VMX (ppc Altivec registers)"),
+            nt::core::X86_XSTATE => Some("NT_X86_XSTATE (x86 XSAVE extended state)"),
+            nt::core::ARM_VFP => Some("NT_ARM_VFP (arm VFP registers)"),
+            nt::core::ARM_TLS => Some("NT_ARM_TLS (AArch TLS registers)"),
+            nt::core::SIGINFO => Some("NT_SIGINFO (siginfo_t data)"),
+            nt::core::FILE => Some("NT_FILE (mapped files)"),
+            nt::core::PRXFPREG => Some("NT_PRXFPREG (user_xfpregs structure)"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn gnu_abi_tag_os_name(os: u32) -> &'static str {
+    match os {
+        0x00 => "Linux",
+        0x01 => "Hurd",
+        0x02 => "Solaris",
+        0x03 => "FreeBSD",
+        0x04 => "NetBSD",
+        0x05 => "Syllable",
+        0x06 => "NaCl",
+        _ => "Unknown",
+    }
+}
+
+/// Names of the bits set in a property of `NT_GNU_PROPERTY_TYPE_0`, see: binutils/readelf.c
+/// decode_x86_feature_1 and friends
+pub fn gnu_property_bit_names(machine: u16, r#type: u32, bits: u32) -> Vec<&'static str> {
+    let names: &[&str] = match (machine, r#type) {
+        (e::machine::X86 | e::machine::X86_64, gnu_property::X86_FEATURE_1_AND) => {
+            &["IBT", "SHSTK", "LAM_U48", "LAM_U57"]
+        }
+        (
+            e::machine::X86 | e::machine::X86_64,
+            gnu_property::X86_FEATURE_2_USED | gnu_property::X86_FEATURE_2_NEEDED,
+        ) => &[
+            "x86", "x87", "MMX", "XMM", "YMM", "ZMM", "FXSR", "XSAVE", "XSAVEOPT", "XSAVEC", "TMM",
+            "MASK",
+        ],
+        (
+            e::machine::X86 | e::machine::X86_64,
+            gnu_property::X86_ISA_1_USED | gnu_property::X86_ISA_1_NEEDED,
+        ) => &["x86-64-baseline", "x86-64-v2", "x86-64-v3", "x86-64-v4"],
+        (e::machine::AARCH64, gnu_property::AARCH64_FEATURE_1_AND) => &["BTI", "PAC", "GCS"],
+        (_, gnu_property::NEEDED_1) => &["indirect external access"],
+        _ => &[],
+    };
+
+    names
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| bits & (1 << bit) != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+pub fn gnu_property_name(machine: u16, r#type: u32) -> Option<&'static str> {
+    match (machine, r#type) {
+        (_, gnu_property::STACK_SIZE) => Some("stack size"),
+        (_, gnu_property::NO_COPY_ON_PROTECTED) => Some("no copy on protected"),
+        (_, gnu_property::NEEDED_1) => Some("1_needed"),
+        (e::machine::AARCH64, gnu_property::AARCH64_FEATURE_1_AND) => Some("AArch64 feature"),
+        (e::machine::X86 | e::machine::X86_64, r#type) => match r#type {
+            gnu_property::X86_FEATURE_1_AND => Some("x86 feature"),
+            gnu_property::X86_FEATURE_2_NEEDED => Some("x86 feature needed"),
+            gnu_property::X86_FEATURE_2_USED => Some("x86 feature used"),
+            gnu_property::X86_ISA_1_NEEDED => Some("x86 ISA needed"),
+            gnu_property::X86_ISA_1_USED => Some("x86 ISA used"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Types of the `NT_AUXV` entries, see: linux/include/uapi/linux/auxvec.h
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod at {
+    pub const           NULL: u64 = 0;
+    pub const          PHDR: u64 = 3;
+    pub const         PAGESZ: u64 = 6;
+    pub const           BASE: u64 = 7;
+    pub const          ENTRY: u64 = 9;
+    pub const       PLATFORM: u64 = 15;
+    pub const         RANDOM: u64 = 25;
+    pub const         EXECFN: u64 = 31;
+    pub const   SYSINFO_EHDR: u64 = 33;
+}
+
+pub fn at_name(r#type: u64) -> &'static str {
+    match r#type {
+        0 => "AT_NULL",
+        1 => "AT_IGNORE",
+        2 => "AT_EXECFD",
+        3 => "AT_PHDR",
+        4 => "AT_PHENT",
+        5 => "AT_PHNUM",
+        6 => "AT_PAGESZ",
+        7 => "AT_BASE",
+        8 => "AT_FLAGS",
+        9 => "AT_ENTRY",
+        10 => "AT_NOTELF",
+        11 => "AT_UID",
+        12 => "AT_EUID",
+        13 => "AT_GID",
+        14 => "AT_EGID",
+        15 => "AT_PLATFORM",
+        16 => "AT_HWCAP",
+        17 => "AT_CLKTCK",
+        23 => "AT_SECURE",
+        24 => "AT_BASE_PLATFORM",
+        25 => "AT_RANDOM",
+        26 => "AT_HWCAP2",
+        27 => "AT_RSEQ_FEATURE_SIZE",
+        28 => "AT_RSEQ_ALIGN",
+        29 => "AT_HWCAP3",
+        30 => "AT_HWCAP4",
+        31 => "AT_EXECFN",
+        32 => "AT_SYSINFO",
+        33 => "AT_SYSINFO_EHDR",
+        51 => "AT_MINSIGSTKSZ",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Linux signal numbers, as on x86, Arm, PowerPC and RISC-V.
+pub fn signal_name(signal: u32) -> &'static str {
+    match signal {
+        1 => "SIGHUP",
+        2 => "SIGINT",
+        3 => "SIGQUIT",
+        4 => "SIGILL",
+        5 => "SIGTRAP",
+        6 => "SIGABRT",
+        7 => "SIGBUS",
+        8 => "SIGFPE",
+        9 => "SIGKILL",
+        10 => "SIGUSR1",
+        11 => "SIGSEGV",
+        12 => "SIGUSR2",
+        13 => "SIGPIPE",
+        14 => "SIGALRM",
+        15 => "SIGTERM",
+        16 => "SIGSTKFLT",
+        17 => "SIGCHLD",
+        18 => "SIGCONT",
+        19 => "SIGSTOP",
+        20 => "SIGTSTP",
+        21 => "SIGTTIN",
+        22 => "SIGTTOU",
+        23 => "SIGURG",
+        24 => "SIGXCPU",
+        25 => "SIGXFSZ",
+        26 => "SIGVTALRM",
+        27 => "SIGPROF",
+        28 => "SIGWINCH",
+        29 => "SIGIO",
+        30 => "SIGPWR",
+        31 => "SIGSYS",
+        _ => "UNKNOWN",
+    }
+}
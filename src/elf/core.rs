@@ -0,0 +1,583 @@
+//! Contents of core dumps, decoded from the notes of their `PT_NOTE` segments.
+//!
+//! The layouts are the ones of Linux, see: linux/include/uapi/linux/elfcore.h
+
+use crate::elf::common::{at, e, nt, p};
+use crate::elf::header::{Error, Header};
+use crate::elf::note::{Note, Notes};
+use crate::elf::reader::{Class, Reader};
+use crate::elf::segment::ProgramHeaders;
+use crate::input::Data;
+
+/// Registers of `elf_gregset_t` in the order the kernel stores them.
+#[rustfmt::skip]
+fn register_names(machine: u16) -> Option<&'static [&'static str]> {
+    Some(match machine {
+        e::machine::X86_64 => &[
+            "r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8", "rax", "rcx",
+            "rdx", "rsi", "rdi", "orig_rax", "rip", "cs", "eflags", "rsp", "ss", "fs_base",
+            "gs_base", "ds", "es", "fs", "gs",
+        ],
+        e::machine::X86 => &[
+            "ebx", "ecx", "edx", "esi", "edi", "ebp", "eax", "ds", "es", "fs", "gs", "orig_eax",
+            "eip", "cs", "eflags", "esp", "ss",
+        ],
+        e::machine::ARM => &[
+            "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "fp", "ip", "sp",
+            "lr", "pc", "cpsr", "orig_r0",
+        ],
+        e::machine::AARCH64 => &[
+            "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12",
+            "x13", "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24",
+            "x25", "x26", "x27", "x28", "x29", "x30", "sp", "pc", "pstate",
+        ],
+        e::machine::PPC | e::machine::PPC64 => &[
+            "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "r11", "r12",
+            "r13", "r14", "r15", "r16", "r17", "r18", "r19", "r20", "r21", "r22", "r23", "r24",
+            "r25", "r26", "r27", "r28", "r29", "r30", "r31", "nip", "msr", "orig_r3", "ctr",
+            "lnk", "xer", "ccr", "softe", "trap", "dar", "dsisr", "result",
+        ],
+        e::machine::RISCV => &[
+            "pc", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3",
+            "a4", "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11",
+            "t3", "t4", "t5", "t6",
+        ],
+        _ => return None,
+    })
+}
+
+/// Name of the program counter in [`register_names`].
+fn pc_name(machine: u16) -> Option<&'static str> {
+    match machine {
+        e::machine::X86_64 => Some("rip"),
+        e::machine::X86 => Some("eip"),
+        e::machine::ARM | e::machine::AARCH64 | e::machine::RISCV => Some("pc"),
+        e::machine::PPC | e::machine::PPC64 => Some("nip"),
+        _ => None,
+    }
+}
+
+/// `NT_PRSTATUS`, the state of a thread.
+#[derive(Debug, Clone, Copy)]
+pub struct PrStatus<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+    machine: u16,
+}
+
+impl<'a> PrStatus<'a> {
+    pub(crate) fn new(desc: &'a [u8], reader: Reader, machine: u16) -> Option<Self> {
+        // Everything up to the registers
+        (desc.len() >= reader.by_class(0x48, 0x70)).then_some(Self {
+            desc,
+            reader,
+            machine,
+        })
+    }
+
+    /// Signal the thread was stopped by, 0 for the ones that were only dumped along.
+    pub fn signal(&self) -> u32 {
+        self.reader.u16(&self.desc[0x0C..]) as u32
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.reader
+            .u32(&self.desc[self.reader.by_class(0x18, 0x20)..])
+    }
+
+    pub fn ppid(&self) -> u32 {
+        self.reader
+            .u32(&self.desc[self.reader.by_class(0x1C, 0x24)..])
+    }
+
+    pub fn pgrp(&self) -> u32 {
+        self.reader
+            .u32(&self.desc[self.reader.by_class(0x20, 0x28)..])
+    }
+
+    pub fn sid(&self) -> u32 {
+        self.reader
+            .u32(&self.desc[self.reader.by_class(0x24, 0x2C)..])
+    }
+
+    /// General purpose registers by name, `None` for the machines whose layout is not known or
+    /// when they do not fit in the note.
+    pub fn registers(&self) -> Option<Vec<(&'static str, u64)>> {
+        let names = register_names(self.machine)?;
+        let word = self.reader.wordsize();
+        let start = self.reader.by_class(0x48, 0x70);
+        let registers = self.desc.get(start..start + names.len() * word)?;
+
+        Some(
+            names
+                .iter()
+                .zip(registers.chunks_exact(word))
+                .map(|(name, value)| (*name, self.reader.word(value)))
+                .collect(),
+        )
+    }
+
+    /// Program counter, where the thread was stopped.
+    pub fn pc(&self) -> Option<u64> {
+        let pc = pc_name(self.machine)?;
+
+        self.registers()?
+            .into_iter()
+            .find_map(|(name, value)| (name == pc).then_some(value))
+    }
+}
+
+/// `NT_PRPSINFO`, the process that was dumped.
+#[derive(Debug, Clone, Copy)]
+pub struct PrPsInfo<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+}
+
+impl<'a> PrPsInfo<'a> {
+    pub(crate) fn new(desc: &'a [u8], reader: Reader) -> Option<Self> {
+        // Only exactly 0x7C bytes are the 16-bit ids layout, the other one takes 0x80
+        let fits = match reader.class() {
+            Class::Elf32 => desc.len() == 0x7C || desc.len() >= 0x80,
+            Class::Elf64 => desc.len() >= 0x88,
+        };
+
+        fits.then_some(Self { desc, reader })
+    }
+
+    /// Whether `uid_t` is 16 bits, as on i386 and 32-bit Arm.
+    fn uid16(&self) -> bool {
+        self.reader.class() == Class::Elf32 && self.desc.len() == 0x7C
+    }
+
+    /// Offset of `pr_pid`, after the user and group ids.
+    fn ids(&self) -> usize {
+        match self.uid16() {
+            true => 0x0C,
+            false => self.reader.by_class(0x10, 0x18),
+        }
+    }
+
+    /// One of `RSDTZW`, for running, sleeping, disk sleep, stopped, zombie and paging.
+    pub fn state(&self) -> char {
+        self.desc[0x01] as char
+    }
+
+    pub fn nice(&self) -> i8 {
+        self.desc[0x03] as i8
+    }
+
+    pub fn flags(&self) -> u64 {
+        self.reader
+            .word(&self.desc[self.reader.by_class(0x04, 0x08)..])
+    }
+
+    pub fn uid(&self) -> u32 {
+        match self.uid16() {
+            true => self.reader.u16(&self.desc[0x08..]) as u32,
+            false => self
+                .reader
+                .u32(&self.desc[self.reader.by_class(0x08, 0x10)..]),
+        }
+    }
+
+    pub fn gid(&self) -> u32 {
+        match self.uid16() {
+            true => self.reader.u16(&self.desc[0x0A..]) as u32,
+            false => self
+                .reader
+                .u32(&self.desc[self.reader.by_class(0x0C, 0x14)..]),
+        }
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.reader.u32(&self.desc[self.ids()..])
+    }
+
+    pub fn ppid(&self) -> u32 {
+        self.reader.u32(&self.desc[self.ids() + 0x04..])
+    }
+
+    pub fn pgrp(&self) -> u32 {
+        self.reader.u32(&self.desc[self.ids() + 0x08..])
+    }
+
+    pub fn sid(&self) -> u32 {
+        self.reader.u32(&self.desc[self.ids() + 0x0C..])
+    }
+
+    /// Name of the executable, cut to 15 characters.
+    pub fn fname(&self) -> Option<&'a str> {
+        let start = self.ids() + 0x10;
+        string(&self.desc[start..start + 0x10])
+    }
+
+    /// Command line, with the arguments separated by spaces and cut to 79 characters.
+    pub fn psargs(&self) -> Option<&'a str> {
+        let start = self.ids() + 0x20;
+        string(&self.desc[start..start + 0x50]).map(str::trim_end)
+    }
+}
+
+/// The bytes up to the first NUL.
+fn string(bytes: &[u8]) -> Option<&str> {
+    let end = bytes.iter().position(|&b| b == 0x00).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..end]).ok()
+}
+
+/// `NT_SIGINFO`, the `siginfo_t` of the signal that killed the process.
+#[derive(Debug, Clone, Copy)]
+pub struct SigInfo<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+}
+
+impl<'a> SigInfo<'a> {
+    pub(crate) fn new(desc: &'a [u8], reader: Reader) -> Option<Self> {
+        (desc.len() >= reader.by_class(0x10, 0x18)).then_some(Self { desc, reader })
+    }
+
+    pub fn signo(&self) -> u32 {
+        self.reader.u32(&self.desc[0x00..])
+    }
+
+    pub fn errno(&self) -> i32 {
+        self.reader.u32(&self.desc[0x04..]) as i32
+    }
+
+    pub fn code(&self) -> i32 {
+        self.reader.u32(&self.desc[0x08..]) as i32
+    }
+
+    /// Faulting address of `SIGILL`, `SIGFPE`, `SIGSEGV`, `SIGBUS` and `SIGTRAP`.
+    pub fn address(&self) -> Option<u64> {
+        matches!(self.signo(), 4 | 5 | 7 | 8 | 11).then(|| {
+            self.reader
+                .word(&self.desc[self.reader.by_class(0x0C, 0x10)..])
+        })
+    }
+
+    /// Name of `si_code`, for the codes shared by every signal and the ones of `SIGSEGV` and
+    /// `SIGBUS`.
+    pub fn code_name(&self) -> Option<&'static str> {
+        match (self.signo(), self.code()) {
+            (_, 0) => Some("SI_USER"),
+            (_, 0x80) => Some("SI_KERNEL"),
+            (_, -1) => Some("SI_QUEUE"),
+            (_, -6) => Some("SI_TKILL"),
+            (11, 1) => Some("SEGV_MAPERR"),
+            (11, 2) => Some("SEGV_ACCERR"),
+            (11, 3) => Some("SEGV_BNDERR"),
+            (11, 4) => Some("SEGV_PKUERR"),
+            (7, 1) => Some("BUS_ADRALN"),
+            (7, 2) => Some("BUS_ADRERR"),
+            (7, 3) => Some("BUS_OBJERR"),
+            _ => None,
+        }
+    }
+}
+
+/// `NT_AUXV`, the auxiliary vector the kernel gave the process as `(type, value)` pairs.
+#[derive(Debug, Clone)]
+pub struct Auxv<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+}
+
+impl<'a> Auxv<'a> {
+    pub(crate) fn new(desc: &'a [u8], reader: Reader) -> Self {
+        Self { desc, reader }
+    }
+}
+
+impl Iterator for Auxv<'_> {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let word = self.reader.wordsize();
+        let r#type = self.reader.word(self.desc.get(..word)?);
+        let value = self.reader.word(self.desc.get(word..word * 2)?);
+
+        self.desc = &self.desc[word * 2..];
+
+        (r#type != at::NULL).then_some((r#type, value))
+    }
+}
+
+/// A file mapped in the memory of the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappedFile<'a> {
+    pub start: u64,
+    pub end: u64,
+    /// Offset in the file the mapping starts at, in bytes.
+    pub offset: u64,
+    pub path: Option<&'a str>,
+}
+
+/// `NT_FILE`, the files mapped in the memory of the process.
+#[derive(Debug, Clone)]
+pub struct MappedFiles<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+    page_size: u64,
+    count: usize,
+    index: usize,
+    paths: std::slice::Split<'a, u8, fn(&u8) -> bool>,
+}
+
+impl<'a> MappedFiles<'a> {
+    pub(crate) fn new(desc: &'a [u8], reader: Reader) -> Option<Self> {
+        let word = reader.wordsize();
+        let count = usize::try_from(reader.word(desc.get(..word)?)).ok()?;
+        let page_size = reader.word(desc.get(word..word * 2)?);
+        let paths = desc.get(count.checked_mul(word * 3)?.checked_add(word * 2)?..)?;
+
+        Some(Self {
+            desc,
+            reader,
+            page_size,
+            count,
+            index: 0,
+            paths: paths.split((|&b| b == 0x00) as fn(&u8) -> bool),
+        })
+    }
+
+    /// Unit of the offsets stored in the note.
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+}
+
+impl<'a> Iterator for MappedFiles<'a> {
+    type Item = MappedFile<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.count {
+            return None;
+        }
+
+        let word = self.reader.wordsize();
+        let start = word * (2 + self.index * 3);
+        let entry = &self.desc[start..start + word * 3];
+        self.index += 1;
+
+        Some(MappedFile {
+            start: self.reader.word(entry),
+            end: self.reader.word(&entry[word..]),
+            offset: self
+                .reader
+                .word(&entry[word * 2..])
+                .wrapping_mul(self.page_size),
+            path: self
+                .paths
+                .next()
+                .and_then(|path| std::str::from_utf8(path).ok()),
+        })
+    }
+}
+
+impl<'a> Note<'a> {
+    fn is_core(&self, r#type: u32) -> bool {
+        matches!(self.name(), Some("CORE" | "LINUX")) && self.r#type() == r#type
+    }
+
+    pub fn prstatus(&self) -> Option<PrStatus<'a>> {
+        if !self.is_core(nt::core::PRSTATUS) {
+            return None;
+        }
+
+        PrStatus::new(self.desc(), self.reader(), self.machine())
+    }
+
+    pub fn prpsinfo(&self) -> Option<PrPsInfo<'a>> {
+        if !self.is_core(nt::core::PRPSINFO) {
+            return None;
+        }
+
+        PrPsInfo::new(self.desc(), self.reader())
+    }
+
+    pub fn siginfo(&self) -> Option<SigInfo<'a>> {
+        if !self.is_core(nt::core::SIGINFO) {
+            return None;
+        }
+
+        SigInfo::new(self.desc(), self.reader())
+    }
+
+    pub fn auxv(&self) -> Option<Auxv<'a>> {
+        self.is_core(nt::core::AUXV)
+            .then(|| Auxv::new(self.desc(), self.reader()))
+    }
+
+    pub fn mapped_files(&self) -> Option<MappedFiles<'a>> {
+        if !self.is_core(nt::core::FILE) {
+            return None;
+        }
+
+        MappedFiles::new(self.desc(), self.reader())
+    }
+}
+
+/// What a core dump tells about the process it was taken of.
+#[derive(Debug, Clone)]
+pub struct Core<'a> {
+    process: Option<PrPsInfo<'a>>,
+    signal: Option<SigInfo<'a>>,
+    threads: Vec<PrStatus<'a>>,
+    auxv: Option<Auxv<'a>>,
+    files: Option<MappedFiles<'a>>,
+}
+
+impl<'a> Core<'a> {
+    /// Reads the notes of the `PT_NOTE` segments of the core dump `file`.
+    pub fn new(file: impl Into<Data<'a>>, header: &Header<'a>) -> Result<Self, Error> {
+        let file = file.into();
+
+        if header.r#type() != e::r#type::CORE {
+            return Err(Error::Core("not a core dump".into()));
+        }
+
+        let mut core = Self {
+            process: None,
+            signal: None,
+            threads: Vec::new(),
+            auxv: None,
+            files: None,
+        };
+
+        for segment in ProgramHeaders::new(file, header)? {
+            if segment.r#type() != p::r#type::NOTE {
+                continue;
+            }
+
+            for note in Notes::from_segment(file, header, &segment)? {
+                if let Some(status) = note.prstatus() {
+                    core.threads.push(status);
+                }
+
+                core.process = core.process.or(note.prpsinfo());
+                core.signal = core.signal.or(note.siginfo());
+                core.auxv = core.auxv.or(note.auxv());
+                core.files = core.files.or(note.mapped_files());
+            }
+        }
+
+        Ok(core)
+    }
+
+    pub fn process(&self) -> Option<PrPsInfo<'a>> {
+        self.process
+    }
+
+    /// The signal that killed the process.
+    pub fn signal(&self) -> Option<SigInfo<'a>> {
+        self.signal
+    }
+
+    /// Every thread, the one that received the signal first.
+    pub fn threads(&self) -> &[PrStatus<'a>] {
+        &self.threads
+    }
+
+    pub fn auxv(&self) -> Option<Auxv<'a>> {
+        self.auxv.clone()
+    }
+
+    pub fn files(&self) -> Option<MappedFiles<'a>> {
+        self.files.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Core, MappedFile, PrPsInfo, PrStatus};
+    use crate::elf::common::{at, e};
+    use crate::elf::header::Header;
+    use crate::elf::reader::{Class, Endian, Reader};
+
+    #[test]
+    fn core_of_crash() {
+        let file = include_bytes!("../../test/core");
+        let header = Header::parse(file).unwrap();
+        let core = Core::new(file, &header).unwrap();
+
+        let process = core.process().unwrap();
+        assert_eq!(process.fname(), Some("crash"));
+        assert_eq!(process.psargs(), Some("./crash a b"));
+
+        // `*(int *)0x10 = 1`
+        let signal = core.signal().unwrap();
+        assert_eq!(signal.signo(), 11);
+        assert_eq!(signal.code_name(), Some("SEGV_MAPERR"));
+        assert_eq!(signal.address(), Some(0x10));
+
+        let threads = core.threads();
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].signal(), 11);
+        assert_eq!(threads[0].pid(), process.pid());
+        assert_eq!(threads[1].pid(), process.pid() + 1);
+
+        let registers = threads[0].registers().unwrap();
+        assert_eq!(registers.len(), 27);
+        assert_eq!(registers[16].0, "rip");
+        assert_eq!(threads[0].pc(), Some(registers[16].1));
+
+        let auxv: Vec<_> = core.auxv().unwrap().collect();
+        assert!(auxv.contains(&(at::PAGESZ, 0x1000)));
+
+        let files: Vec<MappedFile> = core.files().unwrap().collect();
+        assert!(files[0].path.unwrap().ends_with("/crash"));
+        assert!(files
+            .iter()
+            .any(|file| file.path.is_some_and(|path| path.contains("libc.so"))));
+        assert!(files.iter().all(|file| file.start < file.end));
+
+        assert!(Core::new(
+            include_bytes!("../../test/exe"),
+            &Header::parse(include_bytes!("../../test/exe")).unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn prstatus_of_i386() {
+        let reader = Reader::new(Class::Elf32, Endian::Little);
+
+        // `struct elf_prstatus` of i386 is 144 bytes, with the 17 registers at 72
+        let mut desc = vec![0; 0x90];
+        desc[0x0C] = 6;
+        desc[0x18..0x1C].copy_from_slice(&42u32.to_le_bytes());
+        desc[0x48 + 12 * 4..0x48 + 13 * 4].copy_from_slice(&0x8049000u32.to_le_bytes());
+
+        let status = PrStatus::new(&desc, reader, e::machine::X86).unwrap();
+        assert_eq!(status.signal(), 6);
+        assert_eq!(status.pid(), 42);
+        assert_eq!(status.registers().unwrap().len(), 17);
+        assert_eq!(status.pc(), Some(0x8049000));
+
+        assert!(PrStatus::new(&desc[..0x40], reader, e::machine::X86).is_none());
+        assert!(PrStatus::new(&desc, reader, 0x0008)
+            .unwrap()
+            .registers()
+            .is_none());
+    }
+
+    #[test]
+    fn prpsinfo_of_i386() {
+        let reader = Reader::new(Class::Elf32, Endian::Little);
+
+        // 16-bit ids at exactly 0x7C bytes, 32-bit ones from 0x80
+        let mut desc = vec![0; 0x80];
+        desc[0x0C..0x10].copy_from_slice(&42u32.to_le_bytes());
+        desc[0x1C..0x20].copy_from_slice(b"sh\0\0");
+        assert_eq!(PrPsInfo::new(&desc[..0x7C], reader).unwrap().pid(), 42);
+        assert_eq!(
+            PrPsInfo::new(&desc[..0x7C], reader).unwrap().fname(),
+            Some("sh")
+        );
+
+        assert!(PrPsInfo::new(&desc[..0x7D], reader).is_none());
+        assert_eq!(PrPsInfo::new(&desc, reader).unwrap().psargs(), Some(""));
+    }
+}
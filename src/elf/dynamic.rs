@@ -0,0 +1,319 @@
+use crate::elf::common::{self, dt, p, sh};
+use crate::elf::header::{Error, Header};
+use crate::elf::reader::Reader;
+use crate::elf::section::{SectionHeaders, StringTable};
+use crate::elf::segment::ProgramHeaders;
+use crate::input::Data;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::slice::ChunksExact;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicEntry<'a> {
+    entry: &'a [u8],
+    reader: Reader,
+    string: Option<&'a str>,
+}
+
+impl<'a> From<(&'a [u8], Reader, Option<&'a str>)> for DynamicEntry<'a> {
+    fn from((entry, reader, string): (&'a [u8], Reader, Option<&'a str>)) -> Self {
+        Self {
+            entry,
+            reader,
+            string,
+        }
+    }
+}
+
+impl Deref for DynamicEntry<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.entry
+    }
+}
+
+impl Display for DynamicEntry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:#0width$x} {:<pad$} ",
+            self.tag(),
+            format!("({})", common::d_tag_name(self.tag())),
+            width = self.reader.by_class(10, 18),
+            pad = self.reader.by_class(28, 20)
+        )?;
+
+        let string = self.string().unwrap_or("<corrupt>");
+
+        match self.tag() {
+            dt::NEEDED => write!(f, "Shared library: [{}]", string),
+            dt::SONAME => write!(f, "Library soname: [{}]", string),
+            dt::RPATH => write!(f, "Library rpath: [{}]", string),
+            dt::RUNPATH => write!(f, "Library runpath: [{}]", string),
+            dt::AUXILIARY | dt::FILTER | dt::CONFIG | dt::DEPAUDIT | dt::AUDIT => {
+                write!(f, "{}", string)
+            }
+            dt::FLAGS => write!(f, "{}", common::d_flags_names(self.value()).join(" ")),
+            dt::FLAGS_1 => write!(
+                f,
+                "Flags: {}",
+                common::d_flags_1_names(self.value()).join(" ")
+            ),
+            dt::PLTREL => match self.value() {
+                dt::REL => write!(f, "REL"),
+                dt::RELA => write!(f, "RELA"),
+                value => write!(f, "{:#x}", value),
+            },
+            dt::PLTRELSZ
+            | dt::RELASZ
+            | dt::RELAENT
+            | dt::STRSZ
+            | dt::SYMENT
+            | dt::RELSZ
+            | dt::RELENT
+            | dt::INIT_ARRAYSZ
+            | dt::FINI_ARRAYSZ
+            | dt::PREINIT_ARRAYSZ
+            | dt::RELRSZ
+            | dt::RELRENT
+            | dt::SYMINSZ
+            | dt::SYMINENT
+            | dt::MOVEENT
+            | dt::MOVESZ
+            | dt::PLTPADSZ
+            | dt::GNU_CONFLICTSZ
+            | dt::GNU_LIBLISTSZ => write!(f, "{} (bytes)", self.value()),
+            dt::VERDEFNUM | dt::VERNEEDNUM | dt::RELACOUNT | dt::RELCOUNT => {
+                write!(f, "{}", self.value())
+            }
+            _ => write!(f, "{:#x}", self.value()),
+        }
+    }
+}
+
+impl<'a> DynamicEntry<'a> {
+    /// Size of `Elf32_Dyn`/`Elf64_Dyn` for the class in `reader`.
+    pub fn entsize_of(reader: &Reader) -> usize {
+        reader.by_class(0x08, 0x10)
+    }
+
+    /// Whether the `d_val` of `tag` is an offset into the `DT_STRTAB` string table.
+    pub fn is_string(tag: u64) -> bool {
+        matches!(
+            tag,
+            dt::NEEDED
+                | dt::SONAME
+                | dt::RPATH
+                | dt::RUNPATH
+                | dt::AUXILIARY
+                | dt::FILTER
+                | dt::CONFIG
+                | dt::DEPAUDIT
+                | dt::AUDIT
+        )
+    }
+
+    pub fn tag(&self) -> u64 {
+        self.reader.word(&self[0x00..])
+    }
+
+    /// `d_val` or `d_ptr`, they share the same storage.
+    pub fn value(&self) -> u64 {
+        let start = self.reader.by_class(0x04, 0x08);
+
+        self.reader.word(&self[start..])
+    }
+
+    /// Value resolved through `DT_STRTAB` for the string valued tags, `None` otherwise or if the
+    /// offset is not valid.
+    pub fn string(&self) -> Option<&'a str> {
+        self.string
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Dynamic<'a> {
+    entries: ChunksExact<'a, u8>,
+    reader: Reader,
+    strtab: StringTable<'a>,
+    offset: u64,
+    done: bool,
+}
+
+impl<'a> Dynamic<'a> {
+    /// Entries of `PT_DYNAMIC`, or of the `SHT_DYNAMIC` section for files without program
+    /// headers. `None` for statically linked files.
+    pub fn new(file: impl Into<Data<'a>>, header: &Header<'a>) -> Result<Option<Self>, Error> {
+        let file = file.into();
+
+        let reader = header.reader();
+        let segments = ProgramHeaders::new(file, header)?;
+        let sections = SectionHeaders::new(file, header)?;
+
+        let (offset, data, strtab) = match segments
+            .clone()
+            .find(|segment| segment.r#type() == p::r#type::DYNAMIC)
+        {
+            Some(segment) => (segment.offset(), segment.data(file)?, None),
+            None => match sections
+                .clone()
+                .find(|section| section.r#type() == sh::r#type::DYNAMIC)
+            {
+                Some(section) => {
+                    let strtab = sections
+                        .get(section.link() as usize)
+                        .filter(|strtab| strtab.r#type() == sh::r#type::STRTAB)
+                        .map(|strtab| strtab.data(file))
+                        .transpose()?;
+
+                    (section.offset(), section.data(file)?, strtab)
+                }
+                None => return Ok(None),
+            },
+        };
+
+        let mut dynamic = Self {
+            entries: data.chunks_exact(DynamicEntry::entsize_of(&reader)),
+            reader,
+            strtab: StringTable::default(),
+            offset,
+            done: false,
+        };
+
+        dynamic.strtab = match strtab {
+            Some(strtab) => StringTable(strtab),
+            None => {
+                let address = dynamic.clone().find(|entry| entry.tag() == dt::STRTAB);
+                let size = dynamic.clone().find(|entry| entry.tag() == dt::STRSZ);
+
+                match address.zip(size) {
+                    Some((address, size)) => {
+                        let strtab = segments
+                            .translate(address.value())
+                            .and_then(|start| usize::try_from(start).ok())
+                            .zip(usize::try_from(size.value()).ok())
+                            .and_then(|(start, size)| file.get(start..start.checked_add(size)?))
+                            .ok_or_else(|| {
+                                Error::Dynamic("DT_STRTAB is out of the file bounds".into())
+                            })?;
+
+                        StringTable(strtab)
+                    }
+                    None => StringTable::default(),
+                }
+            }
+        };
+
+        Ok(Some(dynamic))
+    }
+
+    /// File offset of the dynamic section.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    fn strings(&self, tag: u64) -> impl Iterator<Item = &'a str> {
+        self.clone()
+            .filter(move |entry| entry.tag() == tag)
+            .filter_map(|entry| entry.string())
+    }
+
+    /// Libraries listed by `DT_NEEDED`, in load order.
+    pub fn needed(&self) -> impl Iterator<Item = &'a str> {
+        self.strings(dt::NEEDED)
+    }
+
+    pub fn soname(&self) -> Option<&'a str> {
+        self.strings(dt::SONAME).next()
+    }
+
+    pub fn rpath(&self) -> Option<&'a str> {
+        self.strings(dt::RPATH).next()
+    }
+
+    pub fn runpath(&self) -> Option<&'a str> {
+        self.strings(dt::RUNPATH).next()
+    }
+}
+
+impl<'a> Iterator for Dynamic<'a> {
+    type Item = DynamicEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let entry = self.entries.next()?;
+
+        let mut entry: DynamicEntry = (entry, self.reader, None).into();
+        if DynamicEntry::is_string(entry.tag()) {
+            entry.string = usize::try_from(entry.value())
+                .ok()
+                .and_then(|offset| self.strtab.get(offset));
+        }
+
+        // Anything past the first DT_NULL is padding left by the linker
+        self.done = entry.tag() == dt::NULL;
+
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Dynamic;
+    use crate::elf::common::dt;
+    use crate::elf::header::Header;
+
+    #[test]
+    fn dynamic_of_lib_so() {
+        let file = include_bytes!("../../test/lib.so");
+
+        let header = Header::parse(file).unwrap();
+
+        let dynamic = Dynamic::new(file, &header).unwrap().unwrap();
+
+        assert!(dynamic.needed().eq(["libc.so.6"]));
+        assert_eq!(dynamic.soname(), None);
+        assert_eq!(dynamic.runpath(), None);
+        assert_eq!(dynamic.clone().last().unwrap().tag(), dt::NULL);
+        assert_eq!(
+            dynamic
+                .clone()
+                .find(|entry| entry.tag() == dt::PLTREL)
+                .unwrap()
+                .value(),
+            dt::RELA
+        );
+    }
+
+    #[test]
+    fn no_dynamic_in_lib_o() {
+        let file = include_bytes!("../../test/lib.o");
+
+        let header = Header::parse(file).unwrap();
+
+        assert!(Dynamic::new(file, &header).unwrap().is_none());
+    }
+
+    #[test]
+    fn dynamic_of_i386_so() {
+        let file = include_bytes!("../../test/i386.so");
+        let header = Header::parse(file).unwrap();
+
+        let dynamic = Dynamic::new(file, &header).unwrap().unwrap();
+
+        assert_eq!(dynamic.offset(), 0x2F74);
+        assert_eq!(dynamic.clone().count(), 11);
+        assert_eq!(
+            dynamic
+                .clone()
+                .find(|entry| entry.tag() == dt::PLTREL)
+                .unwrap()
+                .value(),
+            dt::REL
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::elf::common::{self, ei};
+use crate::elf::common::{self, e, ei};
+use crate::elf::reader::{Class, Endian, Reader};
+use crate::input::Data;
 use std::error;
 use std::fmt::Display;
 use std::ops::Deref;
@@ -7,6 +9,14 @@ use std::ops::Deref;
 pub enum Error {
     Ident(String),
     Header(String),
+    Segment(String),
+    Section(String),
+    Symbol(String),
+    Relocation(String),
+    Dynamic(String),
+    Version(String),
+    Core(String),
+    Truncated { expected: usize, found: usize },
 }
 
 impl error::Error for Error {}
@@ -20,11 +30,39 @@ impl Display for Error {
             Self::Header(err) => {
                 write!(f, "malformed header: {}", err)
             }
+            Self::Segment(err) => {
+                write!(f, "malformed program header: {}", err)
+            }
+            Self::Section(err) => {
+                write!(f, "malformed section header: {}", err)
+            }
+            Self::Symbol(err) => {
+                write!(f, "malformed symbol table: {}", err)
+            }
+            Self::Relocation(err) => {
+                write!(f, "malformed relocation table: {}", err)
+            }
+            Self::Dynamic(err) => {
+                write!(f, "malformed dynamic section: {}", err)
+            }
+            Self::Version(err) => {
+                write!(f, "malformed symbol version table: {}", err)
+            }
+            Self::Core(err) => {
+                write!(f, "malformed core dump: {}", err)
+            }
+            Self::Truncated { expected, found } => {
+                write!(
+                    f,
+                    "truncated header: expected {} bytes but found {}",
+                    expected, found
+                )
+            }
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Ident<'a> {
     ident: &'a [u8; common::NIDENT],
 }
@@ -86,39 +124,39 @@ impl Deref for Ident<'_> {
 // TODO: Refator this
 impl Display for Ident<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "     |  Header   |\n")?;
-        write!(
+        writeln!(f, "     |  Header   |")?;
+        writeln!(
             f,
-            "0x00 | {:02X}{:02X} {:02X}{:02X} |   magic: .ELF\n",
+            "0x00 | {:02X}{:02X} {:02X}{:02X} |   magic: .ELF",
             self[0x00], self[0x01], self[0x02], self[0x03]
         )?;
 
-        write!(
+        writeln!(
             f,
-            "{2:#04X} |        {1:02X} |   class: {0}-bits\n",
+            "{2:#04X} |        {1:02X} |   class: {0}-bits",
             self.arch(),
             self.class(),
             ei::CLASS
         )?;
 
-        write!(
+        writeln!(
             f,
-            "{2:#04X} |        {1:02X} |    data: {0}\n",
+            "{2:#04X} |        {1:02X} |    data: {0}",
             common::ei_data_name(self.endianness()),
             self.endianness(),
             ei::DATA,
         )?;
 
-        write!(
+        writeln!(
             f,
-            "{1:#04X} |        {0:02X} | version: {0}\n",
+            "{1:#04X} |        {0:02X} | version: {0}",
             self.version(),
             ei::VERSION,
         )?;
 
-        write!(
+        writeln!(
             f,
-            "{2:#04X} |        {1:02X} |     abi: {0}\n",
+            "{2:#04X} |        {1:02X} |     abi: {0}",
             common::ei_abi_name(self.abi()),
             self.abi(),
             ei::OSABI,
@@ -134,12 +172,9 @@ impl Display for Ident<'_> {
 }
 
 impl Ident<'_> {
+    /// Size of the header, `e_ehsize` for files that are not lying about it.
     pub fn arch(&self) -> usize {
-        match self[ei::CLASS] {
-            0x01 => 0x34,
-            0x02 => 0x40,
-            _ => unreachable!(),
-        }
+        self.reader().by_class(0x34, 0x40)
     }
 
     pub fn class(&self) -> u8 {
@@ -161,20 +196,41 @@ impl Ident<'_> {
     pub fn abi_version(&self) -> u8 {
         self[ei::ABIVERSION]
     }
+
+    /// Reader for the class and byte order of the file.
+    pub fn reader(&self) -> Reader {
+        let class = match self.class() {
+            0x01 => Class::Elf32,
+            0x02 => Class::Elf64,
+            _ => unreachable!(),
+        };
+
+        let endian = match self.endianness() {
+            0x01 => Endian::Little,
+            0x02 => Endian::Big,
+            _ => unreachable!(),
+        };
+
+        Reader::new(class, endian)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Header<'a> {
-    header: *const u8,
+    header: &'a [u8],
     ident: Ident<'a>,
 }
 
-impl<'a> From<(&[u8], Ident<'a>)> for Header<'a> {
-    fn from((header, ident): (&[u8], Ident<'a>)) -> Self {
-        Self {
-            header: header.as_ptr(),
-            ident,
-        }
+impl<'a> TryFrom<(&'a [u8], Ident<'a>)> for Header<'a> {
+    type Error = self::Error;
+
+    fn try_from((header, ident): (&'a [u8], Ident<'a>)) -> Result<Self, Self::Error> {
+        let header = header.get(..ident.arch()).ok_or(Error::Truncated {
+            expected: ident.arch(),
+            found: header.len(),
+        })?;
+
+        Ok(Self { header, ident })
     }
 }
 
@@ -182,218 +238,118 @@ impl Deref for Header<'_> {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
-        unsafe { std::slice::from_raw_parts(self.header, self.ident.arch()) }
+        self.header
     }
 }
 
 impl Display for Header<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "type={:#04X}\n", self.r#type())?;
-        write!(f, "machine={:#04X}\n", self.machine())?;
-        write!(f, "machine={}\n", common::machine_name(self.machine()))?;
-        write!(f, "version={}\n", self.version())?;
-        write!(f, "entry={:#08X}\n", self.entry())?;
-        write!(f, "phoff={:#08X}\n", self.phoff())?;
-        write!(f, "shoff={:#08X}\n", self.shoff())?;
-        write!(f, "flags={:}\n", self.flags())?;
-        write!(f, "ehsize={:}\n", self.ehsize())?;
-        write!(f, "phentzise={:}\n", self.phentzise())?;
-        write!(f, "phnum={:}\n", self.phnum())?;
-        write!(f, "shentsize={:}\n", self.shentsize())?;
-        write!(f, "shnum={:}\n", self.shnum())?;
-        write!(f, "shstrndx={:}\n", self.shstrndx())
+        writeln!(f, "type={:#04X}", self.r#type())?;
+        writeln!(f, "type={}", common::type_name(self.r#type()))?;
+        writeln!(f, "machine={:#04X}", self.machine())?;
+        writeln!(f, "machine={}", common::machine_name(self.machine()))?;
+        writeln!(f, "version={}", self.version())?;
+        writeln!(f, "entry={:#08X}", self.entry())?;
+        writeln!(f, "phoff={:#08X}", self.phoff())?;
+        writeln!(f, "shoff={:#08X}", self.shoff())?;
+        writeln!(f, "flags={:}", self.flags())?;
+        writeln!(f, "ehsize={:}", self.ehsize())?;
+        writeln!(f, "phentzise={:}", self.phentzise())?;
+        writeln!(f, "phnum={:}", self.phnum())?;
+        writeln!(f, "shentsize={:}", self.shentsize())?;
+        writeln!(f, "shnum={:}", self.shnum())?;
+        writeln!(f, "shstrndx={:}", self.shstrndx())
     }
 }
 
-impl Header<'_> {
-    fn r#type(&self) -> u16 {
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[0x10..=0x11].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[0x10..=0x11].try_into().unwrap())
-        }
-    }
+impl<'a> Header<'a> {
+    /// Validates the ident at the start of `file` and borrows the header that follows it.
+    pub fn parse(file: impl Into<Data<'a>>) -> Result<Self, Error> {
+        let file = file.into();
 
-    fn machine(&self) -> u16 {
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[0x12..=0x13].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[0x12..=0x13].try_into().unwrap())
-        }
-    }
+        let raw = file
+            .get(0..common::NIDENT)
+            .and_then(|raw| raw.first_chunk())
+            .ok_or(Error::Truncated {
+                expected: common::NIDENT,
+                found: file.len(),
+            })?;
 
-    fn version(&self) -> u32 {
-        if self.ident.endianness() == 0x01 {
-            u32::from_le_bytes(self[0x14..=0x17].try_into().unwrap())
-        } else {
-            u32::from_be_bytes(self[0x14..=0x17].try_into().unwrap())
-        }
-    }
+        let ident: Ident = RawIdent(raw).try_into()?;
 
-    fn entry(&self) -> u64 {
-        if self.ident.endianness() == 0x01 {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x18..=0x1B].try_into().unwrap())
-            } else {
-                u64::from_le_bytes(self[0x18..=0x1F].try_into().unwrap())
-            }
-        } else {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x18..=0x1B].try_into().unwrap())
-            } else {
-                u64::from_be_bytes(self[0x18..=0x1F].try_into().unwrap())
-            }
-        }
-    }
+        // Only what is there is borrowed so the size check below reports the truncation
+        let header = file
+            .get(0..ident.arch().min(file.len()))
+            .unwrap_or_default();
 
-    fn phoff(&self) -> u64 {
-        if self.ident.endianness() == 0x01 {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x1C..=0x1F].try_into().unwrap())
-            } else {
-                u64::from_le_bytes(self[0x20..=0x27].try_into().unwrap())
-            }
-        } else {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x1C..=0x1F].try_into().unwrap())
-            } else {
-                u64::from_be_bytes(self[0x20..=0x27].try_into().unwrap())
-            }
-        }
+        (header, ident).try_into()
     }
 
-    fn shoff(&self) -> u64 {
-        if self.ident.endianness() == 0x01 {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x20..=0x23].try_into().unwrap())
-            } else {
-                u64::from_le_bytes(self[0x28..=0x2F].try_into().unwrap())
-            }
-        } else {
-            if self.ident.class() == 0x01 {
-                u64::from_le_bytes(self[0x20..=0x23].try_into().unwrap())
-            } else {
-                u64::from_be_bytes(self[0x28..=0x2F].try_into().unwrap())
-            }
-        }
+    pub fn ident(&self) -> Ident<'a> {
+        self.ident
     }
 
-    fn flags(&self) -> u32 {
-        let start = match self.ident.class() {
-            0x01 => 0x24,
-            0x02 => 0x30,
-            _ => unreachable!(),
-        };
-
-        let end = start + 0x04;
-
-        if self.ident.endianness() == 0x01 {
-            u32::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u32::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    /// Reader for the class and byte order of the file.
+    pub fn reader(&self) -> Reader {
+        self.ident.reader()
     }
 
-    fn ehsize(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x28,
-            0x02 => 0x34,
-            _ => unreachable!(),
-        };
-
-        let end = start + 0x02;
-
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn r#type(&self) -> u16 {
+        self.reader().u16(&self[e::TYPE..])
     }
 
-    fn phentzise(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x2A,
-            0x02 => 0x36,
-            _ => unreachable!(),
-        };
-
-        let end = start + 0x02;
-
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn machine(&self) -> u16 {
+        self.reader().u16(&self[e::MACHINE..])
     }
 
-    fn phnum(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x2C,
-            0x02 => 0x38,
-            _ => unreachable!(),
-        };
-
-        let end = start + 0x02;
+    pub fn version(&self) -> u32 {
+        self.reader().u32(&self[e::VERSION..])
+    }
 
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn entry(&self) -> u64 {
+        self.reader().word(&self[e::ENTRY..])
     }
 
-    fn shentsize(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x2E,
-            0x02 => 0x3A,
-            _ => unreachable!(),
-        };
+    pub fn phoff(&self) -> u64 {
+        self.reader().word(&self[self.reader().at(e::PHOFF)..])
+    }
 
-        let end = start + 0x02;
+    pub fn shoff(&self) -> u64 {
+        self.reader().word(&self[self.reader().at(e::SHOFF)..])
+    }
 
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn flags(&self) -> u32 {
+        self.reader().u32(&self[self.reader().at(e::FLAGS)..])
     }
 
-    fn shnum(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x30,
-            0x02 => 0x3C,
-            _ => unreachable!(),
-        };
+    pub fn ehsize(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::EHSIZE)..])
+    }
 
-        let end = start + 0x02;
+    pub fn phentzise(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::PHENTSIZE)..])
+    }
 
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn phnum(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::PHNUM)..])
     }
 
-    fn shstrndx(&self) -> u16 {
-        let start = match self.ident.class() {
-            0x01 => 0x32,
-            0x02 => 0x3E,
-            _ => unreachable!(),
-        };
+    pub fn shentsize(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::SHENTSIZE)..])
+    }
 
-        let end = start + 0x02;
+    pub fn shnum(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::SHNUM)..])
+    }
 
-        if self.ident.endianness() == 0x01 {
-            u16::from_le_bytes(self[start..end].try_into().unwrap())
-        } else {
-            u16::from_be_bytes(self[start..end].try_into().unwrap())
-        }
+    pub fn shstrndx(&self) -> u16 {
+        self.reader().u16(&self[self.reader().at(e::SHSTRNDX)..])
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Ident, RawIdent};
+    use super::{Error, Header, Ident, RawIdent};
 
     macro_rules! ident_test {
         ($($test_name:ident: $input:expr,)*) => {$(
@@ -424,4 +380,73 @@ mod test {
         wrong_version_2:    [0x7F, 0x45, 0x4C, 0x46, 0x02, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
         wrong_abi:          [0x7F, 0x45, 0x4C, 0x46, 0x02, 0x02, 0x01, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
     }
+
+    #[test]
+    fn truncated_header() {
+        let file = include_bytes!("../../test/exe");
+
+        assert!(matches!(
+            Header::parse(&file[..0x08]),
+            Err(Error::Truncated {
+                expected: 0x10,
+                found: 0x08
+            })
+        ));
+
+        assert!(matches!(
+            Header::parse(&file[..0x20]),
+            Err(Error::Truncated {
+                expected: 0x40,
+                found: 0x20
+            })
+        ));
+
+        assert!(matches!(Header::parse(&[]), Err(Error::Truncated { .. })));
+        assert_eq!(Header::parse(&file[..0x40]).unwrap().len(), 0x40);
+    }
+
+    #[test]
+    fn headers_of_32_bit_and_big_endian() {
+        use crate::elf::common::e;
+        use crate::elf::reader::{Class, Endian};
+
+        for (file, machine, class, endian, shnum) in [
+            (
+                &include_bytes!("../../test/i386.so")[..],
+                e::machine::X86,
+                Class::Elf32,
+                Endian::Little,
+                15,
+            ),
+            (
+                &include_bytes!("../../test/arm.o")[..],
+                e::machine::ARM,
+                Class::Elf32,
+                Endian::Little,
+                10,
+            ),
+            (
+                &include_bytes!("../../test/ppc.o")[..],
+                e::machine::PPC,
+                Class::Elf32,
+                Endian::Big,
+                9,
+            ),
+            (
+                &include_bytes!("../../test/ppc64.o")[..],
+                e::machine::PPC64,
+                Class::Elf64,
+                Endian::Big,
+                11,
+            ),
+        ] {
+            let header = Header::parse(file).unwrap();
+
+            assert_eq!(header.machine(), machine);
+            assert_eq!(header.reader().class(), class);
+            assert_eq!(header.reader().endian(), endian);
+            assert_eq!(header.ehsize() as usize, header.len());
+            assert_eq!(header.shnum(), shnum);
+        }
+    }
 }
@@ -1,24 +1,141 @@
-mod header;
-mod common;
+pub mod addr;
+pub mod common;
+pub mod core;
+pub mod dynamic;
+pub mod header;
+pub mod note;
+pub mod reader;
+pub mod reloc;
+pub mod section;
+pub mod segment;
+pub mod symbol;
+pub mod version;
 
-use crate::elf::header::RawIdent;
-use crate::elf::header::{Header, Ident};
-use std::error;
-use std::fs;
-use std::path::PathBuf;
+pub use crate::elf::header::{Error, Header, Ident};
 
-pub fn load(file: PathBuf) -> Result<Vec<u8>, Box<dyn error::Error>> {
-    let file = fs::read(file)?;
+use crate::elf::addr::AddressIndex;
+use crate::elf::common::{e, sh};
+use crate::elf::core::Core;
+use crate::elf::dynamic::Dynamic;
+use crate::elf::section::SectionHeaders;
+use crate::elf::segment::ProgramHeaders;
+use crate::elf::symbol::Symbols;
+use crate::input::Data;
 
-    let ident: Ident =
-        RawIdent(<&[u8] as TryInto<&[u8; 0x10]>>::try_into(&file[..0x10])?).try_into()?;
+/// A parsed ELF file, borrowing everything from the data it was parsed from.
+///
+/// Only the header and the program and section header tables are validated up front, the
+/// structures they point to are decoded as they are accessed.
+#[derive(Debug, Clone)]
+pub struct Elf<'a> {
+    data: Data<'a>,
+    header: Header<'a>,
+    segments: ProgramHeaders<'a>,
+    sections: SectionHeaders<'a>,
+}
+
+impl<'a> Elf<'a> {
+    /// Parses `data`, a slice or an [`Input`](crate::input::Input) that reads the structures as
+    /// they are accessed.
+    pub fn parse(data: impl Into<Data<'a>>) -> Result<Self, Error> {
+        let data = data.into();
+        let header = Header::parse(data)?;
+        let segments = ProgramHeaders::new(data, &header)?;
+        let sections = SectionHeaders::new(data, &header)?;
+
+        Ok(Self {
+            data,
+            header,
+            segments,
+            sections,
+        })
+    }
+
+    /// The data the file was parsed from.
+    pub fn data(&self) -> Data<'a> {
+        self.data
+    }
+
+    pub fn ident(&self) -> Ident<'a> {
+        self.header.ident()
+    }
+
+    pub fn header(&self) -> Header<'a> {
+        self.header
+    }
+
+    pub fn segments(&self) -> ProgramHeaders<'a> {
+        self.segments.clone()
+    }
+
+    pub fn sections(&self) -> SectionHeaders<'a> {
+        self.sections.clone()
+    }
+
+    /// Entries of `.symtab`, `None` for stripped files.
+    pub fn symbols(&self) -> Result<Option<Symbols<'a>>, Error> {
+        Symbols::by_type(self.data, &self.sections, sh::r#type::SYMTAB)
+    }
+
+    /// Entries of `.dynsym`, `None` for statically linked files.
+    pub fn dynamic_symbols(&self) -> Result<Option<Symbols<'a>>, Error> {
+        Symbols::by_type(self.data, &self.sections, sh::r#type::DYNSYM)
+    }
+
+    /// Entries of the dynamic section, `None` for statically linked files.
+    pub fn dynamic(&self) -> Result<Option<Dynamic<'a>>, Error> {
+        Dynamic::new(self.data, &self.header)
+    }
+
+    /// Threads, signal and mappings of a core dump, `None` for other files.
+    pub fn core(&self) -> Result<Option<Core<'a>>, Error> {
+        if self.header.r#type() != e::r#type::CORE {
+            return Ok(None);
+        }
+
+        Core::new(self.data, &self.header).map(Some)
+    }
+
+    /// Sections and symbols sorted by address, to look up where addresses are.
+    pub fn address_index(&self) -> Result<AddressIndex<'a>, Error> {
+        AddressIndex::new(self)
+    }
+
+    /// `NT_GNU_BUILD_ID` of the file, see [`note::build_id`].
+    pub fn build_id(&self) -> Result<Option<&'a [u8]>, Error> {
+        note::build_id(self.data, &self.header)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Elf;
+    use crate::elf::common::e;
+
+    #[test]
+    fn elf_of_exe() {
+        let elf = Elf::parse(include_bytes!("../../test/exe")).unwrap();
+
+        assert_eq!(elf.ident().class(), 0x02);
+        assert_eq!(elf.header().machine(), e::machine::X86_64);
+        assert_eq!(elf.segments().len(), elf.header().phnum() as usize);
+        assert_eq!(elf.sections().num(), elf.header().shnum() as usize);
+
+        let symbols = elf.symbols().unwrap().unwrap();
+        assert!(symbols.clone().any(|symbol| symbol.name() == Some("main")));
 
-    println!("{ident}");
+        let dynsym = elf.dynamic_symbols().unwrap().unwrap();
+        assert!(dynsym.clone().any(|symbol| symbol.name() == Some("printf")));
 
-    let header: Header = (file[..ident.arch()].as_ref(), ident).into();
+        assert!(elf.dynamic().unwrap().unwrap().needed().eq(["libc.so.6"]));
+        assert_eq!(elf.build_id().unwrap().unwrap().len(), 0x14);
+    }
 
-    println!("{header}");
-    println!("{:?}", header.as_ref());
+    #[test]
+    fn elf_of_truncated_tables() {
+        let file = include_bytes!("../../test/exe");
 
-    Ok(file)
+        assert!(Elf::parse(&file[..0x40]).is_err());
+        assert!(Elf::parse(&file[..0x1000]).is_err());
+    }
 }
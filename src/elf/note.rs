@@ -0,0 +1,413 @@
+use crate::elf::common::{self, nt, p, sh};
+use crate::elf::header::{Error, Header};
+use crate::elf::reader::Reader;
+use crate::elf::section::{SectionHeader, SectionHeaders};
+use crate::elf::segment::{ProgramHeader, ProgramHeaders};
+use crate::input::Data;
+use std::fmt::Display;
+use std::ops::Deref;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Note<'a> {
+    note: &'a [u8],
+    reader: Reader,
+    machine: u16,
+    name: &'a [u8],
+    desc: &'a [u8],
+}
+
+impl Deref for Note<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.note
+    }
+}
+
+impl Display for Note<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let owner = self.name().unwrap_or("<corrupt>");
+
+        write!(f, "{:<20} {:#010x}\t", owner, self.descsz())?;
+
+        match common::n_type_name(owner, self.r#type()) {
+            Some(name) => writeln!(f, "{}", name)?,
+            None => writeln!(f, "Unknown note type: ({:#010x})", self.r#type())?,
+        }
+
+        if let Some(build_id) = self.build_id() {
+            let build_id: String = build_id.iter().map(|b| format!("{b:02x}")).collect();
+            return write!(f, "    Build ID: {}", build_id);
+        }
+
+        if let Some([os, major, minor, subminor]) = self.abi_tag() {
+            return write!(
+                f,
+                "    OS: {}, ABI: {}.{}.{}",
+                common::gnu_abi_tag_os_name(os),
+                major,
+                minor,
+                subminor
+            );
+        }
+
+        if let Some(properties) = self.properties() {
+            write!(f, "      Properties: ")?;
+
+            for (i, property) in properties.enumerate() {
+                if i != 0 {
+                    write!(f, "\n\t")?;
+                }
+                write!(f, "{property}")?;
+            }
+
+            return Ok(());
+        }
+
+        if let Some(metadata) = self.package_metadata() {
+            return write!(f, "    Packaging Metadata: {}", metadata);
+        }
+
+        if self.is(nt::gnu::GOLD_VERSION) {
+            let version = self.desc.split(|&b| b == 0x00).next().unwrap_or_default();
+            return write!(f, "    Version: {}", String::from_utf8_lossy(version));
+        }
+
+        let desc: Vec<String> = self.desc.iter().map(|b| format!("{b:02x}")).collect();
+        write!(f, "   description data: {}", desc.join(" "))
+    }
+}
+
+impl<'a> Note<'a> {
+    /// Owner of the note, its type values are only meaningful with it.
+    pub fn name(&self) -> Option<&'a str> {
+        let name = match self.name.split_last() {
+            Some((0x00, name)) => name,
+            _ => self.name,
+        };
+
+        std::str::from_utf8(name).ok()
+    }
+
+    pub fn namesz(&self) -> u32 {
+        self.reader.u32(&self[0x00..])
+    }
+
+    pub fn descsz(&self) -> u32 {
+        self.reader.u32(&self[0x04..])
+    }
+
+    pub fn r#type(&self) -> u32 {
+        self.reader.u32(&self[0x08..])
+    }
+
+    pub fn desc(&self) -> &'a [u8] {
+        self.desc
+    }
+
+    pub(crate) fn reader(&self) -> Reader {
+        self.reader
+    }
+
+    pub(crate) fn machine(&self) -> u16 {
+        self.machine
+    }
+
+    fn is(&self, r#type: u32) -> bool {
+        self.name() == Some("GNU") && self.r#type() == r#type
+    }
+
+    /// Descriptor of `NT_GNU_BUILD_ID`, what debuginfod and friends key debug files on.
+    pub fn build_id(&self) -> Option<&'a [u8]> {
+        self.is(nt::gnu::BUILD_ID).then_some(self.desc)
+    }
+
+    /// Descriptor of `NT_GNU_ABI_TAG`, the OS and the minimum kernel version.
+    pub fn abi_tag(&self) -> Option<[u32; 4]> {
+        if !self.is(nt::gnu::ABI_TAG) || self.desc.len() < 0x10 {
+            return None;
+        }
+
+        Some([0x00, 0x04, 0x08, 0x0C].map(|start| self.reader.u32(&self.desc[start..])))
+    }
+
+    /// Properties of `NT_GNU_PROPERTY_TYPE_0`.
+    pub fn properties(&self) -> Option<GnuProperties<'a>> {
+        self.is(nt::gnu::PROPERTY_TYPE_0).then_some(GnuProperties {
+            desc: self.desc,
+            reader: self.reader,
+            machine: self.machine,
+        })
+    }
+
+    /// JSON of the `.note.package` FDO packaging metadata note.
+    pub fn package_metadata(&self) -> Option<&'a str> {
+        if self.name() != Some("FDO") || self.r#type() != nt::fdo::PACKAGING_METADATA {
+            return None;
+        }
+
+        let json = self.desc.split(|&b| b == 0x00).next()?;
+        std::str::from_utf8(json).ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GnuProperty<'a> {
+    r#type: u32,
+    data: &'a [u8],
+    reader: Reader,
+    machine: u16,
+}
+
+impl Display for GnuProperty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(name) = common::gnu_property_name(self.machine, self.r#type) else {
+            return write!(
+                f,
+                "<unknown type {:#x} data: {:02x?}>",
+                self.r#type, self.data
+            );
+        };
+
+        write!(f, "{}: ", name)?;
+
+        match self.r#type {
+            common::gnu_property::STACK_SIZE => match self.data.len() {
+                0x04 | 0x08 => write!(f, "{:#x}", self.reader.word(self.data)),
+                _ => write!(f, "<corrupt length: {:#x}>", self.data.len()),
+            },
+            common::gnu_property::NO_COPY_ON_PROTECTED => Ok(()),
+            _ => match self.bits() {
+                Some(bits) => write!(
+                    f,
+                    "{}",
+                    common::gnu_property_bit_names(self.machine, self.r#type, bits).join(", ")
+                ),
+                None => write!(f, "<corrupt length: {:#x}>", self.data.len()),
+            },
+        }
+    }
+}
+
+impl<'a> GnuProperty<'a> {
+    pub fn r#type(&self) -> u32 {
+        self.r#type
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Value of the 4 byte bitmask properties, e.g. `GNU_PROPERTY_X86_FEATURE_1_AND`.
+    pub fn bits(&self) -> Option<u32> {
+        (self.data.len() == 0x04).then(|| self.reader.u32(self.data))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GnuProperties<'a> {
+    desc: &'a [u8],
+    reader: Reader,
+    machine: u16,
+}
+
+impl<'a> Iterator for GnuProperties<'a> {
+    type Item = GnuProperty<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Properties are 8 byte aligned on ELFCLASS64 and 4 byte aligned on ELFCLASS32
+        let align = self.reader.by_class(0x04, 0x08);
+
+        let r#type = self.reader.u32(self.desc.get(0x00..0x04)?);
+        let datasz = self.reader.u32(self.desc.get(0x04..0x08)?) as usize;
+        let data = self.desc.get(0x08..0x08usize.checked_add(datasz)?)?;
+
+        let next = (0x08 + datasz).next_multiple_of(align).min(self.desc.len());
+        self.desc = &self.desc[next..];
+
+        Some(GnuProperty {
+            r#type,
+            data,
+            reader: self.reader,
+            machine: self.machine,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes<'a> {
+    data: &'a [u8],
+    reader: Reader,
+    machine: u16,
+    align: usize,
+}
+
+impl<'a> Notes<'a> {
+    /// Notes in `data`, with names and descriptors padded to `align`. Only 8 is honored, anything
+    /// else is taken as 4 like readelf does.
+    pub fn new(data: &'a [u8], header: &Header<'a>, align: u64) -> Self {
+        Self {
+            data,
+            reader: header.reader(),
+            machine: header.machine(),
+            align: if align == 0x08 { 0x08 } else { 0x04 },
+        }
+    }
+
+    /// Notes of a `PT_NOTE` segment.
+    pub fn from_segment(
+        file: impl Into<Data<'a>>,
+        header: &Header<'a>,
+        segment: &ProgramHeader<'a>,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(segment.data(file)?, header, segment.align()))
+    }
+
+    /// Notes of a `SHT_NOTE` section.
+    pub fn from_section(
+        file: impl Into<Data<'a>>,
+        header: &Header<'a>,
+        section: &SectionHeader<'a>,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(section.data(file)?, header, section.addralign()))
+    }
+}
+
+impl<'a> Iterator for Notes<'a> {
+    type Item = Note<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let namesz = self.reader.u32(self.data.get(0x00..0x04)?) as usize;
+        let descsz = self.reader.u32(self.data.get(0x04..0x08)?) as usize;
+
+        let name_end = 0x0Cusize.checked_add(namesz)?;
+        let desc_start = name_end.next_multiple_of(self.align);
+        let desc_end = desc_start.checked_add(descsz)?;
+
+        let name = self.data.get(0x0C..name_end)?;
+        let desc = self.data.get(desc_start..desc_end)?;
+
+        let end = desc_end.next_multiple_of(self.align).min(self.data.len());
+        let note = &self.data[..end];
+        self.data = &self.data[end..];
+
+        Some(Note {
+            note,
+            reader: self.reader,
+            machine: self.machine,
+            name,
+            desc,
+        })
+    }
+}
+
+/// `NT_GNU_BUILD_ID` of the file, looked up in the `PT_NOTE` segments first and in the `SHT_NOTE`
+/// sections for files without program headers, e.g. relocatables and split debug files.
+pub fn build_id<'a>(
+    file: impl Into<Data<'a>>,
+    header: &Header<'a>,
+) -> Result<Option<&'a [u8]>, Error> {
+    let file = file.into();
+
+    for segment in ProgramHeaders::new(file, header)? {
+        if segment.r#type() != p::r#type::NOTE {
+            continue;
+        }
+
+        if let Some(build_id) =
+            Notes::from_segment(file, header, &segment)?.find_map(|n| n.build_id())
+        {
+            return Ok(Some(build_id));
+        }
+    }
+
+    for section in SectionHeaders::new(file, header)? {
+        if section.r#type() != sh::r#type::NOTE {
+            continue;
+        }
+
+        if let Some(build_id) =
+            Notes::from_section(file, header, &section)?.find_map(|n| n.build_id())
+        {
+            return Ok(Some(build_id));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Notes;
+    use crate::elf::common::gnu_property;
+    use crate::elf::header::Header;
+    use crate::elf::section::SectionHeaders;
+
+    #[test]
+    fn notes_of_exe() {
+        let file = include_bytes!("../../test/exe");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let notes = |name| {
+            let section = sections.by_name(name).unwrap();
+            Notes::from_section(file, &header, &section)
+                .unwrap()
+                .collect::<Vec<_>>()
+        };
+
+        let property = notes(".note.gnu.property");
+        assert_eq!(property.len(), 1);
+
+        let properties: Vec<_> = property[0].properties().unwrap().collect();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(properties[0].r#type(), gnu_property::X86_ISA_1_NEEDED);
+        assert_eq!(properties[0].bits(), Some(0x01));
+
+        let build_id = notes(".note.gnu.build-id");
+        assert_eq!(
+            build_id[0].build_id().unwrap(),
+            [
+                0xC0, 0x22, 0x18, 0x92, 0x83, 0xB4, 0xCD, 0xEA, 0xCA, 0x9C, 0x0B, 0x06, 0xD1, 0x48,
+                0x0A, 0x7F, 0x96, 0x56, 0xD3, 0x68
+            ]
+        );
+        assert_eq!(
+            super::build_id(file, &header).unwrap(),
+            build_id[0].build_id()
+        );
+
+        let abi_tag = notes(".note.ABI-tag");
+        assert_eq!(abi_tag[0].name(), Some("GNU"));
+        assert_eq!(abi_tag[0].abi_tag(), Some([0, 4, 4, 0]));
+    }
+
+    #[test]
+    fn package_metadata() {
+        let file = include_bytes!("../../test/exe");
+
+        let header = Header::parse(file).unwrap();
+
+        let json = br#"{"type":"deb","name":"badelf"}"#;
+
+        let mut note = Vec::new();
+        note.extend(0x04u32.to_le_bytes());
+        note.extend((json.len() as u32 + 1).to_le_bytes());
+        note.extend(0xCAFE1A7Eu32.to_le_bytes());
+        note.extend(b"FDO\0");
+        note.extend(json);
+        note.push(0x00);
+        note.resize(note.len().next_multiple_of(0x04), 0x00);
+
+        let mut notes = Notes::new(&note, &header, 0x04);
+        let note = notes.next().unwrap();
+        assert!(notes.next().is_none());
+
+        assert_eq!(note.name(), Some("FDO"));
+        assert_eq!(
+            note.package_metadata(),
+            Some(r#"{"type":"deb","name":"badelf"}"#)
+        );
+    }
+}
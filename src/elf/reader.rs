@@ -0,0 +1,130 @@
+/// `EI_CLASS`, the size of addresses and offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Elf32,
+    Elf64,
+}
+
+/// `EI_DATA`, the byte order of every multi-byte field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+/// Decodes fields for the class and byte order of a file.
+///
+/// Every structure reads through one, so the layout differences between ELFCLASS32 and
+/// ELFCLASS64 are reduced to picking offsets with [`Reader::by_class`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reader {
+    class: Class,
+    endian: Endian,
+}
+
+impl Reader {
+    pub fn new(class: Class, endian: Endian) -> Self {
+        Self { class, endian }
+    }
+
+    pub fn class(&self) -> Class {
+        self.class
+    }
+
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Picks `elf32` or `elf64`, e.g. the offset or size of a field, by the class.
+    pub fn by_class<T>(&self, elf32: T, elf64: T) -> T {
+        match self.class {
+            Class::Elf32 => elf32,
+            Class::Elf64 => elf64,
+        }
+    }
+
+    /// Same as [`Reader::by_class`] for the `(elf32, elf64)` pairs of `common`.
+    pub fn at(&self, (elf32, elf64): (usize, usize)) -> usize {
+        self.by_class(elf32, elf64)
+    }
+
+    /// Size of `ElfN_Addr`, `ElfN_Off` and the other class sized fields.
+    pub fn wordsize(&self) -> usize {
+        self.by_class(0x04, 0x08)
+    }
+
+    pub fn u16(&self, bytes: &[u8]) -> u16 {
+        let bytes = *bytes.first_chunk().unwrap();
+
+        match self.endian {
+            Endian::Little => u16::from_le_bytes(bytes),
+            Endian::Big => u16::from_be_bytes(bytes),
+        }
+    }
+
+    pub fn u32(&self, bytes: &[u8]) -> u32 {
+        let bytes = *bytes.first_chunk().unwrap();
+
+        match self.endian {
+            Endian::Little => u32::from_le_bytes(bytes),
+            Endian::Big => u32::from_be_bytes(bytes),
+        }
+    }
+
+    pub fn u64(&self, bytes: &[u8]) -> u64 {
+        let bytes = *bytes.first_chunk().unwrap();
+
+        match self.endian {
+            Endian::Little => u64::from_le_bytes(bytes),
+            Endian::Big => u64::from_be_bytes(bytes),
+        }
+    }
+
+    /// Reads an `ElfN_Addr`/`ElfN_Off` sized field, 4 bytes for ELFCLASS32 and 8 for ELFCLASS64.
+    pub fn word(&self, bytes: &[u8]) -> u64 {
+        match self.class {
+            Class::Elf32 => self.u32(bytes).into(),
+            Class::Elf64 => self.u64(bytes),
+        }
+    }
+
+    /// Reads an `ElfN_Sxword` sized field, e.g. `r_addend`, sign extended for ELFCLASS32.
+    pub fn sword(&self, bytes: &[u8]) -> i64 {
+        match self.class {
+            Class::Elf32 => self.u32(bytes) as i32 as i64,
+            Class::Elf64 => self.u64(bytes) as i64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Class, Endian, Reader};
+
+    #[test]
+    fn reader() {
+        let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0xF8];
+
+        let le32 = Reader::new(Class::Elf32, Endian::Little);
+        let be32 = Reader::new(Class::Elf32, Endian::Big);
+        let le64 = Reader::new(Class::Elf64, Endian::Little);
+        let be64 = Reader::new(Class::Elf64, Endian::Big);
+
+        assert_eq!(le32.u16(&bytes), 0x0201);
+        assert_eq!(be32.u16(&bytes), 0x0102);
+        assert_eq!(le32.u32(&bytes), 0x04030201);
+        assert_eq!(be32.u32(&bytes), 0x01020304);
+
+        assert_eq!(le32.word(&bytes), 0x04030201);
+        assert_eq!(be32.word(&bytes), 0x01020304);
+        assert_eq!(le64.word(&bytes), 0xF807060504030201);
+        assert_eq!(be64.word(&bytes), 0x01020304050607F8);
+
+        assert_eq!(be32.sword(&bytes[4..]), 0x050607F8);
+        assert_eq!(le32.sword(&bytes[4..]), -0x07F8F9FB);
+        assert_eq!(le64.sword(&bytes), 0xF807060504030201u64 as i64);
+
+        assert_eq!(le32.wordsize(), 0x04);
+        assert_eq!(be64.by_class(0x34, 0x40), 0x40);
+    }
+}
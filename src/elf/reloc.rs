@@ -0,0 +1,479 @@
+use crate::elf::common::{self, e, sh};
+use crate::elf::header::{Error, Header};
+use crate::elf::reader::{Class, Reader};
+use crate::elf::section::SectionHeaders;
+use crate::elf::symbol::{Symbol, Symbols};
+use crate::input::Data;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::slice::ChunksExact;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Relocation<'a> {
+    relocation: &'a [u8],
+    reader: Reader,
+    machine: u16,
+    symbol: Option<Symbol<'a>>,
+}
+
+impl<'a> From<(&'a [u8], Reader, u16, Option<Symbol<'a>>)> for Relocation<'a> {
+    fn from(
+        (relocation, reader, machine, symbol): (&'a [u8], Reader, u16, Option<Symbol<'a>>),
+    ) -> Self {
+        Self {
+            relocation,
+            reader,
+            machine,
+            symbol,
+        }
+    }
+}
+
+impl Deref for Relocation<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.relocation
+    }
+}
+
+impl Display for Relocation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "offset={:#018X} ", self.offset())?;
+        write!(f, "info={:#018X} ", self.info())?;
+
+        match common::r_type_name(self.machine, self.r#type()) {
+            Some(name) => write!(f, "type={:<24} ", name)?,
+            None => write!(
+                f,
+                "type={:<24} ",
+                format!("<unknown>: {:#X}", self.r#type())
+            )?,
+        }
+
+        let name = match self.symbol {
+            Some(symbol) => symbol.name().unwrap_or("<corrupt>"),
+            None if self.sym() == 0 => "",
+            None => "<corrupt>",
+        };
+
+        match self.addend() {
+            Some(addend) if addend < 0 => write!(f, "sym={} - {:#X}", name, addend.unsigned_abs()),
+            Some(addend) => write!(f, "sym={} + {:#X}", name, addend),
+            None => write!(f, "sym={}", name),
+        }
+    }
+}
+
+impl<'a> Relocation<'a> {
+    /// Size of `ElfN_Rel`, or `ElfN_Rela` if `rela`, for the class in `reader`.
+    pub fn entsize_of(reader: &Reader, rela: bool) -> usize {
+        match (reader.class(), rela) {
+            (Class::Elf32, false) => 0x08,
+            (Class::Elf32, true) => 0x0C,
+            (Class::Elf64, false) => 0x10,
+            (Class::Elf64, true) => 0x18,
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.reader.word(&self[0x00..])
+    }
+
+    pub fn info(&self) -> u64 {
+        let start = self.reader.by_class(0x04, 0x08);
+
+        self.reader.word(&self[start..])
+    }
+
+    /// Index of the target symbol in the linked symbol table, `ELFN_R_SYM(r_info)`.
+    pub fn sym(&self) -> u32 {
+        self.reader
+            .by_class((self.info() >> 8) as u32, (self.info() >> 32) as u32)
+    }
+
+    /// Machine specific relocation type, `ELFN_R_TYPE(r_info)`.
+    pub fn r#type(&self) -> u32 {
+        self.reader.by_class(
+            (self.info() & 0xFF) as u32,
+            (self.info() & 0xFFFFFFFF) as u32,
+        )
+    }
+
+    /// Explicit addend of `SHT_RELA` entries, `SHT_REL` entries keep it in the relocated field.
+    pub fn addend(&self) -> Option<i64> {
+        let start = self.reader.by_class(0x08, 0x10);
+
+        (self.len() > start).then(|| self.reader.sword(&self[start..]))
+    }
+
+    pub fn symbol(&self) -> Option<Symbol<'a>> {
+        self.symbol
+    }
+
+    pub fn type_name(&self) -> Option<&'static str> {
+        common::r_type_name(self.machine, self.r#type())
+    }
+
+    /// How the relocation writes `S + A` when applied to the debug sections of a relocatable
+    /// object, following binutils. `None` for the kinds binutils leaves unapplied there.
+    pub fn fixup(&self) -> Option<Fixup> {
+        match (self.machine, self.r#type()) {
+            (_, 0) => Some(Fixup::None),
+            // R_386_32, R_386_TLS_LDO_32
+            (e::machine::X86, 1 | 32) => Some(Fixup::Write(4)),
+            // R_X86_64_64, R_X86_64_DTPOFF64
+            (e::machine::X86_64, 1 | 17) => Some(Fixup::Write(8)),
+            // R_X86_64_32, R_X86_64_DTPOFF32
+            (e::machine::X86_64, 10 | 21) => Some(Fixup::Write(4)),
+            (e::machine::ARM, 2) => Some(Fixup::Write(4)),
+            (e::machine::AARCH64, 257) => Some(Fixup::Write(8)),
+            (e::machine::AARCH64, 258) => Some(Fixup::Write(4)),
+            (e::machine::RISCV, 1) => Some(Fixup::Write(4)),
+            (e::machine::RISCV, 2) => Some(Fixup::Write(8)),
+            // R_RISCV_ADD8 to R_RISCV_ADD64 and R_RISCV_SUB8 to R_RISCV_SUB64
+            (e::machine::RISCV, 33) => Some(Fixup::Add(1)),
+            (e::machine::RISCV, 34) => Some(Fixup::Add(2)),
+            (e::machine::RISCV, 35) => Some(Fixup::Add(4)),
+            (e::machine::RISCV, 36) => Some(Fixup::Add(8)),
+            (e::machine::RISCV, 37) => Some(Fixup::Sub(1)),
+            (e::machine::RISCV, 38) => Some(Fixup::Sub(2)),
+            (e::machine::RISCV, 39) => Some(Fixup::Sub(4)),
+            (e::machine::RISCV, 40) => Some(Fixup::Sub(8)),
+            (e::machine::RISCV, 52) => Some(Fixup::Sub6),
+            (e::machine::RISCV, 53) => Some(Fixup::Write6),
+            (e::machine::RISCV, 54) => Some(Fixup::Write(1)),
+            (e::machine::RISCV, 55) => Some(Fixup::Write(2)),
+            (e::machine::RISCV, 56) => Some(Fixup::Write(4)),
+            (e::machine::PPC, 1) => Some(Fixup::Write(4)),
+            (e::machine::PPC64, 1) => Some(Fixup::Write(4)),
+            (e::machine::PPC64, 38) => Some(Fixup::Write(8)),
+            _ => None,
+        }
+    }
+}
+
+/// What a relocation does to the field it applies to, see [`Relocation::fixup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fixup {
+    /// Leaves the field alone.
+    None,
+    /// Overwrites the field of that many bytes with `S + A`.
+    Write(usize),
+    /// Adds `S + A` to the field of that many bytes.
+    Add(usize),
+    /// Subtracts `S + A` from the field of that many bytes.
+    Sub(usize),
+    /// Overwrites the low 6 bits of a byte with `S + A`.
+    Write6,
+    /// Subtracts `S + A` from the low 6 bits of a byte.
+    Sub6,
+}
+
+impl Fixup {
+    /// Size in bytes of the field the fixup applies to.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Write(size) | Self::Add(size) | Self::Sub(size) => *size,
+            Self::Write6 | Self::Sub6 => 1,
+        }
+    }
+
+    /// New contents of a field holding `field` once `value` is applied, before truncation.
+    pub fn apply(&self, field: u64, value: u64) -> u64 {
+        match self {
+            Self::None => field,
+            Self::Write(_) => value,
+            Self::Add(_) => field.wrapping_add(value),
+            Self::Sub(_) => field.wrapping_sub(value),
+            Self::Write6 => field & !0x3f | value & 0x3f,
+            Self::Sub6 => field & !0x3f | field.wrapping_sub(value) & 0x3f,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Relocations<'a> {
+    entries: ChunksExact<'a, u8>,
+    reader: Reader,
+    machine: u16,
+    symbols: Option<Symbols<'a>>,
+    name: Option<&'a str>,
+}
+
+impl<'a> Relocations<'a> {
+    /// Relocations of the `SHT_REL` or `SHT_RELA` section at `index`.
+    pub fn new(
+        file: impl Into<Data<'a>>,
+        header: &Header<'a>,
+        sections: &SectionHeaders<'a>,
+        index: usize,
+    ) -> Result<Self, Error> {
+        let file = file.into();
+
+        let section = sections
+            .get(index)
+            .ok_or_else(|| Error::Relocation(format!("no section at index {index}")))?;
+
+        let rela = match section.r#type() {
+            sh::r#type::REL => false,
+            sh::r#type::RELA => true,
+            _ => {
+                return Err(Error::Relocation(format!(
+                    "{} is not a relocation table",
+                    section.name().unwrap_or("<corrupt>")
+                )))
+            }
+        };
+
+        let reader = header.reader();
+        let entsize = match section.entsize() as usize {
+            0 => Relocation::entsize_of(&reader, rela),
+            entsize if entsize != Relocation::entsize_of(&reader, rela) => {
+                return Err(Error::Relocation(format!(
+                    "sh_entsize {entsize} is not {}",
+                    Relocation::entsize_of(&reader, rela)
+                )))
+            }
+            entsize => entsize,
+        };
+
+        // Relocations without a symbol table (e.g. `.rela.dyn` of a static PIE) have sh_link 0
+        let symbols = match section.link() {
+            0 => None,
+            link => Some(Symbols::new(file, sections, link as usize)?),
+        };
+
+        Ok(Self {
+            entries: section.data(file)?.chunks_exact(entsize),
+            reader,
+            machine: header.machine(),
+            symbols,
+            name: section.name(),
+        })
+    }
+
+    /// Name of the section holding the relocation table.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+}
+
+impl<'a> Iterator for Relocations<'a> {
+    type Item = Relocation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?;
+
+        let mut relocation: Relocation = (entry, self.reader, self.machine, None).into();
+        relocation.symbol = match relocation.sym() {
+            0 => None,
+            sym => self
+                .symbols
+                .as_ref()
+                .and_then(|symbols| symbols.get(sym as usize)),
+        };
+
+        Some(relocation)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Relocations<'_> {}
+
+/// Addresses of the relative relocations packed in a `SHT_RELR` section.
+///
+/// Even entries are the address of the next relocation, odd entries are a bitmap of which of the
+/// following `wordbits - 1` words also need relocating.
+#[derive(Debug, Clone)]
+pub struct Relr<'a> {
+    entries: ChunksExact<'a, u8>,
+    reader: Reader,
+    base: u64,
+    bitmap: u64,
+    bitmap_base: u64,
+    name: Option<&'a str>,
+}
+
+impl<'a> Relr<'a> {
+    pub fn new(
+        file: impl Into<Data<'a>>,
+        header: &Header<'a>,
+        sections: &SectionHeaders<'a>,
+        index: usize,
+    ) -> Result<Self, Error> {
+        let file = file.into();
+
+        let section = sections
+            .get(index)
+            .ok_or_else(|| Error::Relocation(format!("no section at index {index}")))?;
+
+        if section.r#type() != sh::r#type::RELR {
+            return Err(Error::Relocation(format!(
+                "{} is not a relr table",
+                section.name().unwrap_or("<corrupt>")
+            )));
+        }
+
+        let reader = header.reader();
+
+        Ok(Self {
+            entries: section.data(file)?.chunks_exact(reader.wordsize()),
+            reader,
+            base: 0,
+            bitmap: 0,
+            bitmap_base: 0,
+            name: section.name(),
+        })
+    }
+
+    /// Name of the section holding the relocation table.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+}
+
+impl Iterator for Relr<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let wordsize = self.reader.wordsize() as u64;
+
+        loop {
+            if self.bitmap != 0 {
+                let bit = self.bitmap.trailing_zeros() as u64;
+                self.bitmap &= self.bitmap - 1;
+
+                return Some(self.bitmap_base.wrapping_add(bit * wordsize));
+            }
+
+            let entry = self.reader.word(self.entries.next()?);
+
+            // Addresses wrap around like in binutils, a crafted table must not panic
+            if entry & 1 == 0 {
+                self.base = entry.wrapping_add(wordsize);
+                return Some(entry);
+            }
+
+            self.bitmap = entry >> 1;
+            self.bitmap_base = self.base;
+            self.base = self.base.wrapping_add((wordsize * 8 - 1) * wordsize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Relocations, Relr};
+    use crate::elf::header::Header;
+    use crate::elf::reader::{Class, Endian, Reader};
+    use crate::elf::section::SectionHeaders;
+
+    #[test]
+    fn relocations_of_lib_o() {
+        let file = include_bytes!("../../test/lib.o");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let index = sections
+            .clone()
+            .position(|section| section.name() == Some(".rela.text"))
+            .unwrap();
+        let relocations: Vec<_> = Relocations::new(file, &header, &sections, index)
+            .unwrap()
+            .collect();
+
+        let printf = relocations
+            .iter()
+            .find(|relocation| relocation.type_name() == Some("R_X86_64_PLT32"))
+            .unwrap();
+        assert_eq!(printf.symbol().unwrap().name(), Some("printf"));
+        assert_eq!(printf.addend(), Some(-4));
+    }
+
+    #[test]
+    fn relr() {
+        #[rustfmt::skip]
+        let file: [u8; 0x10] = [
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let relr = Relr {
+            entries: file.chunks_exact(0x08),
+            reader: Reader::new(Class::Elf64, Endian::Little),
+            base: 0,
+            bitmap: 0,
+            bitmap_base: 0,
+            name: None,
+        };
+
+        // 0x1000, then bits 0 and 2 of the bitmap: 0x1008 and 0x1018
+        assert!(relr.eq([0x1000, 0x1008, 0x1018]));
+    }
+
+    #[test]
+    fn relr_of_high_address() {
+        #[rustfmt::skip]
+        let file: [u8; 0x10] = [
+            0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let relr = Relr {
+            entries: file.chunks_exact(0x08),
+            reader: Reader::new(Class::Elf64, Endian::Little),
+            base: 0,
+            bitmap: 0,
+            bitmap_base: 0,
+            name: None,
+        };
+
+        assert!(relr.eq([0xFFFFFFFFFFFFFFF8, 0x00, 0x08]));
+    }
+
+    #[test]
+    fn relocations_of_32_bit_and_big_endian() {
+        for (file, name, r#type, addend) in [
+            (
+                &include_bytes!("../../test/arm.o")[..],
+                ".rel.text",
+                "R_ARM_CALL",
+                None,
+            ),
+            (
+                &include_bytes!("../../test/ppc.o")[..],
+                ".rela.text",
+                "R_PPC_REL24",
+                Some(0),
+            ),
+            (
+                &include_bytes!("../../test/ppc64.o")[..],
+                ".rela.text",
+                "R_PPC64_REL24",
+                Some(0),
+            ),
+        ] {
+            let header = Header::parse(file).unwrap();
+            let sections = SectionHeaders::new(file, &header).unwrap();
+
+            let index = sections
+                .clone()
+                .position(|section| section.name() == Some(name))
+                .unwrap();
+
+            let printf = Relocations::new(file, &header, &sections, index)
+                .unwrap()
+                .find(|relocation| relocation.symbol().and_then(|s| s.name()) == Some("printf"))
+                .unwrap();
+
+            assert_eq!(printf.type_name(), Some(r#type));
+            assert_eq!(printf.addend(), addend);
+        }
+    }
+}
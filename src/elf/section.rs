@@ -0,0 +1,468 @@
+use crate::elf::common::{self, ch, sh, shn};
+use crate::elf::header::{Error, Header};
+use crate::elf::reader::Reader;
+use crate::input::Data;
+use flate2::read::ZlibDecoder;
+use ruzstd::decoding::StreamingDecoder;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::Read;
+use std::ops::Deref;
+
+/// A `SHT_STRTAB` section, strings are referenced by their byte offset.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StringTable<'a>(pub &'a [u8]);
+
+impl<'a> StringTable<'a> {
+    pub fn get(&self, offset: usize) -> Option<&'a str> {
+        let bytes = self.0.get(offset..)?;
+        let end = bytes.iter().position(|&b| b == 0x00)?;
+
+        std::str::from_utf8(&bytes[..end]).ok()
+    }
+}
+
+/// How the contents of a compressed section are stored, from the `Elf32_Chdr`/`Elf64_Chdr` of
+/// `SHF_COMPRESSED` sections or the `ZLIB` header of the legacy `.zdebug_*` ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionHeader {
+    r#type: u32,
+    size: u64,
+    addralign: u64,
+    data_offset: usize,
+}
+
+impl CompressionHeader {
+    /// Size of `Elf32_Chdr`/`Elf64_Chdr` for the class in `reader`.
+    pub fn entsize_of(reader: &Reader) -> usize {
+        reader.by_class(0x0C, 0x18)
+    }
+
+    /// `ELFCOMPRESS_*`, `.zdebug_*` sections are compressed with zlib.
+    pub fn r#type(&self) -> u32 {
+        self.r#type
+    }
+
+    /// Size of the uncompressed contents.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Alignment of the uncompressed contents.
+    pub fn addralign(&self) -> u64 {
+        self.addralign
+    }
+
+    /// Offset of the compressed stream in the section, right after the header.
+    pub fn data_offset(&self) -> usize {
+        self.data_offset
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SectionHeader<'a> {
+    header: &'a [u8],
+    reader: Reader,
+    name: Option<&'a str>,
+}
+
+impl<'a> From<(&'a [u8], Reader, Option<&'a str>)> for SectionHeader<'a> {
+    fn from((header, reader, name): (&'a [u8], Reader, Option<&'a str>)) -> Self {
+        Self {
+            header,
+            reader,
+            name,
+        }
+    }
+}
+
+impl Deref for SectionHeader<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.header
+    }
+}
+
+impl Display for SectionHeader<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "name={} ", self.name().unwrap_or("<corrupt>"))?;
+        write!(f, "type={} ", common::sh_type_name(self.r#type()))?;
+        write!(f, "addr={:#08X} ", self.addr())?;
+        write!(f, "offset={:#08X} ", self.offset())?;
+        write!(f, "size={:#08X} ", self.size())?;
+        write!(f, "entsize={:#X} ", self.entsize())?;
+        write!(f, "flags={} ", common::sh_flags_name(self.flags()))?;
+        write!(f, "link={} ", self.link())?;
+        write!(f, "info={} ", self.info())?;
+        write!(f, "align={:#X}", self.addralign())
+    }
+}
+
+impl<'a> SectionHeader<'a> {
+    /// Size of `Elf32_Shdr`/`Elf64_Shdr` for the class in `reader`.
+    pub fn entsize_of(reader: &Reader) -> usize {
+        reader.by_class(0x28, 0x40)
+    }
+
+    pub fn reader(&self) -> Reader {
+        self.reader
+    }
+
+    /// Name resolved through the section header string table, `None` if `sh_name` is not a valid
+    /// offset into it.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    pub fn name_offset(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(sh::NAME)..])
+    }
+
+    pub fn r#type(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(sh::TYPE)..])
+    }
+
+    pub fn flags(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::FLAGS)..])
+    }
+
+    pub fn addr(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::ADDR)..])
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::OFFSET)..])
+    }
+
+    pub fn size(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::SIZE)..])
+    }
+
+    pub fn link(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(sh::LINK)..])
+    }
+
+    pub fn info(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(sh::INFO)..])
+    }
+
+    pub fn addralign(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::ADDRALIGN)..])
+    }
+
+    pub fn entsize(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(sh::ENTSIZE)..])
+    }
+
+    /// Contents of the section in `file`, `SHT_NOBITS` sections have none.
+    pub fn data(&self, file: impl Into<Data<'a>>) -> Result<&'a [u8], Error> {
+        if self.r#type() == sh::r#type::NOBITS {
+            return Ok(&[]);
+        }
+
+        usize::try_from(self.offset())
+            .ok()
+            .zip(usize::try_from(self.size()).ok())
+            .and_then(|(start, size)| file.into().get(start..start.checked_add(size)?))
+            .ok_or_else(|| {
+                Error::Section(format!(
+                    "contents of {} are out of the file bounds",
+                    self.name().unwrap_or("<corrupt>")
+                ))
+            })
+    }
+
+    /// How the section is compressed, `None` for the ones that are not.
+    pub fn compression(
+        &self,
+        file: impl Into<Data<'a>>,
+    ) -> Result<Option<CompressionHeader>, Error> {
+        let data = self.data(file)?;
+        let truncated = || {
+            Error::Section(format!(
+                "compression header of {} is truncated",
+                self.name().unwrap_or("<corrupt>")
+            ))
+        };
+
+        if self.flags() & sh::flags::COMPRESSED != 0 {
+            let len = CompressionHeader::entsize_of(&self.reader);
+            let header = data.get(..len).ok_or_else(truncated)?;
+
+            return Ok(Some(CompressionHeader {
+                r#type: self.reader.u32(&header[self.reader.at(ch::TYPE)..]),
+                size: self.reader.word(&header[self.reader.at(ch::SIZE)..]),
+                addralign: self.reader.word(&header[self.reader.at(ch::ADDRALIGN)..]),
+                data_offset: len,
+            }));
+        }
+
+        // Before SHF_COMPRESSED, `ZLIB` and the big-endian size of the contents
+        if self.name().is_some_and(|name| name.starts_with(".zdebug")) && data.starts_with(b"ZLIB")
+        {
+            let size = data.get(0x04..0x0C).ok_or_else(truncated)?;
+
+            return Ok(Some(CompressionHeader {
+                r#type: ch::r#type::ZLIB,
+                size: u64::from_be_bytes(size.try_into().unwrap()),
+                addralign: self.addralign(),
+                data_offset: 0x0C,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Contents of the section in `file`, decompressed when the section is compressed.
+    pub fn contents(&self, file: impl Into<Data<'a>>) -> Result<Cow<'a, [u8]>, Error> {
+        let file = file.into();
+        let data = self.data(file)?;
+
+        let Some(header) = self.compression(file)? else {
+            return Ok(Cow::Borrowed(data));
+        };
+
+        let name = self.name().unwrap_or("<corrupt>");
+        let stream = &data[header.data_offset..];
+
+        // The size in the header is not trusted for the allocation, only to stop reading
+        let mut contents = Vec::new();
+        let read = match header.r#type {
+            ch::r#type::ZLIB => ZlibDecoder::new(stream)
+                .take(header.size)
+                .read_to_end(&mut contents)
+                .map_err(|err| err.to_string()),
+            ch::r#type::ZSTD => StreamingDecoder::new(stream)
+                .map_err(|err| err.to_string())
+                .and_then(|decoder| {
+                    decoder
+                        .take(header.size)
+                        .read_to_end(&mut contents)
+                        .map_err(|err| err.to_string())
+                }),
+            other => Err(format!("unknown compression type {other:#x}")),
+        };
+
+        read.map_err(|err| Error::Section(format!("cannot decompress {name}: {err}")))?;
+
+        if contents.len() as u64 != header.size {
+            return Err(Error::Section(format!(
+                "{name} decompresses to {:#x} bytes instead of {:#x}",
+                contents.len(),
+                header.size
+            )));
+        }
+
+        Ok(Cow::Owned(contents))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SectionHeaders<'a> {
+    table: &'a [u8],
+    entsize: usize,
+    index: usize,
+    reader: Reader,
+    strtab: StringTable<'a>,
+}
+
+impl<'a> SectionHeaders<'a> {
+    pub fn new(file: impl Into<Data<'a>>, header: &Header<'a>) -> Result<Self, Error> {
+        let file = file.into();
+
+        let reader = header.reader();
+        let entsize = header.shentsize() as usize;
+
+        let mut sections = Self {
+            table: &[],
+            entsize,
+            index: 0,
+            reader,
+            strtab: StringTable::default(),
+        };
+
+        if header.shoff() == 0 {
+            return Ok(sections);
+        }
+
+        if entsize < SectionHeader::entsize_of(&reader) {
+            return Err(Error::Header(format!(
+                "shentsize {entsize} is smaller than {}",
+                SectionHeader::entsize_of(&reader)
+            )));
+        }
+
+        let table = |num: usize| {
+            usize::try_from(header.shoff())
+                .ok()
+                .and_then(|start| file.get(start..start.checked_add(entsize.checked_mul(num)?)?))
+                .ok_or_else(|| Error::Section("table is out of the file bounds".into()))
+        };
+
+        // With more than SHN_LORESERVE sections `e_shnum` is zero and the real count is kept in
+        // the `sh_size` of the first entry, same goes for `e_shstrndx` and `sh_link`.
+        sections.table = table(1)?;
+        let first = sections.get(0).unwrap();
+
+        let num = match header.shnum() {
+            0 => usize::try_from(first.size())
+                .map_err(|_| Error::Section("too many sections".into()))?,
+            num => num as usize,
+        };
+
+        let shstrndx = match header.shstrndx() {
+            shn::XINDEX => first.link() as usize,
+            shstrndx => shstrndx as usize,
+        };
+
+        sections.table = table(num)?;
+
+        if shstrndx != shn::UNDEF as usize {
+            let shstrtab = sections.get(shstrndx).ok_or_else(|| {
+                Error::Header(format!("shstrndx {shstrndx} is out of the table bounds"))
+            })?;
+
+            sections.strtab = StringTable(shstrtab.data(file)?);
+        }
+
+        Ok(sections)
+    }
+
+    /// Number of entries in the whole table, unlike [`ExactSizeIterator::len`] it does not change
+    /// as the headers are iterated.
+    pub fn num(&self) -> usize {
+        self.table.len() / self.entsize.max(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num() == 0
+    }
+
+    /// Section header string table, referenced by `e_shstrndx`.
+    pub fn strtab(&self) -> StringTable<'a> {
+        self.strtab
+    }
+
+    pub fn get(&self, index: usize) -> Option<SectionHeader<'a>> {
+        let start = index.checked_mul(self.entsize)?;
+        let header = self.table.get(start..start + self.entsize)?;
+        let name = self.strtab.get(self.reader.u32(header) as usize);
+
+        Some((header, self.reader, name).into())
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<SectionHeader<'a>> {
+        (0..self.num())
+            .filter_map(|i| self.get(i))
+            .find(|section| section.name() == Some(name))
+    }
+}
+
+impl<'a> Iterator for SectionHeaders<'a> {
+    type Item = SectionHeader<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let section = self.get(self.index)?;
+        self.index += 1;
+        Some(section)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for SectionHeaders<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::{SectionHeaders, StringTable};
+    use crate::elf::common::{ch, sh};
+    use crate::elf::header::Header;
+    use std::borrow::Cow;
+
+    #[test]
+    fn string_table() {
+        let strtab = StringTable(b"\0.text\0.data\0unterminated");
+
+        assert_eq!(strtab.get(0), Some(""));
+        assert_eq!(strtab.get(1), Some(".text"));
+        assert_eq!(strtab.get(3), Some("ext"));
+        assert_eq!(strtab.get(7), Some(".data"));
+        assert_eq!(strtab.get(13), None);
+        assert_eq!(strtab.get(100), None);
+    }
+
+    #[test]
+    fn sections_of_exe() {
+        let file = include_bytes!("../../test/exe");
+
+        let header = Header::parse(file).unwrap();
+
+        let sections = SectionHeaders::new(file, &header).unwrap();
+        assert_eq!(sections.num(), header.shnum() as usize);
+
+        let mut remaining = sections.clone();
+        remaining.next().unwrap();
+        assert_eq!(remaining.len(), sections.num() - 1);
+        assert_eq!(remaining.num(), sections.num());
+
+        let text = sections.by_name(".text").unwrap();
+        assert_eq!(text.r#type(), sh::r#type::PROGBITS);
+        assert_eq!(text.flags(), sh::flags::ALLOC | sh::flags::EXECINSTR);
+
+        let bss = sections.by_name(".bss").unwrap();
+        assert_eq!(bss.data(file).unwrap(), &[]);
+
+        let names: Vec<_> = sections.map(|section| section.name().unwrap()).collect();
+        assert_eq!(names[..3], ["", ".interp", ".note.gnu.property"]);
+        assert_eq!(names.last(), Some(&".shstrtab"));
+    }
+
+    #[test]
+    fn compressed_contents() {
+        let plain = include_bytes!("../../test/debug4");
+        let header = Header::parse(plain).unwrap();
+        let info = SectionHeaders::new(plain, &header)
+            .unwrap()
+            .by_name(".debug_info")
+            .unwrap();
+        assert_eq!(info.compression(plain).unwrap(), None);
+
+        let expected = info.data(plain).unwrap();
+        assert!(matches!(info.contents(plain).unwrap(), Cow::Borrowed(_)));
+
+        for (file, name, r#type) in [
+            (
+                &include_bytes!("../../test/zlib")[..],
+                ".debug_info",
+                ch::r#type::ZLIB,
+            ),
+            (
+                include_bytes!("../../test/zstd"),
+                ".debug_info",
+                ch::r#type::ZSTD,
+            ),
+            (
+                include_bytes!("../../test/zdebug"),
+                ".zdebug_info",
+                ch::r#type::ZLIB,
+            ),
+        ] {
+            let header = Header::parse(file).unwrap();
+            let section = SectionHeaders::new(file, &header)
+                .unwrap()
+                .by_name(name)
+                .unwrap();
+
+            let compression = section.compression(file).unwrap().unwrap();
+            assert_eq!(compression.r#type(), r#type);
+            assert_eq!(compression.size(), expected.len() as u64);
+            assert!(section.size() < compression.size());
+
+            assert_eq!(&section.contents(file).unwrap()[..], expected, "{name}");
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::elf::common::{self, p};
+use crate::elf::header::{Error, Header};
+use crate::elf::reader::Reader;
+use crate::input::Data;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::slice::ChunksExact;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProgramHeader<'a> {
+    header: &'a [u8],
+    reader: Reader,
+}
+
+impl<'a> From<(&'a [u8], Reader)> for ProgramHeader<'a> {
+    fn from((header, reader): (&'a [u8], Reader)) -> Self {
+        Self { header, reader }
+    }
+}
+
+impl Deref for ProgramHeader<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.header
+    }
+}
+
+impl Display for ProgramHeader<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "type={} ", common::p_type_name(self.r#type()))?;
+        write!(f, "offset={:#08X} ", self.offset())?;
+        write!(f, "vaddr={:#08X} ", self.vaddr())?;
+        write!(f, "paddr={:#08X} ", self.paddr())?;
+        write!(f, "filesz={:#08X} ", self.filesz())?;
+        write!(f, "memsz={:#08X} ", self.memsz())?;
+        let flag = |mask, c| if self.flags() & mask != 0 { c } else { ' ' };
+        write!(
+            f,
+            "flags={}{}{} ",
+            flag(p::flags::R, 'R'),
+            flag(p::flags::W, 'W'),
+            flag(p::flags::X, 'E'),
+        )?;
+        write!(f, "align={:#X}", self.align())
+    }
+}
+
+impl<'a> ProgramHeader<'a> {
+    /// Size of `Elf32_Phdr`/`Elf64_Phdr` for the class in `reader`.
+    pub fn entsize_of(reader: &Reader) -> usize {
+        reader.by_class(0x20, 0x38)
+    }
+
+    pub fn r#type(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(p::TYPE)..])
+    }
+
+    pub fn flags(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(p::FLAGS)..])
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::OFFSET)..])
+    }
+
+    pub fn vaddr(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::VADDR)..])
+    }
+
+    pub fn paddr(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::PADDR)..])
+    }
+
+    pub fn filesz(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::FILESZ)..])
+    }
+
+    pub fn memsz(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::MEMSZ)..])
+    }
+
+    pub fn align(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(p::ALIGN)..])
+    }
+
+    /// Contents of the segment in `file`, the part of `memsz` past `filesz` is not backed by it.
+    pub fn data(&self, file: impl Into<Data<'a>>) -> Result<&'a [u8], Error> {
+        usize::try_from(self.offset())
+            .ok()
+            .zip(usize::try_from(self.filesz()).ok())
+            .and_then(|(start, size)| file.into().get(start..start.checked_add(size)?))
+            .ok_or_else(|| {
+                Error::Segment(format!(
+                    "contents of {} are out of the file bounds",
+                    common::p_type_name(self.r#type())
+                ))
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProgramHeaders<'a> {
+    entries: ChunksExact<'a, u8>,
+    reader: Reader,
+}
+
+impl<'a> ProgramHeaders<'a> {
+    pub fn new(file: impl Into<Data<'a>>, header: &Header<'a>) -> Result<Self, Error> {
+        let file = file.into();
+
+        let reader = header.reader();
+
+        let entsize = header.phentzise() as usize;
+        let num = header.phnum() as usize;
+
+        if num == 0 {
+            return Ok(Self {
+                entries: [].chunks_exact(1),
+                reader,
+            });
+        }
+
+        if entsize < ProgramHeader::entsize_of(&reader) {
+            return Err(Error::Header(format!(
+                "phentsize {entsize} is smaller than {}",
+                ProgramHeader::entsize_of(&reader)
+            )));
+        }
+
+        let table = usize::try_from(header.phoff())
+            .ok()
+            .and_then(|start| Some(start..start.checked_add(entsize.checked_mul(num)?)?))
+            .and_then(|range| file.get(range))
+            .ok_or_else(|| Error::Segment("table is out of the file bounds".into()))?;
+
+        Ok(Self {
+            entries: table.chunks_exact(entsize),
+            reader,
+        })
+    }
+}
+
+impl ProgramHeaders<'_> {
+    /// File offset backing the virtual address `vaddr`, if any `PT_LOAD` segment maps it.
+    pub fn translate(self, vaddr: u64) -> Option<u64> {
+        self.filter(|segment| segment.r#type() == p::r#type::LOAD)
+            .find(|segment| vaddr >= segment.vaddr() && vaddr - segment.vaddr() < segment.filesz())
+            .and_then(|segment| segment.offset().checked_add(vaddr - segment.vaddr()))
+    }
+}
+
+impl<'a> Iterator for ProgramHeaders<'a> {
+    type Item = ProgramHeader<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| (entry, self.reader).into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ProgramHeaders<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::ProgramHeader;
+    use crate::elf::common::p;
+    use crate::elf::header::{Ident, RawIdent};
+
+    #[rustfmt::skip]
+    const IDENT_64_LE: [u8; 0x10] = [0x7F, 0x45, 0x4C, 0x46, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    #[rustfmt::skip]
+    const IDENT_32_BE: [u8; 0x10] = [0x7F, 0x45, 0x4C, 0x46, 0x01, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    #[test]
+    fn load_segment_64_le() {
+        let ident: Ident = RawIdent(&IDENT_64_LE).try_into().unwrap();
+
+        #[rustfmt::skip]
+        let phdr: [u8; 0x38] = [
+            0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x85, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x90, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let phdr: ProgramHeader = (phdr.as_slice(), ident.reader()).into();
+
+        assert_eq!(phdr.r#type(), p::r#type::LOAD);
+        assert_eq!(phdr.flags(), p::flags::R | p::flags::X);
+        assert_eq!(phdr.offset(), 0x1000);
+        assert_eq!(phdr.vaddr(), 0x2000);
+        assert_eq!(phdr.paddr(), 0x3000);
+        assert_eq!(phdr.filesz(), 0x185);
+        assert_eq!(phdr.memsz(), 0x190);
+        assert_eq!(phdr.align(), 0x1000);
+    }
+
+    #[test]
+    fn load_segment_32_be() {
+        let ident: Ident = RawIdent(&IDENT_32_BE).try_into().unwrap();
+
+        #[rustfmt::skip]
+        let phdr: [u8; 0x20] = [
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x30, 0x00,
+            0x00, 0x00, 0x01, 0x85, 0x00, 0x00, 0x01, 0x90,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00,
+        ];
+
+        let phdr: ProgramHeader = (phdr.as_slice(), ident.reader()).into();
+
+        assert_eq!(phdr.r#type(), p::r#type::LOAD);
+        assert_eq!(phdr.flags(), p::flags::R | p::flags::W);
+        assert_eq!(phdr.offset(), 0x1000);
+        assert_eq!(phdr.vaddr(), 0x2000);
+        assert_eq!(phdr.paddr(), 0x3000);
+        assert_eq!(phdr.filesz(), 0x185);
+        assert_eq!(phdr.memsz(), 0x190);
+        assert_eq!(phdr.align(), 0x10000);
+    }
+}
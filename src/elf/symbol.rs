@@ -0,0 +1,408 @@
+use crate::elf::common::{self, sh, shn, st};
+use crate::elf::header::Error;
+use crate::elf::reader::Reader;
+use crate::elf::section::{SectionHeaders, StringTable};
+use crate::elf::version::{Version, Versions};
+use crate::input::Data;
+use std::fmt::Display;
+use std::ops::Deref;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Symbol<'a> {
+    symbol: &'a [u8],
+    reader: Reader,
+    name: Option<&'a str>,
+    xindex: Option<u32>,
+    version: Option<Version<'a>>,
+}
+
+impl<'a> From<(&'a [u8], Reader, Option<&'a str>, Option<u32>)> for Symbol<'a> {
+    fn from(
+        (symbol, reader, name, xindex): (&'a [u8], Reader, Option<&'a str>, Option<u32>),
+    ) -> Self {
+        Self {
+            symbol,
+            reader,
+            name,
+            xindex,
+            version: None,
+        }
+    }
+}
+
+impl Deref for Symbol<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.symbol
+    }
+}
+
+impl Display for Symbol<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value={:#018X} ", self.value())?;
+        write!(f, "size={:<5} ", self.size())?;
+        write!(f, "type={:<7} ", common::st_type_name(self.r#type()))?;
+        write!(f, "bind={:<6} ", common::st_bind_name(self.bind()))?;
+        write!(
+            f,
+            "vis={:<9} ",
+            common::st_visibility_name(self.visibility())
+        )?;
+
+        match self.shndx() {
+            shn::UNDEF => write!(f, "ndx=UND ")?,
+            shn::ABS => write!(f, "ndx=ABS ")?,
+            shn::COMMON => write!(f, "ndx=COM ")?,
+            shn::XINDEX => match self.xindex {
+                Some(index) => write!(f, "ndx={:<3} ", index)?,
+                None => write!(f, "ndx=<corrupt> ")?,
+            },
+            shndx @ shn::LORESERVE.. => write!(f, "ndx={:#06X} ", shndx)?,
+            shndx => write!(f, "ndx={:<3} ", shndx)?,
+        }
+
+        write!(f, "name={}", self.name().unwrap_or("<corrupt>"))?;
+
+        match self.versioned() {
+            Some((separator, version)) => write!(f, "{separator}{version}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Symbol<'a> {
+    /// Size of `Elf32_Sym`/`Elf64_Sym` for the class in `reader`.
+    pub fn entsize_of(reader: &Reader) -> usize {
+        reader.by_class(0x10, 0x18)
+    }
+
+    /// Name resolved through the string table linked to the symbol table, `None` if `st_name` is
+    /// not a valid offset into it.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    pub fn name_offset(&self) -> u32 {
+        self.reader.u32(&self[self.reader.at(st::NAME)..])
+    }
+
+    pub fn value(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(st::VALUE)..])
+    }
+
+    pub fn size(&self) -> u64 {
+        self.reader.word(&self[self.reader.at(st::SIZE)..])
+    }
+
+    pub fn info(&self) -> u8 {
+        self[self.reader.at(st::INFO)]
+    }
+
+    pub fn other(&self) -> u8 {
+        self[self.reader.at(st::OTHER)]
+    }
+
+    /// Raw `st_shndx`, which may be one of the special `SHN_*` indexes.
+    pub fn shndx(&self) -> u16 {
+        self.reader.u16(&self[self.reader.at(st::SHNDX)..])
+    }
+
+    pub fn bind(&self) -> u8 {
+        self.info() >> 4
+    }
+
+    pub fn r#type(&self) -> u8 {
+        self.info() & 0x0F
+    }
+
+    pub fn visibility(&self) -> u8 {
+        self.other() & 0x03
+    }
+
+    /// Index of the section the symbol is defined in, looking through `SHN_XINDEX` into the
+    /// `SHT_SYMTAB_SHNDX` table. `None` for undefined, absolute and common symbols.
+    pub fn section_index(&self) -> Option<u32> {
+        match self.shndx() {
+            shn::UNDEF => None,
+            shn::XINDEX => self.xindex,
+            shn::LORESERVE.. => None,
+            shndx => Some(shndx.into()),
+        }
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        self.shndx() == shn::UNDEF
+    }
+
+    /// Version from `.gnu.version`, only dynamic symbols of objects using symbol versioning have
+    /// one.
+    pub fn version(&self) -> Option<Version<'a>> {
+        self.version
+    }
+
+    /// Version to append to the name with the separator binutils uses, `@@` for the default
+    /// version of a definition and `@` for hidden and needed ones. A needed version stays `@`
+    /// even for a defined symbol, e.g. one copy relocated into the executable. `None` when there
+    /// is no version to show.
+    pub fn versioned(&self) -> Option<(&'static str, Version<'a>)> {
+        match self.version {
+            // Symbols named after the version they define, e.g. `LIB_1.0`, are left as is
+            Some(version) if version.is_named() && version.name() != self.name() => {
+                match self.is_undefined() || version.is_hidden() || version.file().is_some() {
+                    true => Some(("@", version)),
+                    false => Some(("@@", version)),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbols<'a> {
+    table: &'a [u8],
+    entsize: usize,
+    index: usize,
+    reader: Reader,
+    strtab: StringTable<'a>,
+    xindex: &'a [u8],
+    versions: Option<Versions<'a>>,
+    name: Option<&'a str>,
+}
+
+impl<'a> Symbols<'a> {
+    /// Symbols of the `SHT_SYMTAB` or `SHT_DYNSYM` section at `index`.
+    pub fn new(
+        file: impl Into<Data<'a>>,
+        sections: &SectionHeaders<'a>,
+        index: usize,
+    ) -> Result<Self, Error> {
+        let file = file.into();
+
+        let section = sections
+            .get(index)
+            .ok_or_else(|| Error::Symbol(format!("no section at index {index}")))?;
+
+        if !matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM) {
+            return Err(Error::Symbol(format!(
+                "{} is not a symbol table",
+                section.name().unwrap_or("<corrupt>")
+            )));
+        }
+
+        let reader = section.reader();
+        let entsize = match section.entsize() as usize {
+            0 => Symbol::entsize_of(&reader),
+            entsize if entsize < Symbol::entsize_of(&reader) => {
+                return Err(Error::Symbol(format!(
+                    "sh_entsize {entsize} is smaller than {}",
+                    Symbol::entsize_of(&reader)
+                )))
+            }
+            entsize => entsize,
+        };
+
+        let strtab = sections
+            .get(section.link() as usize)
+            .filter(|strtab| strtab.r#type() == sh::r#type::STRTAB)
+            .ok_or_else(|| Error::Symbol("sh_link is not a string table".into()))?;
+
+        let xindex = sections
+            .clone()
+            .find(|shndx| {
+                shndx.r#type() == sh::r#type::SYMTAB_SHNDX && shndx.link() as usize == index
+            })
+            .map(|shndx| shndx.data(file))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            table: section.data(file)?,
+            entsize,
+            index: 0,
+            reader,
+            strtab: StringTable(strtab.data(file)?),
+            xindex,
+            versions: Versions::new(file, sections, index)?,
+            name: section.name(),
+        })
+    }
+
+    /// Symbols of the first section of `type`, either `SHT_SYMTAB` or `SHT_DYNSYM`.
+    pub fn by_type(
+        file: impl Into<Data<'a>>,
+        sections: &SectionHeaders<'a>,
+        r#type: u32,
+    ) -> Result<Option<Self>, Error> {
+        let file = file.into();
+
+        sections
+            .clone()
+            .position(|section| section.r#type() == r#type)
+            .map(|index| Self::new(file, sections, index))
+            .transpose()
+    }
+
+    /// Name of the section holding the symbol table.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// Versions of the symbols, see [`Symbol::version`].
+    pub fn versions(&self) -> Option<&Versions<'a>> {
+        self.versions.as_ref()
+    }
+
+    /// Number of symbols in the whole table, not only the ones left to iterate.
+    pub fn num(&self) -> usize {
+        self.table.len() / self.entsize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<Symbol<'a>> {
+        let start = index.checked_mul(self.entsize)?;
+        let symbol = self.table.get(start..start + self.entsize)?;
+        let name = self.strtab.get(self.reader.u32(symbol) as usize);
+        let xindex = self
+            .xindex
+            .get(index * 0x04..index * 0x04 + 0x04)
+            .map(|xindex| self.reader.u32(xindex));
+
+        let mut symbol: Symbol = (symbol, self.reader, name, xindex).into();
+        symbol.version = self
+            .versions
+            .as_ref()
+            .and_then(|versions| versions.get(index));
+
+        Some(symbol)
+    }
+}
+
+impl<'a> Iterator for Symbols<'a> {
+    type Item = Symbol<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.get(self.index)?;
+        self.index += 1;
+        Some(symbol)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Symbols<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::Symbols;
+    use crate::elf::common::{sh, shn, stb, stt, stv};
+    use crate::elf::header::Header;
+    use crate::elf::section::SectionHeaders;
+
+    #[test]
+    fn symbols_of_lib_o() {
+        let file = include_bytes!("../../test/lib.o");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let symbols = Symbols::by_type(file, &sections, sh::r#type::SYMTAB)
+            .unwrap()
+            .unwrap();
+        assert_eq!(symbols.name(), Some(".symtab"));
+
+        let foo = symbols
+            .clone()
+            .find(|symbol| symbol.name() == Some("foo"))
+            .unwrap();
+        assert_eq!(foo.bind(), stb::GLOBAL);
+        assert_eq!(foo.r#type(), stt::FUNC);
+        assert_eq!(foo.visibility(), stv::DEFAULT);
+        assert_eq!(
+            sections
+                .get(foo.section_index().unwrap() as usize)
+                .unwrap()
+                .name(),
+            Some(".text")
+        );
+
+        let printf = symbols
+            .clone()
+            .find(|symbol| symbol.name() == Some("printf"))
+            .unwrap();
+        assert_eq!(printf.shndx(), shn::UNDEF);
+        assert_eq!(printf.section_index(), None);
+
+        let file = symbols
+            .clone()
+            .find(|symbol| symbol.r#type() == stt::FILE)
+            .unwrap();
+        assert_eq!(file.name(), Some("lib.c"));
+        assert_eq!(file.shndx(), shn::ABS);
+    }
+
+    #[test]
+    fn symbols_of_ppc_o() {
+        // ELFv1 function symbols point at their descriptor in .opd rather than at the code
+        for (file, section) in [
+            (&include_bytes!("../../test/ppc.o")[..], ".text"),
+            (&include_bytes!("../../test/ppc64.o")[..], ".opd"),
+        ] {
+            let header = Header::parse(file).unwrap();
+            let sections = SectionHeaders::new(file, &header).unwrap();
+
+            let symbols = Symbols::by_type(file, &sections, sh::r#type::SYMTAB)
+                .unwrap()
+                .unwrap();
+
+            let foo = symbols
+                .clone()
+                .find(|symbol| symbol.name() == Some("foo"))
+                .unwrap();
+            assert_eq!(foo.bind(), stb::GLOBAL);
+            assert_eq!(foo.r#type(), stt::FUNC);
+            assert_eq!(
+                sections
+                    .get(foo.section_index().unwrap() as usize)
+                    .unwrap()
+                    .name(),
+                Some(section)
+            );
+        }
+    }
+
+    #[test]
+    fn versions_of_copy() {
+        let file = include_bytes!("../../test/copy");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let symbols = Symbols::by_type(file, &sections, sh::r#type::DYNSYM)
+            .unwrap()
+            .unwrap();
+
+        // Copy relocated into .bss, but still the version libc.so.6 defines
+        let stdout = symbols
+            .clone()
+            .find(|symbol| symbol.name() == Some("stdout"))
+            .unwrap();
+        assert!(!stdout.is_undefined());
+        assert!(stdout.to_string().ends_with(" name=stdout@GLIBC_2.2.5"));
+
+        // gcc turns the fputs of a literal into fwrite
+        let fwrite = symbols
+            .clone()
+            .find(|symbol| symbol.name() == Some("fwrite"))
+            .unwrap();
+        assert!(fwrite.is_undefined());
+        assert!(fwrite.to_string().ends_with(" name=fwrite@GLIBC_2.2.5"));
+    }
+}
@@ -0,0 +1,509 @@
+use crate::elf::common::sh;
+use crate::elf::header::Error;
+use crate::elf::reader::Reader;
+use crate::elf::section::{SectionHeaders, StringTable};
+use crate::input::Data;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// `VER_NDX_LOCAL`, the symbol is not available outside the object.
+pub const LOCAL: u16 = 0x0000;
+/// `VER_NDX_GLOBAL`, the symbol is available under the base version.
+pub const GLOBAL: u16 = 0x0001;
+/// Set on `.gnu.version` entries of symbols only visible under their exact version.
+pub const HIDDEN: u16 = 0x8000;
+/// `VER_FLG_BASE`, the definition names the object itself.
+pub const FLG_BASE: u16 = 0x0001;
+/// `VER_FLG_WEAK`, the reference is not an error when missing.
+pub const FLG_WEAK: u16 = 0x0002;
+
+/// Version a symbol is bound to, resolved through `.gnu.version_d` for definitions and
+/// `.gnu.version_r` for references.
+#[derive(Debug, Clone, Copy)]
+pub struct Version<'a> {
+    index: u16,
+    hidden: bool,
+    name: Option<&'a str>,
+    file: Option<&'a str>,
+}
+
+impl Display for Version<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.index {
+            LOCAL => write!(f, "*local*"),
+            GLOBAL => write!(f, "*global*"),
+            _ => write!(f, "{}", self.name.unwrap_or("<corrupt>")),
+        }
+    }
+}
+
+impl<'a> Version<'a> {
+    /// Index into the version tables, without the hidden bit.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Name of the version, `None` for `VER_NDX_LOCAL`, `VER_NDX_GLOBAL` or if the index is not
+    /// in any of the tables.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// Library the version is required from, `None` for versions defined by the object.
+    pub fn file(&self) -> Option<&'a str> {
+        self.file
+    }
+
+    /// Whether the version is one of the tables' own, as opposed to local or global.
+    pub fn is_named(&self) -> bool {
+        self.index > GLOBAL
+    }
+}
+
+/// An `ElfN_Verdef` entry, with the name taken from its first `ElfN_Verdaux`.
+#[derive(Debug, Clone, Copy)]
+pub struct VersionDefinition<'a> {
+    flags: u16,
+    index: u16,
+    count: u16,
+    hash: u32,
+    name: Option<&'a str>,
+    parent: Option<&'a str>,
+}
+
+impl<'a> VersionDefinition<'a> {
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Number of `ElfN_Verdaux` entries, the name and its parents.
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    pub fn hash(&self) -> u32 {
+        self.hash
+    }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// Version this one inherits from, e.g. `LIB_1.0` for `LIB_2.0 { ... } LIB_1.0;`.
+    pub fn parent(&self) -> Option<&'a str> {
+        self.parent
+    }
+
+    /// Whether the definition names the object itself rather than a version.
+    pub fn is_base(&self) -> bool {
+        self.flags & FLG_BASE != 0
+    }
+}
+
+/// Entries of a `SHT_GNU_verdef` section, chained through `vd_next`.
+#[derive(Debug, Clone)]
+pub struct VersionDefinitions<'a> {
+    data: &'a [u8],
+    reader: Reader,
+    strtab: StringTable<'a>,
+    offset: Option<usize>,
+}
+
+impl<'a> Iterator for VersionDefinitions<'a> {
+    type Item = VersionDefinition<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset.take()?;
+        let verdef = self.data.get(offset..offset.checked_add(0x14)?)?;
+
+        let count = self.reader.u16(&verdef[0x06..]);
+        let aux = offset.checked_add(self.reader.u32(&verdef[0x0C..]) as usize)?;
+        let next = self.reader.u32(&verdef[0x10..]) as usize;
+
+        let verdaux = |offset: usize| {
+            let verdaux = self.data.get(offset..offset.checked_add(0x08)?)?;
+            Some((
+                self.strtab.get(self.reader.u32(&verdaux[0x00..]) as usize),
+                self.reader.u32(&verdaux[0x04..]) as usize,
+            ))
+        };
+
+        let (name, parent) = match verdaux(aux) {
+            Some((name, next)) if count > 1 && next != 0 => (
+                name,
+                aux.checked_add(next).and_then(verdaux).and_then(|p| p.0),
+            ),
+            Some((name, _)) => (name, None),
+            None => (None, None),
+        };
+
+        // vd_next is relative and unsigned, so the chain only moves forward until it is zero
+        self.offset = match next {
+            0 => None,
+            next => offset.checked_add(next),
+        };
+
+        Some(VersionDefinition {
+            flags: self.reader.u16(&verdef[0x02..]),
+            index: self.reader.u16(&verdef[0x04..]),
+            count,
+            hash: self.reader.u32(&verdef[0x08..]),
+            name,
+            parent,
+        })
+    }
+}
+
+/// An `ElfN_Vernaux` entry, a version required from the library of its `VersionNeed`.
+#[derive(Debug, Clone, Copy)]
+pub struct NeededVersion<'a> {
+    hash: u32,
+    flags: u16,
+    index: u16,
+    name: Option<&'a str>,
+}
+
+impl<'a> NeededVersion<'a> {
+    pub fn hash(&self) -> u32 {
+        self.hash
+    }
+
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// `vna_other`, the index `.gnu.version` entries refer to the version by.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    pub fn is_weak(&self) -> bool {
+        self.flags & FLG_WEAK != 0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NeededVersions<'a> {
+    data: &'a [u8],
+    reader: Reader,
+    strtab: StringTable<'a>,
+    offset: Option<usize>,
+    remaining: u16,
+}
+
+impl<'a> Iterator for NeededVersions<'a> {
+    type Item = NeededVersion<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+
+        let offset = self.offset.take()?;
+        let vernaux = self.data.get(offset..offset.checked_add(0x10)?)?;
+
+        self.offset = match self.reader.u32(&vernaux[0x0C..]) as usize {
+            0 => None,
+            next => offset.checked_add(next),
+        };
+
+        Some(NeededVersion {
+            hash: self.reader.u32(&vernaux[0x00..]),
+            flags: self.reader.u16(&vernaux[0x04..]),
+            index: self.reader.u16(&vernaux[0x06..]),
+            name: self.strtab.get(self.reader.u32(&vernaux[0x08..]) as usize),
+        })
+    }
+}
+
+/// An `ElfN_Verneed` entry, the versions required from one `DT_NEEDED` library.
+#[derive(Debug, Clone, Copy)]
+pub struct VersionNeed<'a> {
+    data: &'a [u8],
+    reader: Reader,
+    strtab: StringTable<'a>,
+    file: Option<&'a str>,
+    count: u16,
+    aux: Option<usize>,
+}
+
+impl<'a> VersionNeed<'a> {
+    pub fn file(&self) -> Option<&'a str> {
+        self.file
+    }
+
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    pub fn versions(&self) -> NeededVersions<'a> {
+        NeededVersions {
+            data: self.data,
+            reader: self.reader,
+            strtab: self.strtab,
+            offset: self.aux,
+            remaining: self.count,
+        }
+    }
+
+    /// Highest of the required versions, comparing the dotted numbers after the last `_`, so
+    /// `GLIBC_2.34` wins over `GLIBC_2.2.5`. Versions without one, e.g. `GLIBC_PRIVATE`, are not
+    /// considered.
+    pub fn max_version(&self) -> Option<&'a str> {
+        self.versions()
+            .filter_map(|version| version.name())
+            .filter_map(|name| Some((numbers(name)?, name)))
+            .max()
+            .map(|(_, name)| name)
+    }
+}
+
+/// Entries of a `SHT_GNU_verneed` section, chained through `vn_next`.
+#[derive(Debug, Clone)]
+pub struct VersionNeeds<'a> {
+    data: &'a [u8],
+    reader: Reader,
+    strtab: StringTable<'a>,
+    offset: Option<usize>,
+}
+
+impl<'a> Iterator for VersionNeeds<'a> {
+    type Item = VersionNeed<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset.take()?;
+        let verneed = self.data.get(offset..offset.checked_add(0x10)?)?;
+
+        self.offset = match self.reader.u32(&verneed[0x0C..]) as usize {
+            0 => None,
+            next => offset.checked_add(next),
+        };
+
+        Some(VersionNeed {
+            data: self.data,
+            reader: self.reader,
+            strtab: self.strtab,
+            file: self.strtab.get(self.reader.u32(&verneed[0x04..]) as usize),
+            count: self.reader.u16(&verneed[0x02..]),
+            aux: offset.checked_add(self.reader.u32(&verneed[0x08..]) as usize),
+        })
+    }
+}
+
+/// Symbol versions of a `SHT_DYNSYM` table, `.gnu.version` along with the `.gnu.version_d` and
+/// `.gnu.version_r` tables its entries index into.
+#[derive(Debug, Clone)]
+pub struct Versions<'a> {
+    versym: &'a [u8],
+    reader: Reader,
+    definitions: VersionDefinitions<'a>,
+    needs: VersionNeeds<'a>,
+    /// Name and library of each version index, looked up once per symbol.
+    names: HashMap<u16, (Option<&'a str>, Option<&'a str>)>,
+}
+
+impl<'a> Versions<'a> {
+    /// Versions of the symbols in the symbol table at `index`, `None` if it has no
+    /// `SHT_GNU_versym` section.
+    pub fn new(
+        file: impl Into<Data<'a>>,
+        sections: &SectionHeaders<'a>,
+        index: usize,
+    ) -> Result<Option<Self>, Error> {
+        let file = file.into();
+
+        let Some(versym) = sections.clone().find(|section| {
+            section.r#type() == sh::r#type::GNU_VERSYM && section.link() as usize == index
+        }) else {
+            return Ok(None);
+        };
+
+        let reader = versym.reader();
+
+        let table = |r#type: u32| -> Result<_, Error> {
+            let Some(section) = sections.clone().find(|section| section.r#type() == r#type) else {
+                return Ok((&[][..], StringTable::default(), None));
+            };
+
+            let strtab = sections
+                .get(section.link() as usize)
+                .filter(|strtab| strtab.r#type() == sh::r#type::STRTAB)
+                .ok_or_else(|| Error::Version("sh_link is not a string table".into()))?;
+
+            Ok((
+                section.data(file)?,
+                StringTable(strtab.data(file)?),
+                Some(0),
+            ))
+        };
+
+        let (data, strtab, offset) = table(sh::r#type::GNU_VERDEF)?;
+        let definitions = VersionDefinitions {
+            data,
+            reader,
+            strtab,
+            offset,
+        };
+
+        let (data, strtab, offset) = table(sh::r#type::GNU_VERNEED)?;
+        let needs = VersionNeeds {
+            data,
+            reader,
+            strtab,
+            offset,
+        };
+
+        // Definitions take precedence over needs with the same index, and the first entry of
+        // either one over the next
+        let mut names = HashMap::new();
+        for definition in definitions.clone() {
+            names
+                .entry(definition.index())
+                .or_insert((definition.name(), None));
+        }
+        for need in needs.clone() {
+            for needed in need.versions() {
+                names
+                    .entry(needed.index())
+                    .or_insert((needed.name(), need.file()));
+            }
+        }
+
+        Ok(Some(Self {
+            versym: versym.data(file)?,
+            reader,
+            definitions,
+            needs,
+            names,
+        }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.versym.len() / 0x02
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Entries of `.gnu.version_d`, the versions the object defines.
+    pub fn definitions(&self) -> VersionDefinitions<'a> {
+        self.definitions.clone()
+    }
+
+    /// Entries of `.gnu.version_r`, the versions the object requires, by library.
+    pub fn needs(&self) -> VersionNeeds<'a> {
+        self.needs.clone()
+    }
+
+    /// Version of the symbol at `index` in the symbol table.
+    pub fn get(&self, index: usize) -> Option<Version<'a>> {
+        let start = index.checked_mul(0x02)?;
+        let versym = self.reader.u16(self.versym.get(start..start + 0x02)?);
+
+        let mut version = Version {
+            index: versym & !HIDDEN,
+            hidden: versym & HIDDEN != 0,
+            name: None,
+            file: None,
+        };
+
+        if !version.is_named() {
+            return Some(version);
+        }
+
+        if let Some(&(name, file)) = self.names.get(&version.index) {
+            version.name = name;
+            version.file = file;
+        }
+
+        Some(version)
+    }
+
+    /// Highest version required from each library, see [`VersionNeed::max_version`].
+    pub fn max_required(&self) -> Vec<(&'a str, &'a str)> {
+        self.needs()
+            .filter_map(|need| Some((need.file()?, need.max_version()?)))
+            .collect()
+    }
+}
+
+fn numbers(name: &str) -> Option<Vec<u32>> {
+    let (_, numbers) = name.rsplit_once('_')?;
+
+    numbers
+        .split('.')
+        .map(|number| number.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::Versions;
+    use crate::elf::common::sh;
+    use crate::elf::header::Header;
+    use crate::elf::section::SectionHeaders;
+
+    #[test]
+    fn versions_of_versioned_so() {
+        let file = include_bytes!("../../test/versioned.so");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let dynsym = sections
+            .clone()
+            .position(|section| section.r#type() == sh::r#type::DYNSYM)
+            .unwrap();
+        let versions = Versions::new(file, &sections, dynsym).unwrap().unwrap();
+
+        let definitions: Vec<_> = versions.definitions().collect();
+        assert_eq!(definitions.len(), 3);
+        assert!(definitions[0].is_base());
+        assert_eq!(definitions[0].name(), Some("versioned.so"));
+        assert_eq!(definitions[2].name(), Some("LIB_2.0"));
+        assert_eq!(definitions[2].parent(), Some("LIB_1.0"));
+
+        // printf@GLIBC_2.2.5 and foo@@LIB_2.0
+        let printf = versions.get(2).unwrap();
+        assert_eq!(printf.name(), Some("GLIBC_2.2.5"));
+        assert_eq!(printf.file(), Some("libc.so.6"));
+
+        let foo = versions.get(7).unwrap();
+        assert_eq!(foo.name(), Some("LIB_2.0"));
+        assert_eq!(foo.file(), None);
+        assert!(!foo.is_hidden());
+
+        assert!(!versions.get(1).unwrap().is_named());
+        assert!(versions.get(9).is_none());
+
+        assert_eq!(versions.max_required(), [("libc.so.6", "GLIBC_2.2.5")]);
+    }
+
+    #[test]
+    fn max_required_of_exe() {
+        let file = include_bytes!("../../test/exe");
+
+        let header = Header::parse(file).unwrap();
+        let sections = SectionHeaders::new(file, &header).unwrap();
+
+        let dynsym = sections
+            .clone()
+            .position(|section| section.r#type() == sh::r#type::DYNSYM)
+            .unwrap();
+        let versions = Versions::new(file, &sections, dynsym).unwrap().unwrap();
+
+        // GLIBC_2.34 (__libc_start_main) is newer than GLIBC_2.2.5 (printf)
+        assert_eq!(versions.max_required(), [("libc.so.6", "GLIBC_2.34")]);
+    }
+}
@@ -0,0 +1,312 @@
+use memmap2::Mmap;
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+use std::slice;
+
+/// Bytes that are only read once a range of them is asked for.
+pub trait Source {
+    fn len(&self) -> usize;
+
+    /// Bytes in `range`, `None` if it is out of bounds or could not be read.
+    fn get(&self, range: Range<usize>) -> Option<&[u8]>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The bytes structures are decoded from, either all in memory or behind a [`Source`].
+#[derive(Clone, Copy)]
+pub enum Data<'a> {
+    Bytes(&'a [u8]),
+    Source(&'a dyn Source),
+}
+
+impl<'a> Data<'a> {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Bytes(bytes) => bytes.len(),
+            Self::Source(source) => source.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Bytes in `range`, `None` if it is out of bounds or could not be read.
+    pub fn get(&self, range: Range<usize>) -> Option<&'a [u8]> {
+        match *self {
+            Self::Bytes(bytes) => bytes.get(range),
+            Self::Source(source) => source.get(range),
+        }
+    }
+}
+
+impl Debug for Data<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bytes(bytes) => write!(f, "Bytes({})", bytes.len()),
+            Self::Source(source) => write!(f, "Source({})", source.len()),
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for Data<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for Data<'a> {
+    fn from(bytes: &'a [u8; N]) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for Data<'a> {
+    fn from(bytes: &'a Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl<'a, R: Read + Seek> From<&'a Lazy<R>> for Data<'a> {
+    fn from(lazy: &'a Lazy<R>) -> Self {
+        Self::Source(lazy)
+    }
+}
+
+impl<'a> From<&'a Input> for Data<'a> {
+    fn from(input: &'a Input) -> Self {
+        input.data()
+    }
+}
+
+/// Reads from `R` in blocks of `BLOCK` bytes, the first time a range overlapping them is asked
+/// for.
+///
+/// Only the blocks that are read get a buffer. A range over several blocks is read into a
+/// buffer of its own so it can be handed out as one slice, and kept for the next ranges over
+/// the same blocks. Buffers are boxed and never written again nor freed once read, so the
+/// slices handed out stay valid for as long as the `Lazy` lives.
+pub struct Lazy<R> {
+    reader: RefCell<R>,
+    len: usize,
+    buffers: RefCell<HashMap<Range<usize>, Box<[u8]>>>,
+    error: RefCell<Option<io::Error>>,
+}
+
+impl<R: Read + Seek> Lazy<R> {
+    pub const BLOCK: usize = 0x1000;
+
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let len = usize::try_from(reader.seek(SeekFrom::End(0))?)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file is too large"))?;
+
+        Ok(Self {
+            reader: RefCell::new(reader),
+            len,
+            buffers: RefCell::new(HashMap::new()),
+            error: RefCell::new(None),
+        })
+    }
+
+    /// Number of bytes read so far, counting the blocks read again as part of a larger range.
+    pub fn loaded(&self) -> usize {
+        self.buffers
+            .borrow()
+            .values()
+            .map(|buffer| buffer.len())
+            .sum()
+    }
+
+    /// The last error of the reader, reported by [`Source::get`] as a `None`.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.error.borrow_mut().take()
+    }
+
+    fn read(&self, range: Range<usize>) -> io::Result<Box<[u8]>> {
+        let mut buffer = vec![0; range.len()].into_boxed_slice();
+
+        let mut reader = self.reader.borrow_mut();
+        reader.seek(SeekFrom::Start(range.start as u64))?;
+        reader.read_exact(&mut buffer)?;
+
+        Ok(buffer)
+    }
+}
+
+impl<R: Read + Seek> Source for Lazy<R> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+        if range.start > range.end || range.end > self.len {
+            return None;
+        }
+
+        if range.is_empty() {
+            return Some(&[]);
+        }
+
+        let blocks = range.start / Self::BLOCK..(range.end - 1) / Self::BLOCK + 1;
+        let start = blocks.start * Self::BLOCK;
+
+        let mut buffers = self.buffers.borrow_mut();
+        let buffer = match buffers.entry(blocks) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let end = self.len.min(entry.key().end * Self::BLOCK);
+
+                match self.read(start..end) {
+                    Ok(buffer) => entry.insert(buffer),
+                    Err(error) => {
+                        *self.error.borrow_mut() = Some(error);
+                        return None;
+                    }
+                }
+            }
+        };
+
+        let bytes = &buffer[range.start - start..range.end - start];
+
+        // SAFETY: the bytes are in a boxed buffer, which does not move with the map and is not
+        // written nor freed before the `Lazy` is dropped.
+        Some(unsafe { slice::from_raw_parts(bytes.as_ptr(), bytes.len()) })
+    }
+}
+
+/// A file opened for inspection, mapped in memory when possible.
+pub enum Input {
+    Mapped(Mmap),
+    Lazy(Lazy<File>),
+}
+
+impl Input {
+    /// Maps the file at `path`, falling back to reading it lazily for files that can not be
+    /// mapped.
+    ///
+    /// The map is only sound as long as the file is not modified while it is open, the same
+    /// assumption readelf and objdump make.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+
+        // SAFETY: see above, the file is never written through the map.
+        match unsafe { Mmap::map(&file) } {
+            Ok(map) => Ok(Self::Mapped(map)),
+            Err(_) => Ok(Self::Lazy(Lazy::new(file)?)),
+        }
+    }
+
+    /// Opens the file at `path` without mapping it, reading only what is inspected.
+    pub fn lazy(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::Lazy(Lazy::new(File::open(path)?)?))
+    }
+
+    pub fn data(&self) -> Data<'_> {
+        match self {
+            Self::Mapped(map) => Data::Bytes(map),
+            Self::Lazy(lazy) => Data::Source(lazy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Data, Input, Lazy, Source};
+    use crate::elf::Elf;
+    use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+    #[test]
+    fn lazy_reads_only_what_is_inspected() {
+        let file = include_bytes!("../test/exe");
+        let lazy = Lazy::new(Cursor::new(&file[..])).unwrap();
+
+        assert_eq!(lazy.len(), file.len());
+        assert_eq!(lazy.loaded(), 0);
+
+        let elf = Elf::parse(&lazy).unwrap();
+        let expected = Elf::parse(file).unwrap();
+
+        assert_eq!(elf.header().as_ref(), expected.header().as_ref());
+        assert!(lazy.loaded() < file.len());
+
+        let names = |elf: &Elf| {
+            elf.dynamic_symbols()
+                .unwrap()
+                .unwrap()
+                .map(|symbol| symbol.name().map(String::from))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&elf), names(&expected));
+
+        assert_eq!(lazy.get(0x00..0x04), Some(&file[0x00..0x04]));
+        assert_eq!(lazy.get(0x0FFE..0x1002), Some(&file[0x0FFE..0x1002]));
+        assert_eq!(lazy.get(file.len()..file.len() + 1), None);
+    }
+
+    /// A file of zeroes larger than the memory, made up as it is read.
+    struct Zeroes {
+        len: u64,
+        position: u64,
+    }
+
+    impl Read for Zeroes {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min((self.len - self.position) as usize);
+            buf[..n].fill(0);
+            self.position += n as u64;
+            Ok(n)
+        }
+    }
+
+    impl Seek for Zeroes {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.position = match pos {
+                SeekFrom::Start(offset) => offset,
+                SeekFrom::End(offset) => self.len.saturating_add_signed(offset),
+                SeekFrom::Current(offset) => self.position.saturating_add_signed(offset),
+            };
+            Ok(self.position)
+        }
+    }
+
+    #[test]
+    fn lazy_allocates_only_what_is_read() {
+        let len = usize::MAX as u64 / 2;
+        let lazy = Lazy::new(Zeroes { len, position: 0 }).unwrap();
+
+        let end = lazy.len();
+        assert_eq!(lazy.get(end - 0x10..end), Some(&[0; 0x10][..]));
+        assert_eq!(lazy.loaded(), end % Lazy::<Zeroes>::BLOCK);
+
+        // Over two blocks, read once more as a single buffer
+        let block = Lazy::<Zeroes>::BLOCK;
+        assert_eq!(lazy.get(block - 0x08..block + 0x08), Some(&[0; 0x10][..]));
+        assert_eq!(lazy.loaded(), end % block + 2 * block);
+        assert_eq!(lazy.get(block - 0x04..block + 0x04), Some(&[0; 0x08][..]));
+        assert_eq!(lazy.loaded(), end % block + 2 * block);
+    }
+
+    #[test]
+    fn input_of_exe() {
+        let file = include_bytes!("../test/exe");
+
+        for input in [
+            Input::open("test/exe").unwrap(),
+            Input::lazy("test/exe").unwrap(),
+        ] {
+            let data: Data = (&input).into();
+
+            assert_eq!(data.len(), file.len());
+            assert_eq!(data.get(0x40..0x80), Some(&file[0x40..0x80]));
+        }
+    }
+}
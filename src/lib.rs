@@ -1 +1,4 @@
+pub mod ar;
+pub mod dwarf;
 pub mod elf;
+pub mod input;
@@ -0,0 +1,225 @@
+use badelf::input::{Data, Input};
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[derive(Debug, Clone, Copy)]
+struct Edit {
+    offset: usize,
+    old: u8,
+    new: u8,
+    /// Identifies the state after the edit, to tell whether it was saved.
+    generation: u64,
+}
+
+enum Buffer {
+    Input(Input),
+    /// Copy of the input made on the first edit, so it is never written through a map.
+    Bytes(Vec<u8>),
+}
+
+/// The bytes of the file being inspected, with the edits made to them.
+pub struct Editor {
+    path: PathBuf,
+    buffer: Buffer,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    generation: u64,
+    saved: u64,
+}
+
+impl Editor {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            buffer: Buffer::Input(Input::open(path)?),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            generation: 0,
+            saved: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn data(&self) -> Data<'_> {
+        match &self.buffer {
+            Buffer::Input(input) => input.data(),
+            Buffer::Bytes(bytes) => Data::Bytes(bytes),
+        }
+    }
+
+    /// Identifies the bytes as edited so far, changed by every edit, undo and redo.
+    pub fn generation(&self) -> u64 {
+        self.undo.last().map_or(0, |edit| edit.generation)
+    }
+
+    /// Whether there are edits that were not saved.
+    pub fn is_dirty(&self) -> bool {
+        self.generation() != self.saved
+    }
+
+    fn bytes(&mut self) -> io::Result<&mut Vec<u8>> {
+        if let Buffer::Input(input) = &self.buffer {
+            let data = input.data();
+            let bytes = data
+                .get(0..data.len())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "failed to read"))?;
+
+            self.buffer = Buffer::Bytes(bytes.to_vec());
+        }
+
+        match &mut self.buffer {
+            Buffer::Bytes(bytes) => Ok(bytes),
+            Buffer::Input(_) => unreachable!(),
+        }
+    }
+
+    /// Sets the byte at `offset`, dropping the edits that were undone.
+    pub fn set(&mut self, offset: usize, byte: u8) -> io::Result<()> {
+        let generation = self.generation + 1;
+        let bytes = self.bytes()?;
+        let Some(old) = bytes.get_mut(offset) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "offset is out of the file",
+            ));
+        };
+
+        let edit = Edit {
+            offset,
+            old: *old,
+            new: byte,
+            generation,
+        };
+
+        *old = byte;
+        self.generation = generation;
+        self.undo.push(edit);
+        self.redo.clear();
+
+        Ok(())
+    }
+
+    /// Reverts the last edit, returning its offset.
+    pub fn undo(&mut self) -> io::Result<Option<usize>> {
+        let Some(edit) = self.undo.pop() else {
+            return Ok(None);
+        };
+
+        self.bytes()?[edit.offset] = edit.old;
+        self.redo.push(edit);
+
+        Ok(Some(edit.offset))
+    }
+
+    /// Applies the last undone edit again, returning its offset.
+    pub fn redo(&mut self) -> io::Result<Option<usize>> {
+        let Some(edit) = self.redo.pop() else {
+            return Ok(None);
+        };
+
+        self.bytes()?[edit.offset] = edit.new;
+        self.undo.push(edit);
+
+        Ok(Some(edit.offset))
+    }
+
+    /// Writes the bytes to `path`, which becomes the file being edited.
+    ///
+    /// They go to a file next to `path` first, renamed over it once fully written, so a failed
+    /// save leaves the original file as it was. An existing file keeps its permissions.
+    pub fn save(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file name"))?;
+
+        let mut temporary = OsString::from(".");
+        temporary.push(name);
+        temporary.push(format!(".{}", process::id()));
+        let temporary = path.with_file_name(temporary);
+
+        let bytes = self.bytes()?;
+        let write = || -> io::Result<()> {
+            let mut file = File::create(&temporary)?;
+            file.write_all(bytes)?;
+            file.sync_all()?;
+
+            if let Ok(metadata) = fs::metadata(path) {
+                fs::set_permissions(&temporary, metadata.permissions())?;
+            }
+
+            fs::rename(&temporary, path)
+        };
+
+        if let Err(error) = write() {
+            let _ = fs::remove_file(&temporary);
+            return Err(error);
+        }
+
+        self.path = path.to_path_buf();
+        self.saved = self.generation();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Editor;
+    use std::fs;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    #[test]
+    fn edit_undo_redo_and_save() {
+        let path = std::env::temp_dir().join(format!("badelf-edit-{}", std::process::id()));
+        let mut editor = Editor::open("test/exe").unwrap();
+        let data = |editor: &Editor| editor.data().get(0x04..0x06).unwrap().to_vec();
+
+        assert!(!editor.is_dirty());
+        assert_eq!(data(&editor), [0x02, 0x01]);
+
+        editor.set(0x04, 0x01).unwrap();
+        editor.set(0x05, 0x02).unwrap();
+        assert!(editor.is_dirty());
+        assert_eq!(data(&editor), [0x01, 0x02]);
+
+        let generation = editor.generation();
+        assert_eq!(editor.undo().unwrap(), Some(0x05));
+        assert_eq!(data(&editor), [0x01, 0x01]);
+        assert_ne!(editor.generation(), generation);
+        assert_eq!(editor.redo().unwrap(), Some(0x05));
+        assert_eq!(editor.generation(), generation);
+        assert_eq!(editor.redo().unwrap(), None);
+
+        editor.save(&path).unwrap();
+        assert!(!editor.is_dirty());
+        assert_eq!(editor.path(), path);
+        assert_eq!(fs::read(&path).unwrap()[0x04..0x06], [0x01, 0x02]);
+
+        editor.undo().unwrap();
+        assert!(editor.is_dirty());
+        editor.redo().unwrap();
+        assert!(!editor.is_dirty());
+
+        // A new edit after an undo is never the saved state, even at the same depth
+        editor.undo().unwrap();
+        editor.set(0x05, 0x02).unwrap();
+        assert!(editor.is_dirty());
+
+        assert!(editor.set(usize::MAX, 0x00).is_err());
+
+        // Saving over a file replaces it as a whole but keeps its permissions
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o751)).unwrap();
+        editor.save(&path).unwrap();
+        assert_eq!(fs::read(&path).unwrap()[0x04..0x06], [0x01, 0x02]);
+        assert_eq!(fs::metadata(&path).unwrap().mode() & 0o777, 0o751);
+
+        fs::remove_file(path).unwrap();
+    }
+}
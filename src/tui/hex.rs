@@ -0,0 +1,153 @@
+use badelf::elf::common::sh;
+use badelf::elf::Elf;
+use crossterm::style::Color;
+use std::ops::Range;
+
+/// Bytes shown per line.
+pub const WIDTH: usize = 0x10;
+
+/// The structure a byte of the file belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owner {
+    Ident,
+    Header,
+    ProgramHeaders,
+    SectionHeaders,
+    /// Contents of the section at the index.
+    Section(usize),
+    /// Padding, or anything no header points to.
+    Unclaimed,
+}
+
+impl Owner {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Owner::Ident => "ident",
+            Owner::Header => "ELF header",
+            Owner::ProgramHeaders => "program headers",
+            Owner::SectionHeaders => "section headers",
+            Owner::Section(_) => "section",
+            Owner::Unclaimed => "padding/unclaimed",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Owner::Ident => Color::Magenta,
+            Owner::Header => Color::Red,
+            Owner::ProgramHeaders => Color::Yellow,
+            Owner::SectionHeaders => Color::Cyan,
+            // Alternated so that adjacent sections can be told apart
+            Owner::Section(index) if index % 2 == 0 => Color::Green,
+            Owner::Section(_) => Color::Blue,
+            Owner::Unclaimed => Color::DarkGrey,
+        }
+    }
+}
+
+/// Where the structures of a file are, sorted by their offset.
+pub struct Layout {
+    regions: Vec<(Range<usize>, Owner)>,
+}
+
+impl Layout {
+    pub fn new(elf: &Elf) -> Self {
+        let header = elf.header();
+        let table = |offset: u64, num: usize, entsize: u16| {
+            let start = usize::try_from(offset).unwrap_or(usize::MAX);
+            start..start.saturating_add(num.saturating_mul(entsize.into()))
+        };
+
+        let mut regions = vec![
+            (0..header.ident().len(), Owner::Ident),
+            (header.ident().len()..header.len(), Owner::Header),
+            (
+                table(header.phoff(), elf.segments().len(), header.phentzise()),
+                Owner::ProgramHeaders,
+            ),
+            (
+                table(header.shoff(), elf.sections().num(), header.shentsize()),
+                Owner::SectionHeaders,
+            ),
+        ];
+
+        regions.extend(
+            elf.sections()
+                .enumerate()
+                .filter(|(_, section)| section.r#type() != sh::r#type::NOBITS)
+                .filter_map(|(index, section)| {
+                    let start = usize::try_from(section.offset()).ok()?;
+                    let end = start.checked_add(usize::try_from(section.size()).ok()?)?;
+
+                    Some((start..end, Owner::Section(index)))
+                }),
+        );
+
+        regions.retain(|(range, _)| !range.is_empty());
+        regions.sort_by_key(|(range, _)| range.start);
+
+        Self { regions }
+    }
+
+    /// Owner of the byte at `offset`, the one starting last when structures overlap.
+    pub fn owner_at(&self, offset: usize) -> Owner {
+        let end = self
+            .regions
+            .partition_point(|(range, _)| range.start <= offset);
+
+        self.regions[..end]
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(&offset))
+            .map_or(Owner::Unclaimed, |&(_, owner)| owner)
+    }
+}
+
+/// Offset of the line holding the byte at `offset`.
+pub fn line_of(offset: usize) -> usize {
+    offset / WIDTH
+}
+
+/// The ASCII column of a byte, non printable ones are shown as `.`.
+pub fn ascii(byte: u8) -> char {
+    match byte {
+        0x20..=0x7E => byte as char,
+        _ => '.',
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Layout, Owner};
+    use badelf::elf::Elf;
+
+    #[test]
+    fn layout_of_exe() {
+        let file = include_bytes!("../../test/exe");
+        let elf = Elf::parse(file).unwrap();
+        let layout = Layout::new(&elf);
+
+        assert_eq!(layout.owner_at(0x00), Owner::Ident);
+        assert_eq!(layout.owner_at(0x0F), Owner::Ident);
+        assert_eq!(layout.owner_at(0x10), Owner::Header);
+        assert_eq!(layout.owner_at(0x3F), Owner::Header);
+        assert_eq!(layout.owner_at(0x40), Owner::ProgramHeaders);
+
+        let shoff = elf.header().shoff() as usize;
+        assert_eq!(layout.owner_at(shoff), Owner::SectionHeaders);
+        assert_eq!(layout.owner_at(file.len()), Owner::Unclaimed);
+
+        let (index, text) = elf
+            .sections()
+            .enumerate()
+            .find(|(_, section)| section.name() == Some(".text"))
+            .unwrap();
+        assert_eq!(
+            layout.owner_at(text.offset() as usize),
+            Owner::Section(index)
+        );
+
+        // The end of the header up to the first section is padding
+        assert_eq!(layout.owner_at(0x1000 - 1), Owner::Unclaimed);
+    }
+}
@@ -0,0 +1,751 @@
+mod edit;
+mod hex;
+mod search;
+mod tree;
+
+use badelf::elf::{self, Elf};
+use clap::Parser;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, Color, Print, SetAttribute, SetForegroundColor},
+    terminal::{
+        self, disable_raw_mode, enable_raw_mode, Clear, EnterAlternateScreen, LeaveAlternateScreen,
+    },
+    ExecutableCommand, QueueableCommand,
+};
+use edit::Editor;
+use hex::{Layout, Owner};
+use search::{Match, Query};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::error;
+use std::io::{stdout, Error, Stdout, StdoutLock, Write};
+use std::rc::Rc;
+use tree::{Node, Tree, Types};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(default_value_t = String::from("a.out"))]
+    file: String,
+}
+
+/// The pane the keys move in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Tree,
+    Hex,
+}
+
+/// A question asked in the last line, answered before any other key is handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Prompt {
+    /// Quit without saving the edits.
+    Quit,
+    /// Write the edits back to the file.
+    Write,
+    /// Write the edits to the path typed so far.
+    SaveAs(String),
+    /// Search for the query typed so far.
+    Search(String),
+    /// Go to the file offset, or `v:` virtual address, typed so far.
+    Goto(String),
+}
+
+/// Matches of the last search, cycled through with `n` and `N`.
+struct Search {
+    text: String,
+    matches: Vec<Match>,
+    index: usize,
+}
+
+/// Layouts of the types with the generation of the editor they were read at.
+type Cached = (u64, Rc<Result<Types, String>>);
+
+struct Tui {
+    stdout: Stdout,
+    editor: Editor,
+    expanded: HashSet<Node>,
+    /// Layouts of the types, read again only once the bytes changed.
+    types: RefCell<Option<Cached>>,
+    focus: Focus,
+    x: u16,
+    y: u16,
+    /// Index of the selected row.
+    row: usize,
+    /// Index of the first row shown.
+    top: usize,
+    /// Offset of the byte under the cursor of the hex pane.
+    cursor: usize,
+    /// Index of the first line of the hex pane.
+    line: usize,
+    /// High nibble typed for the byte under the cursor, waiting for the low one.
+    nibble: Option<u8>,
+    prompt: Option<Prompt>,
+    search: Option<Search>,
+    /// Shown in the last line until the next key.
+    message: Option<String>,
+}
+
+impl Tui {
+    /// Columns of the tree pane, the hex pane and the fields take the rest.
+    const TREE_WIDTH: u16 = 32;
+    /// Lines below the hex pane for the fields of the selected node or byte.
+    const INFO_HEIGHT: u16 = 16;
+
+    fn new(editor: Editor) -> Result<Self, Error> {
+        let (x, y) = terminal::size()?;
+
+        let mut tui = Tui {
+            stdout: stdout(),
+            editor,
+            expanded: HashSet::new(),
+            types: RefCell::new(None),
+            focus: Focus::Tree,
+            x,
+            y,
+            row: 0,
+            top: 0,
+            cursor: 0,
+            line: 0,
+            nibble: None,
+            prompt: None,
+            search: None,
+            message: None,
+        };
+        tui.stdout.execute(EnterAlternateScreen)?;
+        tui.stdout.execute(Hide)?;
+        enable_raw_mode()?;
+        Ok(tui)
+    }
+
+    /// The tree of the file as edited so far.
+    fn tree(&self) -> Result<Tree<'_>, elf::Error> {
+        let elf = Elf::parse(self.editor.data())?;
+        let types = self
+            .expanded
+            .contains(&Node::Types)
+            .then(|| self.types(&elf));
+
+        Ok(Tree::new(elf, &self.expanded, types))
+    }
+
+    /// Layouts of the types of the file as edited so far, read on the first tree built with the
+    /// group expanded.
+    fn types(&self, elf: &Elf) -> Rc<Result<Types, String>> {
+        let generation = self.editor.generation();
+        let mut cache = self.types.borrow_mut();
+
+        match &*cache {
+            Some((read, types)) if *read == generation => types.clone(),
+            _ => {
+                let types = Rc::new(Types::new(elf).map_err(|err| err.to_string()));
+                *cache = Some((generation, types.clone()));
+                types
+            }
+        }
+    }
+
+    fn rows(&self) -> Vec<(usize, Node)> {
+        self.tree().map(|tree| tree.rows()).unwrap_or_default()
+    }
+
+    fn selected(&self, rows: &[(usize, Node)]) -> Node {
+        rows.get(self.row).map_or(Node::Ident, |&(_, node)| node)
+    }
+
+    /// Lines of the tree pane, all but the last one.
+    fn height(&self) -> usize {
+        self.y.saturating_sub(1).max(1) as usize
+    }
+
+    /// Lines of the hex pane, the info lines take the rest.
+    fn hex_height(&self) -> usize {
+        self.height()
+            .saturating_sub(Self::INFO_HEIGHT.min(self.y / 2).into())
+            .max(1)
+    }
+
+    fn move_to(&mut self, row: usize) {
+        let rows = self.rows();
+
+        self.row = row.min(rows.len().saturating_sub(1));
+        self.scroll_tree();
+
+        let node = self.selected(&rows);
+        if let Some(offset) = self.tree().ok().and_then(|tree| tree.offset(node)) {
+            self.seek(offset);
+        }
+    }
+
+    /// Scrolls the tree pane for the selected row to be shown.
+    fn scroll_tree(&mut self) {
+        let height = self.height();
+
+        if self.row < self.top {
+            self.top = self.row;
+        } else if self.row >= self.top + height {
+            self.top = self.row + 1 - height;
+        }
+    }
+
+    /// Moves the hex cursor to `offset`, scrolling the pane to keep it shown.
+    fn seek(&mut self, offset: usize) {
+        self.cursor = offset.min(self.editor.data().len().saturating_sub(1));
+        self.nibble = None;
+        self.scroll_hex();
+    }
+
+    /// Scrolls the hex pane for the line of the cursor to be shown.
+    fn scroll_hex(&mut self) {
+        let line = hex::line_of(self.cursor);
+        let height = self.hex_height();
+
+        if line < self.line {
+            self.line = line;
+        } else if line >= self.line + height {
+            self.line = line + 1 - height;
+        }
+    }
+
+    /// Takes the new size of the terminal, keeping the selected row and the cursor shown.
+    fn resize(&mut self, x: u16, y: u16) {
+        self.x = x;
+        self.y = y;
+        self.scroll_tree();
+        self.scroll_hex();
+    }
+
+    /// Moves a page of the focused pane down, or up for a negative `pages`.
+    fn page(&mut self, pages: isize) {
+        match self.focus {
+            Focus::Tree => {
+                let rows = self.height() as isize * pages;
+                self.move_to(self.row.saturating_add_signed(rows));
+            }
+            Focus::Hex => {
+                let bytes = (self.hex_height() * hex::WIDTH) as isize * pages;
+                self.seek(self.cursor.saturating_add_signed(bytes));
+            }
+        }
+    }
+
+    /// Moves to the first row or byte of the focused pane, or the last one for `end`.
+    fn home(&mut self, end: bool) {
+        match (self.focus, end) {
+            (Focus::Tree, false) => self.move_to(0),
+            (Focus::Tree, true) => self.move_to(usize::MAX),
+            (Focus::Hex, false) => self.seek(0),
+            (Focus::Hex, true) => self.seek(usize::MAX),
+        }
+    }
+
+    /// Moves the hex cursor to the file offset typed in the prompt, or to the one a `v:`
+    /// virtual address is mapped from.
+    fn goto(&mut self, text: String) {
+        let offset = match text.strip_prefix("v:") {
+            Some(vaddr) => search::number(vaddr).and_then(|vaddr| {
+                let elf = Elf::parse(self.editor.data()).map_err(|err| err.to_string())?;
+                search::translate(&elf, vaddr)
+            }),
+            None => search::number(&text).and_then(|offset| {
+                usize::try_from(offset)
+                    .ok()
+                    .filter(|&offset| offset < self.editor.data().len())
+                    .ok_or_else(|| format!("{offset:#x} is out of the file"))
+            }),
+        };
+
+        match offset {
+            Ok(offset) => {
+                self.focus = Focus::Hex;
+                self.seek(offset);
+            }
+            Err(err) => self.message = Some(format!("error: {err}")),
+        }
+    }
+
+    /// Expands the selected group, or moves into it when it already is.
+    fn expand(&mut self) {
+        let node = self.selected(&self.rows());
+
+        if !node.is_group() {
+            return;
+        }
+
+        if !self.expanded.insert(node) {
+            self.move_to(self.row + 1);
+        }
+    }
+
+    /// Collapses the selected group, or moves to the group of the selected node.
+    fn collapse(&mut self) {
+        let rows = self.rows();
+        let node = self.selected(&rows);
+
+        if self.expanded.remove(&node) {
+            return;
+        }
+
+        if let Some(parent) = node.parent() {
+            let row = rows.iter().position(|&(_, node)| node == parent);
+            self.move_to(row.unwrap_or(0));
+        }
+    }
+
+    /// Takes a hex digit typed over the byte under the cursor, the byte is written once both of
+    /// its nibbles are.
+    fn type_nibble(&mut self, digit: u8) {
+        let Some(high) = self.nibble.take() else {
+            self.nibble = Some(digit);
+            return;
+        };
+
+        if let Err(err) = self.editor.set(self.cursor, high << 4 | digit) {
+            self.message = Some(format!("error: {err}"));
+            return;
+        }
+
+        self.validate();
+        self.seek(self.cursor + 1);
+    }
+
+    /// Warns when the edits left the file malformed, the ident going through the same
+    /// `TryFrom<RawIdent>` checks as any file that is opened.
+    fn validate(&mut self) {
+        if let Err(err) = Elf::parse(self.editor.data()) {
+            self.message = Some(format!("warning: {err}"));
+        }
+    }
+
+    fn undo(&mut self) {
+        match self.editor.undo() {
+            Ok(Some(offset)) => {
+                self.seek(offset);
+                self.validate();
+            }
+            Ok(None) => self.message = Some("Already at the oldest change".into()),
+            Err(err) => self.message = Some(format!("error: {err}")),
+        }
+    }
+
+    fn redo(&mut self) {
+        match self.editor.redo() {
+            Ok(Some(offset)) => {
+                self.seek(offset);
+                self.validate();
+            }
+            Ok(None) => self.message = Some("Already at the newest change".into()),
+            Err(err) => self.message = Some(format!("error: {err}")),
+        }
+    }
+
+    fn save(&mut self, path: String) {
+        self.message = Some(match self.editor.save(&path) {
+            Ok(()) => format!("Written to {path}"),
+            Err(err) => format!("error: failed to write {path}: {err}"),
+        });
+    }
+
+    /// Runs the search typed in the prompt, jumping to the first match after the cursor.
+    fn search(&mut self, text: String) {
+        let elf = Elf::parse(self.editor.data()).ok();
+        let matches = Query::parse(&text)
+            .and_then(|query| search::find(&query, elf.as_ref(), self.editor.data()));
+
+        match matches {
+            Ok(matches) if matches.is_empty() => {
+                self.message = Some(format!("Pattern not found: {text}"));
+            }
+            Ok(matches) => {
+                let index = matches
+                    .iter()
+                    .position(|m| matches!(m, Match::Offset(offset) if *offset >= self.cursor))
+                    .unwrap_or(0);
+
+                self.search = Some(Search {
+                    text,
+                    matches,
+                    index,
+                });
+                self.jump(0);
+            }
+            Err(err) => self.message = Some(format!("error: {err}")),
+        }
+    }
+
+    /// Moves `step` matches away from the current one, wrapping around, and shows it.
+    fn jump(&mut self, step: isize) {
+        let Some(search) = &mut self.search else {
+            self.message = Some("No previous search".into());
+            return;
+        };
+
+        let len = search.matches.len() as isize;
+        search.index = (search.index as isize + step).rem_euclid(len) as usize;
+
+        let found = search.matches[search.index];
+        self.message = Some(format!("/{} [{}/{}]", search.text, search.index + 1, len));
+
+        match found {
+            Match::Offset(offset) => {
+                self.focus = Focus::Hex;
+                self.seek(offset);
+            }
+            Match::Symbol(table, index) => {
+                self.focus = Focus::Tree;
+                self.expanded.insert(Node::Symbols(table));
+
+                let node = Node::Symbol(table, index);
+                if let Some(row) = self.rows().iter().position(|&(_, row)| row == node) {
+                    self.move_to(row);
+                }
+            }
+        }
+    }
+
+    /// Handles a key while a prompt is shown, returns whether to quit.
+    fn answer(&mut self, prompt: Prompt, event: KeyEvent) -> bool {
+        match (prompt, event.code) {
+            (Prompt::Quit, KeyCode::Char('y')) => return true,
+            (Prompt::Write, KeyCode::Char('y')) => {
+                self.save(self.editor.path().display().to_string())
+            }
+            (Prompt::SaveAs(path), KeyCode::Enter) if !path.is_empty() => self.save(path),
+            (Prompt::SaveAs(mut path), KeyCode::Char(c)) => {
+                path.push(c);
+                self.prompt = Some(Prompt::SaveAs(path));
+            }
+            (Prompt::SaveAs(mut path), KeyCode::Backspace) => {
+                path.pop();
+                self.prompt = Some(Prompt::SaveAs(path));
+            }
+            (Prompt::Search(text), KeyCode::Enter) if !text.is_empty() => self.search(text),
+            (Prompt::Search(mut text), KeyCode::Char(c)) => {
+                text.push(c);
+                self.prompt = Some(Prompt::Search(text));
+            }
+            (Prompt::Search(mut text), KeyCode::Backspace) => {
+                text.pop();
+                self.prompt = Some(Prompt::Search(text));
+            }
+            (Prompt::Goto(text), KeyCode::Enter) if !text.is_empty() => self.goto(text),
+            (Prompt::Goto(mut text), KeyCode::Char(c)) => {
+                text.push(c);
+                self.prompt = Some(Prompt::Goto(text));
+            }
+            (Prompt::Goto(mut text), KeyCode::Backspace) => {
+                text.pop();
+                self.prompt = Some(Prompt::Goto(text));
+            }
+            _ => (),
+        }
+
+        false
+    }
+
+    /// Handles a key, returns whether to quit.
+    fn key(&mut self, event: KeyEvent) -> bool {
+        self.message = None;
+
+        if let Some(prompt) = self.prompt.take() {
+            return self.answer(prompt, event);
+        }
+
+        let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+
+        match (self.focus, event.code) {
+            (_, KeyCode::Char('q')) if self.editor.is_dirty() => self.prompt = Some(Prompt::Quit),
+            (_, KeyCode::Char('q')) => return true,
+            (_, KeyCode::Char('r')) if ctrl => self.redo(),
+            (_, KeyCode::Char('u')) => self.undo(),
+            (_, KeyCode::Char('w')) => self.prompt = Some(Prompt::Write),
+            (_, KeyCode::Char('W')) => {
+                let path = self.editor.path().display().to_string();
+                self.prompt = Some(Prompt::SaveAs(path));
+            }
+            (_, KeyCode::Char('/')) => self.prompt = Some(Prompt::Search(String::new())),
+            (_, KeyCode::Char('n')) => self.jump(1),
+            (_, KeyCode::Char('N')) => self.jump(-1),
+            (_, KeyCode::Char(':')) => self.prompt = Some(Prompt::Goto(String::new())),
+            (_, KeyCode::PageDown) => self.page(1),
+            (_, KeyCode::PageUp) => self.page(-1),
+            (_, KeyCode::Char('g') | KeyCode::Home) => self.home(false),
+            (_, KeyCode::Char('G') | KeyCode::End) => self.home(true),
+            (Focus::Tree, KeyCode::Tab) => self.focus = Focus::Hex,
+            (Focus::Hex, KeyCode::Tab) => {
+                self.focus = Focus::Tree;
+                self.nibble = None;
+            }
+            (Focus::Tree, KeyCode::Char('j') | KeyCode::Down) => self.move_to(self.row + 1),
+            (Focus::Tree, KeyCode::Char('k') | KeyCode::Up) => {
+                self.move_to(self.row.saturating_sub(1))
+            }
+            (Focus::Tree, KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter) => self.expand(),
+            (Focus::Tree, KeyCode::Char('h') | KeyCode::Left) => self.collapse(),
+            (Focus::Hex, KeyCode::Char('j') | KeyCode::Down) => {
+                self.seek(self.cursor.saturating_add(hex::WIDTH))
+            }
+            (Focus::Hex, KeyCode::Char('k') | KeyCode::Up) => {
+                self.seek(self.cursor.saturating_sub(hex::WIDTH))
+            }
+            (Focus::Hex, KeyCode::Char('l') | KeyCode::Right) => {
+                self.seek(self.cursor.saturating_add(1))
+            }
+            (Focus::Hex, KeyCode::Char('h') | KeyCode::Left) => {
+                self.seek(self.cursor.saturating_sub(1))
+            }
+            (Focus::Hex, KeyCode::Char(c)) if c.is_ascii_hexdigit() && !ctrl => {
+                self.type_nibble(c.to_digit(16).unwrap() as u8)
+            }
+            (Focus::Hex, KeyCode::Esc) => self.nibble = None,
+            _ => (),
+        }
+
+        false
+    }
+
+    fn draw(&mut self) -> Result<(), Error> {
+        let mut out = stdout().lock();
+        let tree = self.tree();
+        let rows = tree.as_ref().map(|tree| tree.rows()).unwrap_or_default();
+        let node = self.selected(&rows);
+        let layout = tree.as_ref().ok().map(|tree| Layout::new(tree.elf()));
+        let width = Self::TREE_WIDTH.min(self.x / 2) as usize;
+
+        out.queue(Clear(terminal::ClearType::All))?;
+
+        match &tree {
+            Ok(tree) => {
+                for (i, &(depth, node)) in
+                    rows.iter().skip(self.top).take(self.height()).enumerate()
+                {
+                    let marker = match (node.is_group(), tree.is_expanded(node)) {
+                        (true, true) => '-',
+                        (true, false) => '+',
+                        _ => ' ',
+                    };
+                    let line = format!(
+                        "{:indent$}{marker} {}",
+                        "",
+                        tree.label(node),
+                        indent = depth * 2
+                    );
+                    let line: String = line.chars().take(width).collect();
+
+                    out.queue(MoveTo(0, i as u16))?;
+
+                    if self.top + i == self.row {
+                        out.queue(SetAttribute(Attribute::Reverse))?;
+                        out.queue(Print(format!("{line:<width$}")))?;
+                        out.queue(SetAttribute(Attribute::Reset))?;
+                    } else {
+                        out.queue(Print(line))?;
+                    }
+                }
+            }
+            Err(err) => {
+                let line: String = format!("error: {err}").chars().take(width).collect();
+
+                out.queue(MoveTo(0, 0))?;
+                out.queue(Print(line))?;
+            }
+        }
+
+        let x = width as u16 + 1;
+        let height = self.hex_height();
+        let data = self.editor.data();
+        let owner = |offset| {
+            layout
+                .as_ref()
+                .map_or(Owner::Unclaimed, |layout| layout.owner_at(offset))
+        };
+
+        for i in 0..height {
+            let start = (self.line + i) * hex::WIDTH;
+            let end = data.len().min(start + hex::WIDTH);
+            let Some(bytes) = data.get(start..end).filter(|bytes| !bytes.is_empty()) else {
+                break;
+            };
+
+            out.queue(MoveTo(x, i as u16))?;
+            out.queue(Print(format!("{start:08x}  ")))?;
+
+            for (column, &byte) in bytes.iter().enumerate() {
+                let offset = start + column;
+                let text = match self.nibble {
+                    Some(nibble) if offset == self.cursor => format!("{nibble:x}_"),
+                    _ => format!("{byte:02x}"),
+                };
+
+                self.byte(&mut out, owner(offset), offset, text)?;
+                out.queue(Print(' '))?;
+            }
+
+            let pad = (hex::WIDTH - bytes.len()) * 3 + 1;
+            out.queue(Print(format!("{:pad$}", "")))?;
+
+            for (column, &byte) in bytes.iter().enumerate() {
+                let offset = start + column;
+                self.byte(
+                    &mut out,
+                    owner(offset),
+                    offset,
+                    hex::ascii(byte).to_string(),
+                )?;
+            }
+        }
+
+        let lines = match (self.focus, &tree) {
+            (_, Err(_)) => Vec::new(),
+            (Focus::Tree, Ok(tree)) => match tree.fields(node) {
+                Ok(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        if field.range.is_empty() {
+                            format!("{:10} {:<14} {}", "", field.name, field.value)
+                        } else {
+                            let offset = format!("{:#010x}", field.range.start);
+                            format!("{offset} {:<14} {}", field.name, field.value)
+                        }
+                    })
+                    .collect(),
+                Err(err) => vec![format!("error: {err}")],
+            },
+            (Focus::Hex, Ok(tree)) => {
+                let owner = owner_name(tree, owner(self.cursor));
+                let mut lines = vec![format!("{:#010x} {owner}", self.cursor)];
+
+                if let Some((node, field)) = tree.field_at(self.cursor) {
+                    lines.push(format!("{:10} {}", "", tree.label(node)));
+                    lines.push(format!(
+                        "{:#010x} {:<14} {}",
+                        field.range.start, field.name, field.value
+                    ));
+                }
+
+                lines
+            }
+        };
+
+        for (i, line) in lines
+            .iter()
+            .take(self.height().saturating_sub(height))
+            .enumerate()
+        {
+            let line: String = line.chars().take(self.x.saturating_sub(x).into()).collect();
+
+            out.queue(MoveTo(x, (height + i) as u16))?;
+            out.queue(Print(line))?;
+        }
+
+        let status = match (&self.prompt, &self.message) {
+            (Some(Prompt::Quit), _) => "Discard the unsaved changes and quit? (y/n)".into(),
+            (Some(Prompt::Write), _) => {
+                format!("Overwrite {}? (y/n)", self.editor.path().display())
+            }
+            (Some(Prompt::SaveAs(path)), _) => format!("Save as: {path}"),
+            (Some(Prompt::Search(text)), _) => format!("/{text}"),
+            (Some(Prompt::Goto(text)), _) => format!(":{text}"),
+            (None, Some(message)) => message.clone(),
+            (None, None) => {
+                let dirty = if self.editor.is_dirty() { " [+]" } else { "" };
+                format!("{}{dirty}", self.editor.path().display())
+            }
+        };
+        let position = match (self.focus, &tree) {
+            (Focus::Tree, Ok(tree)) => {
+                format!("{} {}/{}", tree.label(node), self.row + 1, rows.len())
+            }
+            (Focus::Tree, Err(_)) => String::new(),
+            (Focus::Hex, tree) => {
+                let owner = match tree {
+                    Ok(tree) => owner_name(tree, owner(self.cursor)),
+                    Err(_) => Owner::Unclaimed.name().to_string(),
+                };
+                let percent = (self.cursor + 1) * 100 / data.len().max(1);
+
+                format!("{owner} {:#x}/{:#x} {percent}%", self.cursor, data.len())
+            }
+        };
+
+        let width = self.x as usize;
+        let status: String = status.chars().take(width).collect();
+        let pad = width.saturating_sub(status.chars().count() + 1);
+        let position: String = position.chars().take(pad).collect();
+
+        out.queue(MoveTo(0, self.y.saturating_sub(1)))?;
+        out.queue(SetAttribute(Attribute::Reverse))?;
+        out.queue(Print(format!("{status} {position:>pad$}")))?;
+        out.queue(SetAttribute(Attribute::Reset))?;
+
+        out.flush()
+    }
+
+    /// Prints `text` for the byte at `offset` in the color of its owner, reversed under the
+    /// cursor.
+    fn byte(
+        &self,
+        out: &mut StdoutLock,
+        owner: Owner,
+        offset: usize,
+        text: String,
+    ) -> Result<(), Error> {
+        out.queue(SetForegroundColor(owner.color()))?;
+        if offset == self.cursor {
+            out.queue(SetAttribute(Attribute::Reverse))?;
+        }
+        out.queue(Print(text))?;
+        out.queue(SetAttribute(Attribute::Reset))?;
+        out.queue(SetForegroundColor(Color::Reset))?;
+
+        Ok(())
+    }
+
+    fn run(&mut self) -> Result<(), Error> {
+        let mut quit = false;
+
+        self.draw()?;
+
+        while !quit {
+            match read()? {
+                Event::Key(event) if event.kind == KeyEventKind::Press => {
+                    quit = self.key(event);
+                    self.draw()?;
+                }
+                Event::Resize(x, y) => {
+                    self.resize(x, y);
+                    self.draw()?;
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Name of the structure a byte belongs to, with the name of the section it is in.
+fn owner_name(tree: &Tree, owner: Owner) -> String {
+    match owner {
+        Owner::Section(index) => format!("section {}", tree.section_name(index)),
+        owner => owner.name().to_string(),
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = self.stdout.execute(Show);
+        let _ = self.stdout.execute(LeaveAlternateScreen);
+    }
+}
+
+fn main() -> Result<(), Box<dyn error::Error>> {
+    let args = Args::parse();
+
+    // Malformed files are opened anyway, for their bytes to be fixed in the hex pane
+    Tui::new(Editor::open(&args.file)?)?.run()?;
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use badelf::elf::common::sh;
+use badelf::elf::reader::Endian;
+use badelf::elf::symbol::Symbols;
+use badelf::elf::Elf;
+use badelf::input::Data;
+
+/// What a `/` search looks for, picked by the prefix of the text typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// `x:7f 45 ?? 46`, `??` matching any byte.
+    Bytes(Vec<Option<u8>>),
+    /// `a:text`, also any text without a prefix.
+    Ascii(String),
+    /// `u:text`, encoded in the byte order of the file.
+    Utf16(String),
+    /// `s:name`, the symbols whose name contains it.
+    Symbol(String),
+    /// `v:0x401000`, the file offset a `PT_LOAD` segment maps it from.
+    Vaddr(u64),
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let query = match text.split_once(':') {
+            Some(("x", pattern)) => Query::Bytes(bytes(pattern)?),
+            Some(("a", text)) => Query::Ascii(text.into()),
+            Some(("u", text)) => Query::Utf16(text.into()),
+            Some(("s", name)) => Query::Symbol(name.into()),
+            Some(("v", vaddr)) => Query::Vaddr(number(vaddr)?),
+            _ => Query::Ascii(text.into()),
+        };
+
+        match &query {
+            Query::Bytes(pattern) if pattern.is_empty() => Err("empty pattern".into()),
+            Query::Ascii(text) | Query::Utf16(text) | Query::Symbol(text) if text.is_empty() => {
+                Err("empty pattern".into())
+            }
+            _ => Ok(query),
+        }
+    }
+}
+
+/// A decimal number, or a hex one with `0x`.
+pub fn number(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    let number = match text.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => text.parse(),
+    };
+
+    number.map_err(|err| format!("invalid number {text}: {err}"))
+}
+
+/// File offset the virtual address `vaddr` is mapped from.
+pub fn translate(elf: &Elf, vaddr: u64) -> Result<usize, String> {
+    elf.segments()
+        .translate(vaddr)
+        .and_then(|offset| usize::try_from(offset).ok())
+        .ok_or_else(|| format!("{vaddr:#x} is not mapped from the file"))
+}
+
+/// Hex bytes, optionally separated by spaces, with `??` for any byte.
+fn bytes(pattern: &str) -> Result<Vec<Option<u8>>, String> {
+    let digits: Vec<char> = pattern.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if !digits.len().is_multiple_of(2) {
+        return Err("odd number of hex digits".into());
+    }
+
+    digits
+        .chunks_exact(2)
+        .map(|pair| match pair {
+            ['?', '?'] => Ok(None),
+            [high, low] => match (high.to_digit(16), low.to_digit(16)) {
+                (Some(high), Some(low)) => Ok(Some((high << 4 | low) as u8)),
+                _ => Err(format!("invalid byte: {high}{low}")),
+            },
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Where a search matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Match {
+    Offset(usize),
+    /// Symbol of the table at the index, as the nodes of the tree.
+    Symbol(usize, usize),
+}
+
+/// Every match of `query`, in the order of the file. `elf` is `None` when the file does not
+/// parse, leaving only the byte and string searches.
+pub fn find(query: &Query, elf: Option<&Elf>, data: Data) -> Result<Vec<Match>, String> {
+    let pattern: Vec<Option<u8>> = match query {
+        Query::Bytes(pattern) => pattern.clone(),
+        Query::Ascii(text) => text.bytes().map(Some).collect(),
+        Query::Utf16(text) => {
+            let endian = elf.map_or(Endian::Little, |elf| elf.header().reader().endian());
+
+            text.encode_utf16()
+                .flat_map(|unit| match endian {
+                    Endian::Little => unit.to_le_bytes(),
+                    Endian::Big => unit.to_be_bytes(),
+                })
+                .map(Some)
+                .collect()
+        }
+        Query::Symbol(name) => return symbols(elf.ok_or("the file is malformed")?, name),
+        Query::Vaddr(vaddr) => {
+            let offset = translate(elf.ok_or("the file is malformed")?, *vaddr)?;
+            return Ok(vec![Match::Offset(offset)]);
+        }
+    };
+
+    let file = data.get(0..data.len()).ok_or("failed to read the file")?;
+
+    Ok(file
+        .windows(pattern.len())
+        .enumerate()
+        .filter(|(_, window)| {
+            window
+                .iter()
+                .zip(&pattern)
+                .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected))
+        })
+        .map(|(offset, _)| Match::Offset(offset))
+        .collect())
+}
+
+fn symbols(elf: &Elf, name: &str) -> Result<Vec<Match>, String> {
+    let sections = elf.sections();
+    let mut matches = Vec::new();
+
+    for (table, section) in sections.clone().enumerate() {
+        if !matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM) {
+            continue;
+        }
+
+        let symbols = Symbols::new(elf.data(), &sections, table).map_err(|err| err.to_string())?;
+
+        matches.extend(
+            (0..symbols.num())
+                .filter(|&index| {
+                    symbols
+                        .get(index)
+                        .and_then(|symbol| symbol.name())
+                        .is_some_and(|symbol| symbol.contains(name))
+                })
+                .map(|index| Match::Symbol(table, index)),
+        );
+    }
+
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find, Match, Query};
+    use badelf::elf::Elf;
+    use badelf::input::Data;
+
+    #[test]
+    fn search_exe() {
+        let file = include_bytes!("../../test/exe");
+        let elf = Elf::parse(file).unwrap();
+        let search = |text| find(&Query::parse(text).unwrap(), Some(&elf), file.into());
+
+        assert_eq!(
+            search("x:7f ?? 4c46").unwrap().first(),
+            Some(&Match::Offset(0))
+        );
+        assert_eq!(search("ELF").unwrap().first(), Some(&Match::Offset(1)));
+        assert_eq!(search("v:0x3dd0").unwrap(), [Match::Offset(0x2dd0)]);
+        assert!(search("v:0x10000000").is_err());
+
+        // `.symtab` is section 27 and `main` its symbol 21
+        assert!(search("s:main").unwrap().contains(&Match::Symbol(27, 21)));
+        // `printf` is both in `.dynsym` and `.symtab`
+        assert_eq!(
+            search("s:printf").unwrap(),
+            [Match::Symbol(6, 3), Match::Symbol(27, 12)]
+        );
+
+        assert!(Query::parse("x:7f4").is_err());
+        assert!(Query::parse("x:zz").is_err());
+        assert!(Query::parse("").is_err());
+        assert!(Query::parse("v:0xg").is_err());
+        assert_eq!(Query::parse("v:4096"), Ok(Query::Vaddr(0x1000)));
+    }
+
+    #[test]
+    fn search_utf16_without_elf() {
+        let file = b"\0a\0b\0\0";
+        let search = |text| find(&Query::parse(text).unwrap(), None, Data::Bytes(file));
+
+        assert_eq!(search("u:ab").unwrap(), [Match::Offset(1)]);
+        assert!(search("s:main").is_err());
+    }
+}
@@ -0,0 +1,837 @@
+use badelf::dwarf::layout::Layout;
+use badelf::dwarf::Dwarf;
+use badelf::elf::common::{self, ch, e, ei, p, sh, st};
+use badelf::elf::reader::Reader;
+use badelf::elf::section::SectionHeader;
+use badelf::elf::symbol::{Symbol, Symbols};
+use badelf::elf::Elf;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A structure of the file, the groups are the nodes with children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Node {
+    Ident,
+    Header,
+    Segments,
+    Segment(usize),
+    Sections,
+    Section(usize),
+    /// Symbol table of the `SHT_SYMTAB` or `SHT_DYNSYM` section at the index.
+    Symbols(usize),
+    Symbol(usize, usize),
+    /// Structures, unions and classes of the DWARF debugging information.
+    Types,
+    /// Layout of the type at the offset of `.debug_info`.
+    Type(usize),
+}
+
+impl Node {
+    pub fn is_group(&self) -> bool {
+        matches!(
+            self,
+            Node::Segments | Node::Sections | Node::Symbols(_) | Node::Types
+        )
+    }
+
+    /// The group the node is listed under, `None` for the top level.
+    pub fn parent(&self) -> Option<Node> {
+        match *self {
+            Node::Segment(_) => Some(Node::Segments),
+            Node::Section(_) => Some(Node::Sections),
+            Node::Symbol(table, _) => Some(Node::Symbols(table)),
+            Node::Type(_) => Some(Node::Types),
+            _ => None,
+        }
+    }
+}
+
+/// A decoded field of a node and the bytes of the file it was read from.
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: &'static str,
+    pub value: String,
+    pub range: Range<usize>,
+}
+
+/// Fields of an entry of a table at `base`, sized by the class of the file.
+struct Fields {
+    reader: Reader,
+    base: usize,
+    fields: Vec<Field>,
+}
+
+impl Fields {
+    fn new(reader: Reader, base: usize) -> Self {
+        Self {
+            reader,
+            base,
+            fields: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, name: &'static str, offset: usize, size: usize, value: String) {
+        let start = self.base + offset;
+
+        self.fields.push(Field {
+            name,
+            value,
+            range: start..start + size,
+        });
+    }
+
+    /// An `ElfN_Addr`/`ElfN_Off` sized field at the `(elf32, elf64)` offset.
+    fn word(&mut self, name: &'static str, at: (usize, usize), value: String) {
+        self.push(name, self.reader.at(at), self.reader.wordsize(), value);
+    }
+
+    fn u32(&mut self, name: &'static str, at: (usize, usize), value: String) {
+        self.push(name, self.reader.at(at), 0x04, value);
+    }
+
+    fn u16(&mut self, name: &'static str, at: (usize, usize), value: String) {
+        self.push(name, self.reader.at(at), 0x02, value);
+    }
+
+    fn u8(&mut self, name: &'static str, at: (usize, usize), value: String) {
+        self.push(name, self.reader.at(at), 0x01, value);
+    }
+}
+
+/// Layouts of the types of every unit, the ones defined in several units only once, with the
+/// index of each by the offset of its entry.
+pub struct Types {
+    layouts: Vec<Layout>,
+    offsets: HashMap<usize, usize>,
+}
+
+impl Types {
+    pub fn new(elf: &Elf) -> Result<Self, badelf::dwarf::Error> {
+        let dwarf = Dwarf::new(elf)?;
+        let mut layouts = Vec::new();
+        let mut shown = HashSet::new();
+
+        for unit in dwarf.units() {
+            for layout in Layout::of_unit(&unit?.tree()?) {
+                if shown.insert(layout.to_string()) {
+                    layouts.push(layout);
+                }
+            }
+        }
+
+        layouts.sort_by(|a, b| a.name().cmp(&b.name()));
+        let offsets = layouts
+            .iter()
+            .enumerate()
+            .map(|(index, layout)| (layout.die().start, index))
+            .collect();
+
+        Ok(Self { layouts, offsets })
+    }
+
+    /// Layout of the type whose entry is at `offset` of `.debug_info`.
+    fn get(&self, offset: usize) -> Option<&Layout> {
+        self.layouts.get(*self.offsets.get(&offset)?)
+    }
+}
+
+/// The structures of an ELF file as a tree, with the groups in `expanded` showing their children.
+pub struct Tree<'a> {
+    elf: Elf<'a>,
+    expanded: &'a HashSet<Node>,
+    symbols: HashMap<usize, Result<Symbols<'a>, String>>,
+    /// Layouts of the types, `None` while the group is collapsed as they take long to read for
+    /// large files.
+    types: Option<Rc<Result<Types, String>>>,
+}
+
+impl<'a> Tree<'a> {
+    pub fn new(
+        elf: Elf<'a>,
+        expanded: &'a HashSet<Node>,
+        types: Option<Rc<Result<Types, String>>>,
+    ) -> Self {
+        let symbols = expanded
+            .iter()
+            .filter_map(|node| match node {
+                Node::Symbols(index) => Some(*index),
+                _ => None,
+            })
+            .map(|index| {
+                let symbols = Symbols::new(elf.data(), &elf.sections(), index);
+                (index, symbols.map_err(|err| err.to_string()))
+            })
+            .collect();
+
+        Self {
+            elf,
+            expanded,
+            symbols,
+            types,
+        }
+    }
+
+    pub fn elf(&self) -> &Elf<'a> {
+        &self.elf
+    }
+
+    pub fn is_expanded(&self, node: Node) -> bool {
+        self.expanded.contains(&node)
+    }
+
+    fn children(&self, node: Node) -> Vec<Node> {
+        match node {
+            Node::Segments => (0..self.elf.segments().len()).map(Node::Segment).collect(),
+            Node::Sections => (0..self.elf.sections().num()).map(Node::Section).collect(),
+            Node::Symbols(table) => match self.symbols.get(&table) {
+                Some(Ok(symbols)) => (0..symbols.num())
+                    .map(|index| Node::Symbol(table, index))
+                    .collect(),
+                _ => Vec::new(),
+            },
+            Node::Types => match self.types.as_deref() {
+                Some(Ok(types)) => types
+                    .layouts
+                    .iter()
+                    .map(|layout| Node::Type(layout.die().start))
+                    .collect(),
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// The nodes shown, with their depth, following the expanded groups.
+    pub fn rows(&self) -> Vec<(usize, Node)> {
+        let mut top = vec![Node::Ident, Node::Header, Node::Segments, Node::Sections];
+
+        top.extend(
+            self.elf
+                .sections()
+                .enumerate()
+                .filter(|(_, section)| {
+                    matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM)
+                })
+                .map(|(index, _)| Node::Symbols(index)),
+        );
+
+        if self.debug_info_section().is_some() {
+            top.push(Node::Types);
+        }
+
+        let mut rows = Vec::new();
+
+        for node in top {
+            rows.push((0, node));
+
+            if self.is_expanded(node) {
+                rows.extend(self.children(node).into_iter().map(|child| (1, child)));
+            }
+        }
+
+        rows
+    }
+
+    /// Symbol of a table, read again if the table was not expanded yet.
+    fn symbol(&self, table: usize, index: usize) -> Option<Symbol<'a>> {
+        match self.symbols.get(&table) {
+            Some(Ok(symbols)) => symbols.get(index),
+            Some(Err(_)) => None,
+            None => Symbols::new(self.elf.data(), &self.elf.sections(), table)
+                .ok()?
+                .get(index),
+        }
+    }
+
+    /// The `.debug_info` section, or the legacy compressed `.zdebug_info`.
+    fn debug_info_section(&self) -> Option<SectionHeader<'a>> {
+        let sections = self.elf.sections();
+        sections
+            .by_name(".debug_info")
+            .or_else(|| sections.by_name(".zdebug_info"))
+    }
+
+    /// File offset of the entries of `.debug_info`, `None` when it is compressed as they are
+    /// not in the file then.
+    fn debug_info(&self) -> Option<usize> {
+        let section = self.debug_info_section()?;
+        if section.compression(self.elf.data()).ok()?.is_some() {
+            return None;
+        }
+
+        usize::try_from(section.offset()).ok()
+    }
+
+    /// Layout of the type whose entry is at `offset` of `.debug_info`.
+    fn layout(&self, offset: usize) -> Option<&Layout> {
+        match self.types.as_deref() {
+            Some(Ok(types)) => types.get(offset),
+            _ => None,
+        }
+    }
+
+    /// First byte of the structure of `node`, for the groups the start of their table.
+    pub fn offset(&self, node: Node) -> Option<usize> {
+        let header = self.elf.header();
+
+        match node {
+            Node::Segments => usize::try_from(header.phoff()).ok(),
+            Node::Sections => usize::try_from(header.shoff()).ok(),
+            Node::Types => usize::try_from(self.debug_info_section()?.offset()).ok(),
+            Node::Type(offset) => Some(self.debug_info()? + offset),
+            node => Some(self.fields(node).ok()?.first()?.range.start),
+        }
+    }
+
+    /// The field of the structure holding the byte at `offset`, with the node it belongs to.
+    pub fn field_at(&self, offset: usize) -> Option<(Node, Field)> {
+        let header = self.elf.header();
+        let entry = |table: u64, entsize: u16, len: usize| {
+            let index = offset.checked_sub(usize::try_from(table).ok()?)? / entsize.max(1) as usize;
+            (index < len).then_some(index)
+        };
+
+        let node = if offset < header.ident().len() {
+            Node::Ident
+        } else if offset < header.len() {
+            Node::Header
+        } else if let Some(index) = entry(
+            header.phoff(),
+            header.phentzise(),
+            self.elf.segments().len(),
+        ) {
+            Node::Segment(index)
+        } else if let Some(index) = entry(
+            header.shoff(),
+            header.shentsize(),
+            self.elf.sections().num(),
+        ) {
+            Node::Section(index)
+        } else {
+            let (table, section) = self.elf.sections().enumerate().find(|(_, section)| {
+                matches!(section.r#type(), sh::r#type::SYMTAB | sh::r#type::DYNSYM)
+                    && offset as u64 >= section.offset()
+                    && (offset as u64) - section.offset() < section.size()
+            })?;
+            let entsize = match section.entsize() {
+                0 => Symbol::entsize_of(&header.reader()) as u64,
+                entsize => entsize,
+            };
+
+            Node::Symbol(
+                table,
+                ((offset as u64 - section.offset()) / entsize) as usize,
+            )
+        };
+
+        let field = self
+            .fields(node)
+            .ok()?
+            .into_iter()
+            .find(|field| field.range.contains(&offset))?;
+
+        Some((node, field))
+    }
+
+    pub fn section_name(&self, index: usize) -> &'a str {
+        self.elf
+            .sections()
+            .get(index)
+            .and_then(|section| section.name())
+            .unwrap_or("<corrupt>")
+    }
+
+    pub fn label(&self, node: Node) -> String {
+        let sections = self.elf.sections();
+        let section_name = |index| self.section_name(index);
+
+        match node {
+            Node::Ident => "Ident".into(),
+            Node::Header => "Header".into(),
+            Node::Segments => format!("Segments ({})", self.elf.segments().len()),
+            Node::Segment(index) => match self.elf.segments().nth(index) {
+                Some(segment) => format!("[{index}] {}", common::p_type_name(segment.r#type())),
+                None => format!("[{index}] <corrupt>"),
+            },
+            Node::Sections => format!("Sections ({})", sections.num()),
+            Node::Section(index) => format!("[{index}] {}", section_name(index)),
+            Node::Symbols(table) => match self.symbols.get(&table) {
+                Some(Ok(symbols)) => format!("Symbols {} ({})", section_name(table), symbols.num()),
+                _ => format!("Symbols {}", section_name(table)),
+            },
+            Node::Symbol(table, index) => match self.symbol(table, index) {
+                Some(symbol) => format!("[{index}] {}", symbol.name().unwrap_or("<corrupt>")),
+                None => format!("[{index}] <corrupt>"),
+            },
+            Node::Types => match self.types.as_deref() {
+                Some(Ok(types)) => format!("Types ({})", types.layouts.len()),
+                _ => "Types".into(),
+            },
+            Node::Type(offset) => match self.layout(offset) {
+                Some(layout) => format!(
+                    "{} {}",
+                    layout.kind(),
+                    layout.name().unwrap_or("<anonymous>")
+                ),
+                None => format!("<{offset:#x}>"),
+            },
+        }
+    }
+
+    /// The decoded fields of `node`, empty for the groups without a structure of their own.
+    pub fn fields(&self, node: Node) -> Result<Vec<Field>, String> {
+        let header = self.elf.header();
+        let reader = header.reader();
+
+        match node {
+            Node::Ident => {
+                let ident = header.ident();
+                let mut fields = Fields::new(reader, 0);
+
+                fields.push("EI_MAG", ei::MAG.start, ei::MAG.len(), ".ELF".into());
+                fields.push(
+                    "EI_CLASS",
+                    ei::CLASS,
+                    0x01,
+                    format!("{} ({}-bit)", ident.class(), reader.by_class(32, 64)),
+                );
+                fields.push(
+                    "EI_DATA",
+                    ei::DATA,
+                    0x01,
+                    format!(
+                        "{} ({})",
+                        ident.endianness(),
+                        common::ei_data_name(ident.endianness())
+                    ),
+                );
+                fields.push("EI_VERSION", ei::VERSION, 0x01, ident.version().to_string());
+                fields.push(
+                    "EI_OSABI",
+                    ei::OSABI,
+                    0x01,
+                    format!("{} ({})", ident.abi(), common::ei_abi_name(ident.abi())),
+                );
+                fields.push(
+                    "EI_ABIVERSION",
+                    ei::ABIVERSION,
+                    0x01,
+                    ident.abi_version().to_string(),
+                );
+
+                Ok(fields.fields)
+            }
+            Node::Header => {
+                let mut fields = Fields::new(reader, 0);
+
+                fields.push(
+                    "e_type",
+                    e::TYPE,
+                    0x02,
+                    format!(
+                        "{:#x} ({})",
+                        header.r#type(),
+                        common::type_name(header.r#type())
+                    ),
+                );
+                fields.push(
+                    "e_machine",
+                    e::MACHINE,
+                    0x02,
+                    format!(
+                        "{:#x} ({})",
+                        header.machine(),
+                        common::machine_name(header.machine())
+                    ),
+                );
+                fields.push("e_version", e::VERSION, 0x04, header.version().to_string());
+                fields.push(
+                    "e_entry",
+                    e::ENTRY,
+                    reader.wordsize(),
+                    format!("{:#x}", header.entry()),
+                );
+                fields.word("e_phoff", e::PHOFF, format!("{:#x}", header.phoff()));
+                fields.word("e_shoff", e::SHOFF, format!("{:#x}", header.shoff()));
+                fields.u32("e_flags", e::FLAGS, format!("{:#x}", header.flags()));
+                fields.u16("e_ehsize", e::EHSIZE, header.ehsize().to_string());
+                fields.u16("e_phentsize", e::PHENTSIZE, header.phentzise().to_string());
+                fields.u16("e_phnum", e::PHNUM, header.phnum().to_string());
+                fields.u16("e_shentsize", e::SHENTSIZE, header.shentsize().to_string());
+                fields.u16("e_shnum", e::SHNUM, header.shnum().to_string());
+                fields.u16("e_shstrndx", e::SHSTRNDX, header.shstrndx().to_string());
+
+                Ok(fields.fields)
+            }
+            Node::Segment(index) => {
+                let segment = self
+                    .elf
+                    .segments()
+                    .nth(index)
+                    .ok_or("segment is out of the table")?;
+                let base = header.phoff() as usize + index * header.phentzise() as usize;
+                let mut fields = Fields::new(reader, base);
+
+                fields.u32(
+                    "p_type",
+                    p::TYPE,
+                    format!(
+                        "{:#x} ({})",
+                        segment.r#type(),
+                        common::p_type_name(segment.r#type())
+                    ),
+                );
+                let flag = |mask, c| if segment.flags() & mask != 0 { c } else { ' ' };
+                fields.u32(
+                    "p_flags",
+                    p::FLAGS,
+                    format!(
+                        "{:#x} ({}{}{})",
+                        segment.flags(),
+                        flag(p::flags::R, 'R'),
+                        flag(p::flags::W, 'W'),
+                        flag(p::flags::X, 'E'),
+                    ),
+                );
+                fields.word("p_offset", p::OFFSET, format!("{:#x}", segment.offset()));
+                fields.word("p_vaddr", p::VADDR, format!("{:#x}", segment.vaddr()));
+                fields.word("p_paddr", p::PADDR, format!("{:#x}", segment.paddr()));
+                fields.word("p_filesz", p::FILESZ, format!("{:#x}", segment.filesz()));
+                fields.word("p_memsz", p::MEMSZ, format!("{:#x}", segment.memsz()));
+                fields.word("p_align", p::ALIGN, format!("{:#x}", segment.align()));
+
+                Ok(fields.fields)
+            }
+            Node::Section(index) | Node::Symbols(index) => {
+                let section = self
+                    .elf
+                    .sections()
+                    .get(index)
+                    .ok_or("section is out of the table")?;
+                let base = header.shoff() as usize + index * header.shentsize() as usize;
+                let mut fields = Fields::new(reader, base);
+
+                fields.u32(
+                    "sh_name",
+                    sh::NAME,
+                    format!(
+                        "{:#x} ({})",
+                        section.name_offset(),
+                        section.name().unwrap_or("<corrupt>")
+                    ),
+                );
+                fields.u32(
+                    "sh_type",
+                    sh::TYPE,
+                    format!(
+                        "{:#x} ({})",
+                        section.r#type(),
+                        common::sh_type_name(section.r#type())
+                    ),
+                );
+                fields.word(
+                    "sh_flags",
+                    sh::FLAGS,
+                    format!(
+                        "{:#x} ({})",
+                        section.flags(),
+                        common::sh_flags_name(section.flags())
+                    ),
+                );
+                fields.word("sh_addr", sh::ADDR, format!("{:#x}", section.addr()));
+                fields.word("sh_offset", sh::OFFSET, format!("{:#x}", section.offset()));
+                fields.word("sh_size", sh::SIZE, format!("{:#x}", section.size()));
+                fields.u32("sh_link", sh::LINK, section.link().to_string());
+                fields.u32("sh_info", sh::INFO, section.info().to_string());
+                fields.word(
+                    "sh_addralign",
+                    sh::ADDRALIGN,
+                    section.addralign().to_string(),
+                );
+                fields.word(
+                    "sh_entsize",
+                    sh::ENTSIZE,
+                    format!("{:#x}", section.entsize()),
+                );
+
+                if let Ok(Some(compression)) = section.compression(self.elf.data()) {
+                    let mut chdr = Fields::new(reader, section.offset() as usize);
+                    let r#type = format!(
+                        "{} ({})",
+                        compression.r#type(),
+                        common::ch_type_name(compression.r#type())
+                    );
+
+                    // The header of `.zdebug_*` sections is `ZLIB` and a big-endian size
+                    if section.flags() & sh::flags::COMPRESSED != 0 {
+                        chdr.u32("ch_type", ch::TYPE, r#type);
+                        chdr.word("ch_size", ch::SIZE, format!("{:#x}", compression.size()));
+                        chdr.word(
+                            "ch_addralign",
+                            ch::ADDRALIGN,
+                            compression.addralign().to_string(),
+                        );
+                    } else {
+                        chdr.push("ch_type", 0x00, 0x04, r#type);
+                        chdr.push("ch_size", 0x04, 0x08, format!("{:#x}", compression.size()));
+                    }
+
+                    fields.fields.extend(chdr.fields);
+                }
+
+                match self.symbols.get(&index) {
+                    Some(Err(err)) if node == Node::Symbols(index) => Err(err.clone()),
+                    _ => Ok(fields.fields),
+                }
+            }
+            Node::Symbol(table, index) => {
+                let section = self
+                    .elf
+                    .sections()
+                    .get(table)
+                    .ok_or("section is out of the table")?;
+                let symbol = self
+                    .symbol(table, index)
+                    .ok_or("symbol is out of the table")?;
+                let entsize = match section.entsize() as usize {
+                    0 => Symbol::entsize_of(&reader),
+                    entsize => entsize,
+                };
+                let base = section.offset() as usize + index * entsize;
+                let mut fields = Fields::new(reader, base);
+
+                fields.u32(
+                    "st_name",
+                    st::NAME,
+                    format!(
+                        "{:#x} ({})",
+                        symbol.name_offset(),
+                        symbol.name().unwrap_or("<corrupt>")
+                    ),
+                );
+                fields.word("st_value", st::VALUE, format!("{:#x}", symbol.value()));
+                fields.word("st_size", st::SIZE, symbol.size().to_string());
+                fields.u8(
+                    "st_info",
+                    st::INFO,
+                    format!(
+                        "{:#x} ({} {})",
+                        symbol.info(),
+                        common::st_bind_name(symbol.bind()),
+                        common::st_type_name(symbol.r#type())
+                    ),
+                );
+                fields.u8(
+                    "st_other",
+                    st::OTHER,
+                    format!(
+                        "{:#x} ({})",
+                        symbol.other(),
+                        common::st_visibility_name(symbol.visibility())
+                    ),
+                );
+                fields.u16("st_shndx", st::SHNDX, symbol.shndx().to_string());
+
+                if let Some(version) = symbol.version().filter(|version| version.is_named()) {
+                    fields.fields.push(Field {
+                        name: "version",
+                        value: version.to_string(),
+                        range: 0..0,
+                    });
+                }
+
+                Ok(fields.fields)
+            }
+            Node::Types => match self.types.as_deref() {
+                Some(Err(err)) => Err(err.clone()),
+                _ => Ok(Vec::new()),
+            },
+            Node::Type(offset) => {
+                let layout = self.layout(offset).ok_or("type is not in .debug_info")?;
+                let range = |die: std::ops::Range<usize>| match self.debug_info() {
+                    Some(start) => start + die.start..start + die.end,
+                    None => 0..0,
+                };
+                let field = |name, value| Field {
+                    name,
+                    value,
+                    range: 0..0,
+                };
+
+                let mut fields = Vec::new();
+                for member in layout.members() {
+                    let position = match member.bits() {
+                        Some((bit, _)) => format!("{}:{}", member.offset(), bit),
+                        None => member.offset().to_string(),
+                    };
+
+                    fields.push(Field {
+                        name: "member",
+                        value: format!(
+                            "{position:>6} {:>4} {} {}",
+                            member.size(),
+                            member.type_name(),
+                            member.declarator()
+                        ),
+                        range: range(member.die()),
+                    });
+
+                    if member.bit_hole() != 0 {
+                        fields.push(field("hole", format!("{} bits", member.bit_hole())));
+                    }
+                    if member.hole() != 0 {
+                        fields.push(field("hole", format!("{} bytes", member.hole())));
+                    }
+                }
+
+                let (holes, sum_holes) = layout.holes();
+                fields.push(Field {
+                    name: "size",
+                    value: format!("{} ({} cachelines)", layout.size(), layout.cachelines()),
+                    range: range(layout.die()),
+                });
+                fields.push(field("holes", format!("{holes} ({sum_holes} bytes)")));
+                if layout.padding() != 0 {
+                    fields.push(field("padding", layout.padding().to_string()));
+                }
+
+                Ok(fields)
+            }
+            Node::Segments | Node::Sections => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Node, Tree, Types};
+    use badelf::elf::Elf;
+    use std::collections::HashSet;
+    use std::rc::Rc;
+
+    #[test]
+    fn tree_of_exe() {
+        let elf = Elf::parse(include_bytes!("../../test/exe")).unwrap();
+        let mut expanded = HashSet::new();
+        let tree = Tree::new(elf.clone(), &expanded, None);
+
+        let rows = tree.rows();
+        assert_eq!(rows[0], (0, Node::Ident));
+        assert_eq!(rows[2], (0, Node::Segments));
+        assert!(rows.iter().all(|(depth, _)| *depth == 0));
+
+        let dynsym = rows
+            .iter()
+            .find_map(|(_, node)| match node {
+                Node::Symbols(index) if tree.label(*node).contains(".dynsym") => Some(*index),
+                _ => None,
+            })
+            .unwrap();
+
+        expanded.insert(Node::Segments);
+        expanded.insert(Node::Symbols(dynsym));
+        let tree = Tree::new(elf.clone(), &expanded, None);
+
+        let rows = tree.rows();
+        assert_eq!(rows[3], (1, Node::Segment(0)));
+        assert_eq!(tree.label(Node::Segment(0)), "[0] PHDR");
+        assert_eq!(Node::Segment(0).parent(), Some(Node::Segments));
+        assert!(rows.contains(&(1, Node::Symbol(dynsym, 1))));
+
+        let fields = tree.fields(Node::Header).unwrap();
+        assert_eq!(fields[0].name, "e_type");
+        assert_eq!(fields[0].range, 0x10..0x12);
+        assert_eq!(fields[4].name, "e_phoff");
+        assert_eq!(fields[4].range, 0x20..0x28);
+        assert_eq!(fields[4].value, "0x40");
+
+        let fields = tree.fields(Node::Segment(1)).unwrap();
+        assert_eq!(fields[0].range, 0x78..0x7C);
+        assert_eq!(fields[0].value, "0x3 (INTERP)");
+
+        let fields = tree.fields(Node::Symbol(dynsym, 0)).unwrap();
+        assert_eq!(fields[0].name, "st_name");
+        assert_eq!(fields[0].value, "0x0 ()");
+
+        let (node, field) = tree.field_at(0x05).unwrap();
+        assert_eq!((node, field.name), (Node::Ident, "EI_DATA"));
+
+        let (node, field) = tree.field_at(0x3F).unwrap();
+        assert_eq!((node, field.name), (Node::Header, "e_shstrndx"));
+
+        let (node, field) = tree.field_at(0x40 + 0x38 + 0x09).unwrap();
+        assert_eq!((node, field.name), (Node::Segment(1), "p_offset"));
+        assert_eq!(tree.offset(Node::Segment(1)), Some(0x78));
+
+        let (node, field) = tree.field_at(0x3E0 + 0x18 + 0x08).unwrap();
+        assert_eq!((node, field.name), (Node::Symbol(dynsym, 1), "st_value"));
+
+        expanded.remove(&Node::Segments);
+        let tree = Tree::new(elf, &expanded, None);
+        assert!(!tree.rows().contains(&(1, Node::Segment(0))));
+    }
+
+    #[test]
+    fn types_of_debug() {
+        let elf = Elf::parse(include_bytes!("../../test/debug4")).unwrap();
+        let mut expanded = HashSet::new();
+        let types = |elf: &Elf| Some(Rc::new(Types::new(elf).map_err(|err| err.to_string())));
+
+        let tree = Tree::new(elf.clone(), &expanded, None);
+        assert_eq!(tree.rows().last(), Some(&(0, Node::Types)));
+        assert_eq!(tree.label(Node::Types), "Types");
+
+        expanded.insert(Node::Types);
+        let tree = Tree::new(elf.clone(), &expanded, types(&elf));
+        assert_eq!(tree.label(Node::Types), "Types (3)");
+
+        let shape = tree
+            .rows()
+            .into_iter()
+            .find(|&(_, node)| tree.label(node) == "struct shape")
+            .unwrap()
+            .1;
+        assert_eq!(shape.parent(), Some(Node::Types));
+
+        let debug_info = elf.sections().by_name(".debug_info").unwrap().offset() as usize;
+        let Node::Type(die) = shape else { panic!() };
+        assert_eq!(tree.offset(shape), Some(debug_info + die));
+
+        let fields = tree.fields(shape).unwrap();
+        assert_eq!(fields[0].name, "member");
+        assert_eq!(fields[0].value, "     0    1 char kind");
+        assert!(fields[0].range.start > debug_info + die);
+        assert_eq!(
+            (fields[1].name, fields[1].value.as_str()),
+            ("hole", "3 bytes")
+        );
+        assert!(fields[1].range.is_empty());
+        assert_eq!(fields[5].value, " 12:17    4 unsigned int layer:4");
+        assert_eq!(fields[6].value, "11 bits");
+
+        let holes = fields.iter().find(|field| field.name == "holes").unwrap();
+        assert_eq!(holes.value, "1 (3 bytes)");
+
+        // Compressed, the types are read but are not in the file
+        let elf = Elf::parse(include_bytes!("../../test/zstd")).unwrap();
+        let tree = Tree::new(elf.clone(), &expanded, types(&elf));
+        assert_eq!(tree.label(Node::Types), "Types (3)");
+        assert!(tree.offset(shape).is_none());
+        assert!(tree.fields(shape).unwrap()[0].range.is_empty());
+
+        let info = elf
+            .sections()
+            .position(|section| section.name() == Some(".debug_info"))
+            .unwrap();
+        let fields = tree.fields(Node::Section(info)).unwrap();
+        let r#type = fields.iter().find(|field| field.name == "ch_type").unwrap();
+        assert_eq!(r#type.value, "2 (ZSTD)");
+
+        let elf = Elf::parse(include_bytes!("../../test/exe")).unwrap();
+        assert!(Tree::new(elf.clone(), &expanded, types(&elf))
+            .rows()
+            .iter()
+            .all(|&(_, node)| node != Node::Types));
+    }
+}
@@ -0,0 +1,11 @@
+
+Relocation section '.rel.text' at offset 0x10c contains 3 entries:
+ Offset     Info    Type            Sym.Value  Sym. Name
+00000008  0000042b R_ARM_MOVW_ABS_NC 00000000   .L.str
+0000000c  0000042c R_ARM_MOVT_ABS    00000000   .L.str
+00000010  0000061c R_ARM_CALL        00000000   printf
+
+Relocation section '.rel.ARM.exidx' at offset 0x124 contains 2 entries:
+ Offset     Info    Type            Sym.Value  Sym. Name
+00000000  00000700 R_ARM_NONE        00000000   __aeabi_unwind_cpp_pr0
+00000000  0000022a R_ARM_PREL31      00000000   .text
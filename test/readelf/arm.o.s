@@ -0,0 +1,11 @@
+
+Symbol table '.symtab' contains 8 entries:
+   Num:    Value  Size Type    Bind   Vis      Ndx Name
+     0: 00000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 00000000     0 FILE    LOCAL  DEFAULT  ABS lib.ll
+     2: 00000000     0 SECTION LOCAL  DEFAULT    2 .text
+     3: 00000000     0 NOTYPE  LOCAL  DEFAULT    2 $a.0
+     4: 00000000     3 OBJECT  LOCAL  DEFAULT    6 .L.str
+     5: 00000000    24 FUNC    GLOBAL DEFAULT    2 foo
+     6: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND printf
+     7: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND __aeabi_unwind_c[...]
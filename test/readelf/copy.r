@@ -0,0 +1,10 @@
+
+Relocation section '.rela.dyn' at offset 0x4d0 contains 3 entries:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000403fd8  000100000006 R_X86_64_GLOB_DAT 0000000000000000 __libc_start_main@GLIBC_2.34 + 0
+000000403fe0  000200000006 R_X86_64_GLOB_DAT 0000000000000000 __gmon_start__ + 0
+000000404018  000400000005 R_X86_64_COPY     0000000000404018 stdout@GLIBC_2.2.5 + 0
+
+Relocation section '.rela.plt' at offset 0x518 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000404000  000300000007 R_X86_64_JUMP_SLO 0000000000000000 fwrite@GLIBC_2.2.5 + 0
@@ -0,0 +1,46 @@
+
+Symbol table '.dynsym' contains 5 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND _[...]@GLIBC_2.34 (2)
+     2: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+     3: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND [...]@GLIBC_2.2.5 (3)
+     4: 0000000000404018     8 OBJECT  GLOBAL DEFAULT   25 [...]@GLIBC_2.2.5 (3)
+
+Symbol table '.symtab' contains 35 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS crt1.o
+     2: 000000000040037c    32 OBJECT  LOCAL  DEFAULT    4 __abi_tag
+     3: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS crtstuff.c
+     4: 0000000000401080     0 FUNC    LOCAL  DEFAULT   14 deregister_tm_clones
+     5: 00000000004010b0     0 FUNC    LOCAL  DEFAULT   14 register_tm_clones
+     6: 00000000004010f0     0 FUNC    LOCAL  DEFAULT   14 __do_global_dtors_aux
+     7: 0000000000404020     1 OBJECT  LOCAL  DEFAULT   25 completed.0
+     8: 0000000000403e00     0 OBJECT  LOCAL  DEFAULT   20 __do_global_dtor[...]
+     9: 0000000000401120     0 FUNC    LOCAL  DEFAULT   14 frame_dummy
+    10: 0000000000403df8     0 OBJECT  LOCAL  DEFAULT   19 __frame_dummy_in[...]
+    11: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS copy.c
+    12: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS crtstuff.c
+    13: 00000000004020d8     0 OBJECT  LOCAL  DEFAULT   18 __FRAME_END__
+    14: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS 
+    15: 0000000000403e08     0 OBJECT  LOCAL  DEFAULT   21 _DYNAMIC
+    16: 000000000040200c     0 NOTYPE  LOCAL  DEFAULT   17 __GNU_EH_FRAME_HDR
+    17: 0000000000403fe8     0 OBJECT  LOCAL  DEFAULT   23 _GLOBAL_OFFSET_TABLE_
+    18: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND __libc_start_mai[...]
+    19: 0000000000404018     8 OBJECT  GLOBAL DEFAULT   25 stdout@GLIBC_2.2.5
+    20: 0000000000404008     0 NOTYPE  WEAK   DEFAULT   24 data_start
+    21: 0000000000404018     0 NOTYPE  GLOBAL DEFAULT   24 _edata
+    22: 0000000000401150     0 FUNC    GLOBAL HIDDEN    15 _fini
+    23: 0000000000404008     0 NOTYPE  GLOBAL DEFAULT   24 __data_start
+    24: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+    25: 0000000000404010     0 OBJECT  GLOBAL HIDDEN    24 __dso_handle
+    26: 0000000000402000     4 OBJECT  GLOBAL DEFAULT   16 _IO_stdin_used
+    27: 0000000000404028     0 NOTYPE  GLOBAL DEFAULT   25 _end
+    28: 0000000000401070     1 FUNC    GLOBAL HIDDEN    14 _dl_relocate_sta[...]
+    29: 0000000000401040    34 FUNC    GLOBAL DEFAULT   14 _start
+    30: 0000000000404018     0 NOTYPE  GLOBAL DEFAULT   25 __bss_start
+    31: 0000000000401126    41 FUNC    GLOBAL DEFAULT   14 main
+    32: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND fwrite@GLIBC_2.2.5
+    33: 0000000000404018     0 OBJECT  GLOBAL HIDDEN    24 __TMC_END__
+    34: 0000000000401000     0 FUNC    GLOBAL HIDDEN    12 _init
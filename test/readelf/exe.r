@@ -0,0 +1,15 @@
+
+Relocation section '.rela.dyn' at offset 0x558 contains 8 entries:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000003dd0  000000000008 R_X86_64_RELATIVE                    1130
+000000003dd8  000000000008 R_X86_64_RELATIVE                    10e0
+000000004010  000000000008 R_X86_64_RELATIVE                    4010
+000000003fc0  000100000006 R_X86_64_GLOB_DAT 0000000000000000 __libc_start_main@GLIBC_2.34 + 0
+000000003fc8  000200000006 R_X86_64_GLOB_DAT 0000000000000000 _ITM_deregisterTM[...] + 0
+000000003fd0  000400000006 R_X86_64_GLOB_DAT 0000000000000000 __gmon_start__ + 0
+000000003fd8  000500000006 R_X86_64_GLOB_DAT 0000000000000000 _ITM_registerTMCl[...] + 0
+000000003fe0  000600000006 R_X86_64_GLOB_DAT 0000000000000000 __cxa_finalize@GLIBC_2.2.5 + 0
+
+Relocation section '.rela.plt' at offset 0x618 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000004000  000300000007 R_X86_64_JUMP_SLO 0000000000000000 printf@GLIBC_2.2.5 + 0
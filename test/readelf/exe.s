@@ -0,0 +1,39 @@
+
+Symbol table '.dynsym' contains 7 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND _[...]@GLIBC_2.34 (2)
+     2: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+     3: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND [...]@GLIBC_2.2.5 (3)
+     4: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+     5: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+     6: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND [...]@GLIBC_2.2.5 (3)
+
+Symbol table '.symtab' contains 26 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS lib.c
+     2: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS exe.c
+     3: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS 
+     4: 0000000000003de0     0 OBJECT  LOCAL  DEFAULT   21 _DYNAMIC
+     5: 0000000000002008     0 NOTYPE  LOCAL  DEFAULT   17 __GNU_EH_FRAME_HDR
+     6: 0000000000003fe8     0 OBJECT  LOCAL  DEFAULT   23 _GLOBAL_OFFSET_TABLE_
+     7: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND __libc_start_mai[...]
+     8: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+     9: 0000000000004008     0 NOTYPE  WEAK   DEFAULT   24 data_start
+    10: 0000000000004018     0 NOTYPE  GLOBAL DEFAULT   24 _edata
+    11: 0000000000001178     0 FUNC    GLOBAL HIDDEN    15 _fini
+    12: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND printf@GLIBC_2.2.5
+    13: 0000000000004008     0 NOTYPE  GLOBAL DEFAULT   24 __data_start
+    14: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+    15: 0000000000004010     0 OBJECT  GLOBAL HIDDEN    24 __dso_handle
+    16: 0000000000002000     4 OBJECT  GLOBAL DEFAULT   16 _IO_stdin_used
+    17: 0000000000001139    39 FUNC    GLOBAL DEFAULT   14 foo
+    18: 0000000000004020     0 NOTYPE  GLOBAL DEFAULT   25 _end
+    19: 0000000000001040    38 FUNC    GLOBAL DEFAULT   14 _start
+    20: 0000000000004018     0 NOTYPE  GLOBAL DEFAULT   25 __bss_start
+    21: 0000000000001160    21 FUNC    GLOBAL DEFAULT   14 main
+    22: 0000000000004018     0 OBJECT  GLOBAL HIDDEN    24 __TMC_END__
+    23: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+    24: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND __cxa_finalize@G[...]
+    25: 0000000000001000     0 FUNC    GLOBAL HIDDEN    12 _init
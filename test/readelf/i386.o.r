@@ -0,0 +1,10 @@
+
+Relocation section '.rel.text' at offset 0x124 contains 3 entries:
+ Offset     Info    Type            Sym.Value  Sym. Name
+0000000c  0000050a R_386_GOTPC       00000000   _GLOBAL_OFFSET_TABLE_
+00000015  00000309 R_386_GOTOFF      00000000   .L.str
+0000001f  00000604 R_386_PLT32       00000000   printf
+
+Relocation section '.rel.eh_frame' at offset 0x13c contains 1 entry:
+ Offset     Info    Type            Sym.Value  Sym. Name
+00000020  00000202 R_386_PC32        00000000   .text
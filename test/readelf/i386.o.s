@@ -0,0 +1,10 @@
+
+Symbol table '.symtab' contains 7 entries:
+   Num:    Value  Size Type    Bind   Vis      Ndx Name
+     0: 00000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 00000000     0 FILE    LOCAL  DEFAULT  ABS lib.ll
+     2: 00000000     0 SECTION LOCAL  DEFAULT    2 .text
+     3: 00000000     3 OBJECT  LOCAL  DEFAULT    4 .L.str
+     4: 00000000    40 FUNC    GLOBAL DEFAULT    2 foo
+     5: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND _GLOBAL_OFFSET_TABLE_
+     6: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND printf
@@ -0,0 +1,4 @@
+
+Relocation section '.rel.plt' at offset 0x188 contains 1 entry:
+ Offset     Info    Type            Sym.Value  Sym. Name
+00004000  00000107 R_386_JUMP_SLOT   00000000   printf
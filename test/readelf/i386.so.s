@@ -0,0 +1,16 @@
+
+Symbol table '.dynsym' contains 3 entries:
+   Num:    Value  Size Type    Bind   Vis      Ndx Name
+     0: 00000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND printf
+     2: 00001020    40 FUNC    GLOBAL DEFAULT    7 foo
+
+Symbol table '.symtab' contains 7 entries:
+   Num:    Value  Size Type    Bind   Vis      Ndx Name
+     0: 00000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 00000000     0 FILE    LOCAL  DEFAULT  ABS lib.ll
+     2: 00000000     0 FILE    LOCAL  DEFAULT  ABS 
+     3: 00003f74     0 OBJECT  LOCAL  DEFAULT   10 _DYNAMIC
+     4: 00003ff4     0 OBJECT  LOCAL  DEFAULT   11 _GLOBAL_OFFSET_TABLE_
+     5: 00000000     0 NOTYPE  GLOBAL DEFAULT  UND printf
+     6: 00001020    40 FUNC    GLOBAL DEFAULT    7 foo
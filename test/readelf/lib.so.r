@@ -0,0 +1,14 @@
+
+Relocation section '.rela.dyn' at offset 0x478 contains 7 entries:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000003df8  000000000008 R_X86_64_RELATIVE                    1100
+000000003e00  000000000008 R_X86_64_RELATIVE                    10b0
+000000004008  000000000008 R_X86_64_RELATIVE                    4008
+000000003fc8  000100000006 R_X86_64_GLOB_DAT 0000000000000000 _ITM_deregisterTM[...] + 0
+000000003fd0  000300000006 R_X86_64_GLOB_DAT 0000000000000000 __gmon_start__ + 0
+000000003fd8  000400000006 R_X86_64_GLOB_DAT 0000000000000000 _ITM_registerTMCl[...] + 0
+000000003fe0  000500000006 R_X86_64_GLOB_DAT 0000000000000000 __cxa_finalize@GLIBC_2.2.5 + 0
+
+Relocation section '.rela.plt' at offset 0x520 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000004000  000200000007 R_X86_64_JUMP_SLO 0000000000000000 printf@GLIBC_2.2.5 + 0
@@ -0,0 +1,29 @@
+
+Symbol table '.dynsym' contains 7 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+     2: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND [...]@GLIBC_2.2.5 (2)
+     3: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+     4: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+     5: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND [...]@GLIBC_2.2.5 (2)
+     6: 0000000000001109    39 FUNC    GLOBAL DEFAULT   12 foo
+
+Symbol table '.symtab' contains 16 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS lib.c
+     2: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS 
+     3: 0000000000001130     0 FUNC    LOCAL  DEFAULT   13 _fini
+     4: 0000000000004008     0 OBJECT  LOCAL  DEFAULT   22 __dso_handle
+     5: 0000000000003e08     0 OBJECT  LOCAL  DEFAULT   19 _DYNAMIC
+     6: 0000000000002004     0 NOTYPE  LOCAL  DEFAULT   15 __GNU_EH_FRAME_HDR
+     7: 0000000000004010     0 OBJECT  LOCAL  DEFAULT   22 __TMC_END__
+     8: 0000000000003fe8     0 OBJECT  LOCAL  DEFAULT   21 _GLOBAL_OFFSET_TABLE_
+     9: 0000000000001000     0 FUNC    LOCAL  DEFAULT   10 _init
+    10: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+    11: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND printf@GLIBC_2.2.5
+    12: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+    13: 0000000000001109    39 FUNC    GLOBAL DEFAULT   12 foo
+    14: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+    15: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND __cxa_finalize@G[...]
@@ -0,0 +1,10 @@
+
+Relocation section '.rela.text' at offset 0xf4 contains 3 entries:
+ Offset     Info    Type            Sym.Value  Sym. Name + Addend
+00000012  00000306 R_PPC_ADDR16_HA   00000000   .rodata.str1.1 + 0
+00000016  00000304 R_PPC_ADDR16_LO   00000000   .rodata.str1.1 + 0
+0000001c  0000050a R_PPC_REL24       00000000   printf + 0
+
+Relocation section '.rela.eh_frame' at offset 0x118 contains 1 entry:
+ Offset     Info    Type            Sym.Value  Sym. Name + Addend
+0000001c  0000021a R_PPC_REL32       00000000   .text + 0
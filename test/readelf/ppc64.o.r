@@ -0,0 +1,15 @@
+
+Relocation section '.rela.text' at offset 0x178 contains 3 entries:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+00000000000e  000300000032 R_PPC64_TOC16_HA  0000000000000000 .rodata.str1.1 + 0
+000000000012  000300000030 R_PPC64_TOC16_LO  0000000000000000 .rodata.str1.1 + 0
+00000000001c  00060000000a R_PPC64_REL24     0000000000000000 printf + 0
+
+Relocation section '.rela.opd' at offset 0x1c0 contains 2 entries:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000000000  000200000026 R_PPC64_ADDR64    0000000000000000 .text + 0
+000000000008  000000000033 R_PPC64_TOC                          0
+
+Relocation section '.rela.eh_frame' at offset 0x1f0 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+00000000001c  00020000001a R_PPC64_REL32     0000000000000000 .text + 0
@@ -0,0 +1,10 @@
+
+Symbol table '.symtab' contains 7 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS lib.ll
+     2: 0000000000000000     0 SECTION LOCAL  DEFAULT    2 .text
+     3: 0000000000000000     0 SECTION LOCAL  DEFAULT    6 .rodata.str1.1
+     4: 0000000000000000    64 FUNC    GLOBAL DEFAULT    4 foo
+     5: 0000000000000000     0 NOTYPE  GLOBAL DEFAULT  UND .TOC.
+     6: 0000000000000000     0 NOTYPE  GLOBAL DEFAULT  UND printf
@@ -0,0 +1,4 @@
+
+Relocation section '.rela.eh_frame' at offset 0x120 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000000020  000200000002 R_X86_64_PC32     0000000000000000 .text.kept + 0
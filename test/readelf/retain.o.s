@@ -0,0 +1,7 @@
+
+Symbol table '.symtab' contains 4 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS retain.c
+     2: 0000000000000000     0 SECTION LOCAL  DEFAULT    4 .text.kept
+     3: 0000000000000000    11 FUNC    LOCAL  DEFAULT    4 kept
@@ -0,0 +1,43 @@
+
+Symbol table '.dynsym' contains 9 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+     2: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND [...]@GLIBC_2.2.5 (4)
+     3: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+     4: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+     5: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND [...]@GLIBC_2.2.5 (4)
+     6: 0000000000000000     0 OBJECT  GLOBAL DEFAULT  ABS LIB_1.0
+     7: 0000000000001109    39 FUNC    GLOBAL DEFAULT   13 foo@@LIB_2.0
+     8: 0000000000000000     0 OBJECT  GLOBAL DEFAULT  ABS LIB_2.0
+
+Symbol table '.symtab' contains 28 entries:
+   Num:    Value          Size Type    Bind   Vis      Ndx Name
+     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND 
+     1: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS crtstuff.c
+     2: 0000000000001050     0 FUNC    LOCAL  DEFAULT   13 deregister_tm_clones
+     3: 0000000000001080     0 FUNC    LOCAL  DEFAULT   13 register_tm_clones
+     4: 00000000000010c0     0 FUNC    LOCAL  DEFAULT   13 __do_global_dtors_aux
+     5: 0000000000004010     1 OBJECT  LOCAL  DEFAULT   24 completed.0
+     6: 0000000000003dd0     0 OBJECT  LOCAL  DEFAULT   19 __do_global_dtor[...]
+     7: 0000000000001100     0 FUNC    LOCAL  DEFAULT   13 frame_dummy
+     8: 0000000000003dc8     0 OBJECT  LOCAL  DEFAULT   18 __frame_dummy_in[...]
+     9: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS lib.c
+    10: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS crtstuff.c
+    11: 00000000000020a0     0 OBJECT  LOCAL  DEFAULT   17 __FRAME_END__
+    12: 0000000000000000     0 FILE    LOCAL  DEFAULT  ABS 
+    13: 0000000000001130     0 FUNC    LOCAL  DEFAULT   14 _fini
+    14: 0000000000004008     0 OBJECT  LOCAL  DEFAULT   23 __dso_handle
+    15: 0000000000003dd8     0 OBJECT  LOCAL  DEFAULT   20 _DYNAMIC
+    16: 0000000000002004     0 NOTYPE  LOCAL  DEFAULT   16 __GNU_EH_FRAME_HDR
+    17: 0000000000004010     0 OBJECT  LOCAL  DEFAULT   23 __TMC_END__
+    18: 0000000000003fe8     0 OBJECT  LOCAL  DEFAULT   22 _GLOBAL_OFFSET_TABLE_
+    19: 0000000000001000     0 FUNC    LOCAL  DEFAULT   10 _init
+    20: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_deregisterT[...]
+    21: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND printf@GLIBC_2.2.5
+    22: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__
+    23: 0000000000000000     0 OBJECT  GLOBAL DEFAULT  ABS LIB_1.0
+    24: 0000000000001109    39 FUNC    GLOBAL DEFAULT   13 foo
+    25: 0000000000000000     0 OBJECT  GLOBAL DEFAULT  ABS LIB_2.0
+    26: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND _ITM_registerTMC[...]
+    27: 0000000000000000     0 FUNC    WEAK   DEFAULT  UND __cxa_finalize@G[...]
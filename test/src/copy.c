@@ -0,0 +1,6 @@
+#include <stdio.h>
+
+int main() {
+    fputs("copy\n", stdout);
+    return 0;
+}
@@ -0,0 +1,17 @@
+#include <pthread.h>
+#include <unistd.h>
+
+static void *idle(void *arg) {
+    (void)arg;
+    pause();
+    return 0;
+}
+
+int main(void) {
+    pthread_t thread;
+    pthread_create(&thread, 0, idle, 0);
+    sleep(1);
+
+    *(volatile int *)0x10 = 1;
+    return 0;
+}
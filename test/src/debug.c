@@ -0,0 +1,16 @@
+#include "debug.h"
+
+shape_t square = {
+    .kind = 's',
+    .sides = 4,
+    .corners = {{0, 0}, {2, 0}, {2, 2}, {0, 2}},
+};
+
+int main(int argc, char **argv) {
+    long total = 0;
+
+    for (shape_t *shape = &square; shape; shape = shape->next)
+        total += area(shape);
+
+    return total + argc;
+}
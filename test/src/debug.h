@@ -0,0 +1,28 @@
+#include <stdint.h>
+
+struct point {
+    int x, y;
+};
+
+union value {
+    int64_t number;
+    double real;
+    const char *string;
+};
+
+typedef struct shape {
+    char kind;
+    struct point origin;
+    short sides;
+    unsigned visible : 1, layer : 4;
+    union value value;
+    struct point corners[4];
+    struct shape *next;
+} shape_t;
+
+static inline long area(const shape_t *shape) {
+    long width = shape->corners[1].x - shape->corners[0].x;
+    long height = shape->corners[2].y - shape->corners[1].y;
+
+    return width * height;
+}
@@ -0,0 +1,9 @@
+; Same as lib.c, for the targets there is no cross compiler for
+@.str = private unnamed_addr constant [3 x i8] c"%d\00"
+
+declare i32 @printf(i8*, ...)
+
+define void @foo(i32 %x) {
+  %1 = call i32 (i8*, ...) @printf(i8* getelementptr ([3 x i8], [3 x i8]* @.str, i32 0, i32 0), i32 %x)
+  ret void
+}
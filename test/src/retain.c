@@ -0,0 +1,3 @@
+__attribute__((used, retain)) static int kept(void) {
+    return 0;
+}
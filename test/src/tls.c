@@ -0,0 +1,12 @@
+struct counter {
+    long hits;
+    short misses;
+};
+
+__thread int calls;
+__thread struct counter counter;
+
+long hit(void) {
+    calls++;
+    return ++counter.hits;
+}
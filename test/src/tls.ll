@@ -0,0 +1,41 @@
+; Same as tls.c with its debug information, for RISC-V there is no cross compiler for
+%struct.counter = type { i64, i16 }
+
+@calls = dso_local thread_local global i32 0, align 4, !dbg !20
+@counter = dso_local thread_local global %struct.counter zeroinitializer, align 8, !dbg !0
+
+define dso_local i64 @hit() !dbg !15 {
+  %1 = load i32, i32* @calls, align 4, !dbg !23
+  %2 = add nsw i32 %1, 1, !dbg !23
+  store i32 %2, i32* @calls, align 4, !dbg !23
+  %3 = load i64, i64* getelementptr (%struct.counter, %struct.counter* @counter, i32 0, i32 0), align 8, !dbg !18
+  %4 = add nsw i64 %3, 1, !dbg !18
+  store i64 %4, i64* getelementptr (%struct.counter, %struct.counter* @counter, i32 0, i32 0), align 8, !dbg !18
+  ret i64 %4, !dbg !19
+}
+
+!llvm.dbg.cu = !{!2}
+!llvm.module.flags = !{!13, !14}
+
+!0 = !DIGlobalVariableExpression(var: !1, expr: !DIExpression())
+!1 = distinct !DIGlobalVariable(name: "counter", scope: !2, file: !3, line: 7, type: !6, isLocal: false, isDefinition: true)
+!2 = distinct !DICompileUnit(language: DW_LANG_C99, file: !3, producer: "tls.ll", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !5)
+!3 = !DIFile(filename: "src/tls.c", directory: "/")
+!5 = !{!20, !0}
+!6 = distinct !DICompositeType(tag: DW_TAG_structure_type, name: "counter", file: !3, line: 1, size: 128, elements: !7)
+!7 = !{!8, !10}
+!8 = !DIDerivedType(tag: DW_TAG_member, name: "hits", scope: !6, file: !3, line: 2, baseType: !9, size: 64)
+!9 = !DIBasicType(name: "long", size: 64, encoding: DW_ATE_signed)
+!10 = !DIDerivedType(tag: DW_TAG_member, name: "misses", scope: !6, file: !3, line: 3, baseType: !11, size: 16, offset: 64)
+!11 = !DIBasicType(name: "short", size: 16, encoding: DW_ATE_signed)
+!13 = !{i32 7, !"Dwarf Version", i32 5}
+!14 = !{i32 2, !"Debug Info Version", i32 3}
+!15 = distinct !DISubprogram(name: "hit", scope: !3, file: !3, line: 9, type: !16, scopeLine: 9, spFlags: DISPFlagDefinition, unit: !2)
+!16 = !DISubroutineType(types: !17)
+!17 = !{!9}
+!18 = !DILocation(line: 11, column: 12, scope: !15)
+!19 = !DILocation(line: 11, column: 5, scope: !15)
+!20 = !DIGlobalVariableExpression(var: !21, expr: !DIExpression())
+!21 = distinct !DIGlobalVariable(name: "calls", scope: !2, file: !3, line: 6, type: !22, isLocal: false, isDefinition: true)
+!22 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!23 = !DILocation(line: 10, column: 10, scope: !15)